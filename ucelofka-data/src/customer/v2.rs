@@ -17,6 +17,9 @@ pub struct Customer {
     pub address: Vec<String>,
     pub identifications: Vec<Identification>,
     pub email: Vec<String>,
+    /// preferred language of the documents (e.g. `cs`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
@@ -43,6 +46,7 @@ impl From<v1::Customer> for Customer {
             address: old.address,
             email: old.email,
             identifications,
+            locale: None,
         }
     }
 }
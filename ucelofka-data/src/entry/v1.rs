@@ -14,6 +14,9 @@ pub struct Entry {
     pub price: f32,
     pub currency: String,
     pub details: Vec<String>,
+    /// VAT rate in percent, entries without a rate are not subject to VAT
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vat_rate: Option<f32>,
 }
 
 impl Entry {
@@ -31,6 +34,7 @@ impl Entry {
             price,
             currency,
             details,
+            vat_rate: None,
         }
     }
 }
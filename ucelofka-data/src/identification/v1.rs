@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Identification {
@@ -6,8 +7,8 @@ pub struct Identification {
     pub value: String,
 }
 
-impl ToString for Identification {
-    fn to_string(&self) -> String {
-        format!("{}:{}", self.name, self.value)
+impl fmt::Display for Identification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.name, self.value)
     }
 }
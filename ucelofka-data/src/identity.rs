@@ -7,6 +7,6 @@ use super::data_versions;
 pub use latest::{Identities, Identity};
 pub use v2 as latest;
 
-pub const CURRENT_VERSION: u32 = v1::VERSION;
+pub const CURRENT_VERSION: u32 = latest::VERSION;
 
 data_versions!(Identity, 1, 2);
@@ -26,6 +26,16 @@ pub struct Entry {
     pub price: f32,
     pub currency: String,
     pub details: Vec<String>,
+    /// VAT rate in percent, the price doesn't include the tax
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vat_rate: Option<f32>,
+}
+
+impl Entry {
+    /// Amount of VAT for the entry
+    pub fn tax(&self) -> f32 {
+        self.vat_rate.map_or(0.0, |rate| self.price * rate / 100.0)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -123,6 +133,7 @@ impl Invoice {
                     price: e.price,
                     name: e.name.clone(),
                     details: e.details.clone(),
+                    vat_rate: e.vat_rate,
                 })
                 .collect(),
         }
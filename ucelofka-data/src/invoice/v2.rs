@@ -18,6 +18,8 @@ pub struct Customer {
     pub address: Vec<String>,
     pub identifications: Vec<Identification>,
     pub email: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
 }
 
 impl From<v1::Customer> for Customer {
@@ -38,6 +40,7 @@ impl From<v1::Customer> for Customer {
             address: old.address,
             email: old.email,
             identifications,
+            locale: None,
         }
     }
 }
@@ -75,6 +78,20 @@ impl From<v1::Issuer> for Issuer {
     }
 }
 
+/// Payment reminder sent for an overdue invoice
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Reminder {
+    pub level: u32,
+    pub date: String,
+}
+
+/// Email with the invoice which was sent to the customer
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Delivery {
+    pub date: String,
+    pub recipients: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Invoice {
     #[serde(default = "default_version")]
@@ -86,6 +103,13 @@ pub struct Invoice {
     pub customer: Customer,
     pub entries: Vec<Entry>,
     pub billing: Billing,
+    /// Date when the invoice was marked as paid
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paid: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reminders: Vec<Reminder>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sent: Vec<Delivery>,
 }
 
 impl Invoice {
@@ -105,7 +129,11 @@ impl Invoice {
         invoices: Vec<Self>,
         due: Option<usize>,
     ) -> Self {
-        let total = entries.iter().map(|e| e.price).sum();
+        // prices of entries don't include VAT
+        let total = entries
+            .iter()
+            .map(|e| e.price + e.vat_rate.map_or(0.0, |rate| e.price * rate / 100.0))
+            .sum();
         let new_id = Self::make_new_id(&invoices);
         Self {
             _version: VERSION,
@@ -132,6 +160,7 @@ impl Invoice {
                 address: customer.address,
                 identifications: customer.identifications,
                 email: customer.email,
+                locale: customer.locale,
             },
             billing: Billing {
                 account_name: account.account_name,
@@ -149,8 +178,12 @@ impl Invoice {
                     price: e.price,
                     name: e.name.clone(),
                     details: e.details.clone(),
+                    vat_rate: e.vat_rate,
                 })
                 .collect(),
+            paid: None,
+            reminders: vec![],
+            sent: vec![],
         }
     }
 }
@@ -166,6 +199,9 @@ impl From<v1::Invoice> for Invoice {
             billing: old.billing,
             issuer: old.issuer.into(),
             customer: old.customer.into(),
+            paid: None,
+            reminders: vec![],
+            sent: vec![],
         }
     }
 }
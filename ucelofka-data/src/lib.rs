@@ -4,6 +4,7 @@ pub mod entry;
 pub mod identification;
 pub mod identity;
 pub mod invoice;
+pub mod payment;
 pub mod template;
 
 pub use anyhow::{anyhow, Result};
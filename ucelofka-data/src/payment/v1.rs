@@ -0,0 +1,32 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{data_display, data_try_from, default_version};
+
+pub const VERSION: u32 = 1;
+
+/// Incoming payment which was matched to an invoice
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Payment {
+    #[serde(default = "default_version")]
+    pub _version: u32,
+    pub id: String,
+    pub invoice: u64,
+    pub date: String,
+    pub amount: f32,
+    pub currency: String,
+    pub variable_symbol: String,
+    /// Account or name of the payer
+    pub counterparty: String,
+    /// Transaction reference from the bank statement
+    pub reference: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Payments {
+    pub payments: Vec<Payment>,
+}
+
+data_display!(Payment);
+data_try_from!(Payment);
+data_display!(Payments);
@@ -1,5 +1,5 @@
 pub mod v1;
 
-pub use v1::{Template, Templates};
+pub use v1::{html_to_text, Template, Templates};
 
 pub const CURRENT_VERSION: u32 = v1::VERSION;
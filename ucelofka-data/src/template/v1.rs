@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::{
     fmt,
     fs::{read_to_string, File},
+    io::Read,
     path::{Path, PathBuf},
 };
 
@@ -42,11 +43,7 @@ impl Template {
         let file = File::open(&self.path)
             .map_err(|e| anyhow!("Failed to open file {}: {}", self.path.to_str().unwrap(), e))?;
 
-        self.text = Some(from_read_with_decorator(
-            file,
-            TEXT_WIDTH,
-            RichDecorator::new(),
-        ));
+        self.text = Some(html_to_text(file));
 
         Ok(())
     }
@@ -57,18 +54,32 @@ impl Template {
     }
 }
 
+/// Renders html as a plain text (`TEXT_WIDTH` characters wide)
+pub fn html_to_text<R: Read>(html: R) -> String {
+    from_read_with_decorator(html, TEXT_WIDTH, RichDecorator::new())
+}
+
 #[derive(Debug, Deserialize, Serialize, Default, PartialEq)]
 pub struct Templates {
     pub templates: Vec<Template>,
 }
 
 impl Templates {
+    pub fn is_partial(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.starts_with('_'))
+            .unwrap_or(false)
+    }
+
     pub fn load(template_dir: &Path) -> Result<Self> {
+        // partials (`_base.html`) and asset directories are not templates
         let mut paths: Vec<PathBuf> = template_dir
             .read_dir()?
             .collect::<Result<Vec<_>, _>>()?
             .iter()
             .map(|e| template_dir.join(e.path()))
+            .filter(|path| path.is_file() && !Self::is_partial(path))
             .collect();
 
         // sort novices by filename
@@ -0,0 +1,38 @@
+invoice = Faktura
+invoice-number = Faktura #
+issued = Vydáno
+due = Splatnost
+contractor = Dodavatel
+customer = Objednavatel
+address = Adresa
+phone = Telefon
+email = Email
+www = www
+identification-registration = IČ
+identification-tax = DIČ
+item = Položka
+price = Cena
+total = Celkem
+vat-rate = Sazba DPH
+vat-base = Základ daně
+vat = DPH
+price-with-vat = Cena s DPH
+payment = Platba
+bank-transfer = Bankovní převod
+account-number = Číslo účtu
+iban = IBAN
+swift = SWIFT
+variable-symbol = Variabilní symbol
+amount = Částka
+currency = Měna
+reminder = Upomínka
+reminder-date = Datum
+reminder-text = Dosud jsme neobdrželi úhradu níže uvedené faktury. Prosíme o úhradu dlužné částky co nejdříve.
+reminder-text-final = Toto je poslední upomínka níže uvedené faktury. Nebude-li dlužná částka neprodleně uhrazena, budeme nuceni ji vymáhat jinou cestou.
+days-overdue = Dní po splatnosti
+outstanding = Dlužná částka
+late-interest = Úrok z prodlení
+total-due = Celkem k úhradě
+email-greeting = Dobrý den,
+email-text = v příloze zasíláme fakturu za naše služby.
+email-closing = S pozdravem
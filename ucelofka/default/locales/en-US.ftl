@@ -0,0 +1,38 @@
+invoice = Invoice
+invoice-number = Invoice #
+issued = Issued
+due = Due
+contractor = Contractor
+customer = Customer
+address = Address
+phone = Phone
+email = Email
+www = www
+identification-registration = ID
+identification-tax = VAT ID
+item = Item
+price = Price
+total = Total
+vat-rate = VAT rate
+vat-base = Tax base
+vat = VAT
+price-with-vat = Price with VAT
+payment = Payment
+bank-transfer = Bank Transfer
+account-number = Account no.
+iban = IBAN
+swift = SWIFT
+variable-symbol = Variable sym.
+amount = Amount
+currency = Currency
+reminder = Payment reminder
+reminder-date = Date
+reminder-text = We have not received the payment of the invoice below yet. Please pay the outstanding amount as soon as possible.
+reminder-text-final = This is the final reminder of the invoice below. Unless the outstanding amount is paid without delay, we will have to claim it by other means.
+days-overdue = Days overdue
+outstanding = Outstanding amount
+late-interest = Late interest
+total-due = Total due
+email-greeting = Hello,
+email-text = please find attached the invoice for our services.
+email-closing = Best regards,
@@ -4,9 +4,432 @@ data-directory-is-missing-subdir = adresáři s daty `{ $dir_path }` chybí poda
 data-directory-path = cesta k adresáři s daty
 entries-different-invoice = Záznamy by neměli mít různé měny ({ $currencies })
 invoice-rendered = faktura `{ $invoice }` byla vykreslena do `{ $filename }`
+invoice-exported = faktura `{ $invoice }` byla exportována do { $format } do `{ $filename }`
+invoice-sent = faktura `{ $invoice }` byla odeslána na { $recipients }
+invoice-eml-written = email s fakturou `{ $invoice }` byl zapsán do `{ $filename }`
+invoices-built = vykresleno { $rendered }, aktuálních { $skipped }, selhalo { $failed }
+records-imported = { $imported } importováno, { $skipped } přeskočeno
+record-exists = záznam { $id } již existuje (použijte --conflict skip nebo overwrite)
+payment-paid = { $date } { $amount } { $currency } - faktura { $invoice } je uhrazena
+payment-partial = { $date } { $amount } { $currency } - faktura { $invoice } je uhrazena částečně, zbývá { $remaining }
+payment-review = { $date } { $amount } { $currency } - převyšuje zbývajících { $remaining } faktury { $invoice }, zkontrolujte ji (nezaznamenáno)
+payment-unmatched = { $date } { $amount } { $currency } - žádná odpovídající faktura (variabilní symbol "{ $variable_symbol }", { $counterparty })
+payment-recorded = { $date } { $amount } { $currency } - již zaznamenáno
+payments-imported = { $matched } spárováno, { $unmatched } nespárováno, { $review } ke kontrole, { $recorded } již zaznamenáno
+reminder-sent = upomínka { $level } faktury `{ $invoice }` ({ $days } dní po splatnosti) byla vykreslena do `{ $filename }`
 response-msg =
     { $value ->
         [one] "{ $input }" has one Collatz step.
        *[other] "{ $input }" has { $value } Collatz steps.
     }
 not-a-port-number = `{ $port }` není validní číslo portu
+invoice-created = Vytvořena faktura { $invoice }
+project-file-added = přidávám { $path }
+web-started = Spouštím webové rozhraní ucelofky na http://localhost:{ $port }
+
+## Errors
+
+account-not-found = Účet { $id } nebyl nalezen.
+customer-not-found = Zákazník { $id } nebyl nalezen.
+entry-not-found = Položka { $id } nebyla nalezena.
+identity-not-found = Identita { $id } nebyla nalezena.
+invoice-not-found = Faktura { $id } nebyla nalezena.
+payment-not-found = Platba { $id } nebyla nalezena.
+template-not-found = Šablona { $id } nebyla nalezena.
+template-date-format-invalid = Neplatný formát data { $format }
+template-path-outside = { $path } není uvnitř adresáře se šablonami
+record-not-found = Záznam { $id } nebyl nalezen.
+record-not-unique = záznam `{ $id }`: id není unikátní
+record-index-error = záznam #{ $index }: { $error }
+record-id-error = záznam `{ $id }`: { $error }
+record-convert-failed = nepodařilo se převést { $path } - { $error }
+git-open-failed = Nepodařilo se otevřít git repozitář ({ $error })
+git-index-failed = Nepodařilo se načíst index repozitáře ({ $error })
+git-add-failed = Nepodařilo se přidat soubor { $path } ({ $error })
+git-remove-failed = Nepodařilo se odebrat soubor { $path } ({ $error })
+git-write-failed = Nepodařilo se zapsat index ({ $error })
+git-no-workdir = Git repozitář nemá pracovní adresář
+git-outside-repo = Soubor { $path } není uvnitř git repozitáře
+git-create-failed = Nepodařilo se vytvořit git repozitář v { $path } ({ $error })
+invalid-path = Neplatná cesta { $path }
+file-read-failed = Nepodařilo se přečíst { $path } ({ $error })
+unknown-encoding = Neznámé kódování { $encoding }
+file-write-failed = Nepodařilo se zapsat { $path } ({ $error })
+dir-create-failed = Nepodařilo se vytvořit adresář { $path } ({ $error })
+output-dir-failed = Nepodařilo se vytvořit výstupní adresář ({ $error })
+output-write-failed = Nepodařilo se zapsat výstupní soubor ({ $error })
+output-pattern-invalid = Vzor výstupu { $pattern } musí být relativní cesta uvnitř výstupního adresáře
+output-name-conflict = Faktura { $invoice } se šablonou { $template } a faktura { $other_invoice } se šablonou { $other_template } by se vytvořily do stejného souboru { $filename } (použijte { "{" }template{ "}" } ve vzoru výstupu)
+fonts-dir-failed = Nepodařilo se přečíst adresář s fonty ({ $error })
+font-not-found = V { $path } nebyl nalezen žádný font
+font-read-failed = Nepodařilo se přečíst font ({ $error })
+font-parse-failed = Nepodařilo se zpracovat font ({ $error })
+font-subset-failed = Nepodařilo se vytvořit podmnožinu fontu ({ $error })
+facturx-empty-text = Šablona { $template } nevytvořila žádný text pro PDF
+customer-no-email = Zákazník faktury { $invoice } nemá email (použijte --to)
+issuer-no-email = Vystavitel faktury { $invoice } nemá email (použijte --from)
+invoice-invalid-issue-date = Faktura { $invoice } má neplatné datum vystavení { $date } ({ $error })
+export-single-invoice = Pro export do { $format } musí být vybrána právě jedna faktura
+unknown-conflict-policy = Neznámé řešení konfliktů { $value }
+unknown-period = Neznámé období { $value }
+unknown-export-format = Neznámý formát exportu { $value }
+unknown-accounting-format = Neznámý účetní formát { $value }
+unknown-report-format = Neznámý formát přehledu { $value }
+unknown-statement-format = Neznámý formát výpisu { $value }
+unknown-format = Neznámý formát { $value }
+format-not-detected = Nepodařilo se rozpoznat formát { $path }, použijte --format
+statement-format-not-detected = Nepodařilo se rozpoznat formát výpisu, použijte --format
+invalid-date = Neplatné datum { $date } ({ $error })
+invalid-amount = Neplatná částka { $amount } ({ $error })
+invalid-locale = Neplatné locale `{ $locale }` ({ $error })
+invalid-tax-period = Neplatné zdaňovací období { $period } (použijte např. 2026Q3 nebo 2026-07)
+reminder-templates-failed = Nepodařilo se načíst šablony upomínek z templates/{ $dir } ({ $error })
+reminder-template-not-found = Šablona upomínky { $template } nebyla v templates/{ $dir } nalezena
+smtp-invalid-port = Neplatný SMTP port { $port } ({ $error })
+smtp-send-failed = Odeslání emailu přes { $host }:{ $port } selhalo ({ $error })
+smtp-credentials-unencrypted = Přihlašovací údaje SMTP nejsou posílány přes nešifrované spojení (použijte --smtp-security starttls nebo tls)
+smtp-unknown-security = Neznámé zabezpečení SMTP { $value }
+invalid-email-address = Neplatná emailová adresa { $address } ({ $error })
+command-run-failed = Nepodařilo se spustit { $path } ({ $error })
+command-write-failed = Nepodařilo se zapsat do { $path }
+command-failed = { $path } selhal ({ $status })
+statement-entry-without-amount = Položka výpisu nemá částku
+statement-entry-without-date = Položka výpisu nemá datum
+statement-invalid-camt = Neplatný CAMT výpis ({ $error })
+statement-invalid-amount-on-line = Neplatná částka na řádku { $line } ({ $error })
+statement-invalid-date-on-line = Neplatné datum na řádku { $line } ({ $error })
+statement-invalid-line = Neplatný řádek výpisu :61:{ $line }
+vat-rate-unsupported = Faktura { $invoice } má nepodporovanou sazbu DPH { $rate }
+isdoc-foreign-currency = Faktura { $invoice } je v { $currency }, ISDOC faktury českých dodavatelů lze exportovat jen v CZK
+issuer-without-czech-tax-id = Vystavitel { $issuer } nemá české DIČ
+no-invoices-in-period = V období { $period } nebyly vystaveny žádné faktury
+invoice-vat-not-in-currency = Faktura { $invoice } s DPH není v { $currency }
+config-read-failed = Nepodařilo se přečíst { $file } ({ $error })
+config-parse-failed = Nepodařilo se zpracovat { $file } ({ $error })
+config-invalid-key = Neplatná klávesa '{ $key }' v { $file } (použijte jeden znak)
+config-duplicate-key = Klávesa '{ $key }' v { $file } je použita pro více akcí na stejné stránce
+watch-failed = Nepodařilo se sledovat { $path } ({ $error })
+value-missing = chybí hodnota `{ $column }`
+value-invalid = neplatná hodnota `{ $column }` ({ $error })
+id-invalid = neplatné id `{ $id }`
+id-not-unique = id není unikátní
+price-invalid = neplatná cena
+currency-missing = chybí měna
+vat-rate-invalid = neplatná sazba DPH
+invoice-without-rows = žádné řádky faktury
+invoice-without-entries = faktura nemá žádné položky
+entry-different-currency = položka `{ $entry }` má jinou měnu než faktura
+reminder-invalid = neplatná upomínka `{ $reminder }`
+reminder-level-invalid = neplatná upomínka `{ $reminder }` ({ $error })
+delivery-invalid = neplatné doručení `{ $delivery }`
+json-invalid = neplatný JSON ({ $error })
+
+## Command line help
+
+about-ucelofka = Jednoduchý program na vystavování faktur
+about-invoice = Správa faktur
+about-invoice-create = Vytvoří novou fakturu
+about-invoice-list = Vypíše faktury
+about-invoice-ids = Vypíše id faktur
+about-invoice-get = Zobrazí fakturu
+about-invoice-render = Vykreslí fakturu
+about-invoice-build = Vykreslí všechny (nebo vybrané) faktury, které nejsou aktuální
+about-invoice-export = Exportuje faktury do strojově čitelného formátu
+about-invoice-send = Odešle vykreslenou fakturu zákazníkovi emailem
+about-invoice-paid = Označí fakturu jako uhrazenou
+about-invoice-import = Importuje faktury
+about-remind = Vykreslí upomínky faktur po splatnosti
+about-project = Správa datového projektu
+about-project-make = Vytvoří nový adresář s daty
+about-account = Správa účtů
+about-account-list = Vypíše účty
+about-account-ids = Vypíše id účtů
+about-account-get = Zobrazí účet
+about-account-export = Exportuje účty
+about-account-import = Importuje účty
+about-customer = Správa zákazníků
+about-customer-list = Vypíše zákazníky
+about-customer-ids = Vypíše id zákazníků
+about-customer-get = Zobrazí zákazníka
+about-customer-export = Exportuje zákazníky
+about-customer-import = Importuje zákazníky
+about-entry = Správa položek
+about-entry-list = Vypíše položky
+about-entry-ids = Vypíše id položek
+about-entry-get = Zobrazí položku
+about-entry-export = Exportuje položky
+about-entry-import = Importuje položky
+about-entry-create = Vytvoří položku
+about-identity = Správa identit
+about-identity-list = Vypíše identity
+about-identity-ids = Vypíše id identit
+about-identity-get = Zobrazí identitu
+about-identity-export = Exportuje identity
+about-identity-import = Importuje identity
+about-payment = Správa plateb
+about-payment-list = Vypíše platby
+about-payment-ids = Vypíše id plateb
+about-payment-get = Zobrazí platbu
+about-payment-import = Importuje příchozí platby z bankovního výpisu a spáruje je s fakturami
+about-report = Přehledy faktur
+about-report-revenue = Fakturované částky podle období a měny
+about-report-aging = Neuhrazené částky zákazníků podle počtu dní po splatnosti
+about-tax = DPH vystavených faktur
+about-tax-report = Základ daně a daň podle sazeb DPH
+about-tax-control-statement = XML kontrolního hlášení DPH pro portál EPO
+about-template = Správa šablon
+about-template-list = Vypíše šablony
+about-template-get = Zobrazí šablonu
+about-web = Spustí webové rozhraní ucelofky
+about-completions = Generátor doplňování pro shell
+about-ids = Vypíše id všech entit
+about-tui = Spustí textové rozhraní ucelofky
+help-output-pattern = Vzor názvu výstupního souboru (nahrazuje se { "{" }id{ "}" }, { "{" }customer{ "}" }, { "{" }issue_date{ "}" }, { "{" }template{ "}" } a { "{" }ext{ "}" })
+help-output = Cesta k výstupnímu souboru
+help-output-or-stdout = Cesta k výstupnímu souboru (výchozí je standardní výstup)
+help-export-format = Formát exportu
+help-import-input = Cesta k importovanému souboru (- pro standardní vstup)
+help-import-format = Formát importu (výchozí je podle přípony souboru)
+help-import-conflict = Co dělat se záznamy, které již existují
+help-import-git = Přidat importované záznamy do gitu
+help-account-id = Id účtu
+help-customer-id = Id zákazníka
+help-entry-id = Id položky
+help-identity-id = Id identity
+help-invoice-id = Id faktury
+help-template-id = Id šablony
+help-invoice-create-git = Přidat novou fakturu do gitu
+help-invoice-due = Doba splatnosti (ve dnech)
+help-render-locale = Locale vykreslené faktury (výchozí je locale zákazníka)
+help-render-facturx = Vykreslit PDF Factur-X s vloženým CII XML (HTML šablony jsou převedeny na prostý text)
+help-render-stdout = Vypsat vykreslenou fakturu na standardní výstup
+help-output-git = Přidat nový soubor do gitu
+help-build-invoice = Id faktury (výchozí je vykreslit všechny faktury)
+help-build-locale = Locale vykreslených faktur (výchozí je locale zákazníka)
+help-build-force = Vykreslit i faktury, které jsou aktuální
+help-build-jobs = Počet paralelních úloh (výchozí je počet CPU)
+help-build-git = Přidat nové soubory do gitu
+help-export-invoice = Id faktury (csv, json, pohoda a money-s3 exportují ve výchozím stavu všechny faktury)
+help-export-from = Exportovat jen faktury vystavené od tohoto data (RRRR-MM-DD)
+help-export-to = Exportovat jen faktury vystavené do tohoto data (RRRR-MM-DD)
+help-export-stdout = Vypsat exportovanou fakturu na standardní výstup
+help-send-template = Šablona přiložené faktury (aktuální výstup se použije znovu)
+help-send-email-template = Šablona emailu (v templates/emails, první řádek je předmět)
+help-send-locale = Locale faktury a emailu (výchozí je locale zákazníka)
+help-send-from = Odesílatel (výchozí je email vystavitele)
+help-send-to = Příjemci (výchozí jsou emaily zákazníka)
+help-send-smtp = SMTP server (host[:port]) použitý místo sendmailu
+help-send-smtp-security = Šifrování SMTP spojení (none je určeno pro lokální relay)
+help-send-smtp-user = Uživatel SMTP
+help-send-smtp-password = Heslo SMTP
+help-send-sendmail = Program kompatibilní se sendmailem
+help-send-dry-run = Zapsat email do output/<faktura>.eml místo odeslání
+help-send-git = Přidat změněnou fakturu a vykreslený výstup do gitu
+help-paid-date = Datum platby (RRRR-MM-DD, výchozí je dnešek)
+help-paid-git = Přidat změněnou fakturu do gitu
+help-remind-template = Šablona upomínky (v templates/reminders)
+help-remind-date = Datum upomínek (RRRR-MM-DD, výchozí je dnešek)
+help-remind-interest = Roční úrok z prodlení v procentech
+help-remind-interval = Nejmenší počet dní mezi upomínkami stejné faktury
+help-remind-locale = Locale upomínek (výchozí je locale zákazníka)
+help-remind-git = Přidat upomínky a změněné faktury do gitu
+help-project-target = Kam se má projekt umístit
+help-project-git = Inicializovat s git repozitářem
+help-entry-create-id = Id nové položky
+help-entry-create-name = Název nové položky
+help-entry-create-price = Cena nové položky
+help-entry-create-currency = Měna nové položky
+help-entry-create-details = Detail nové položky
+help-entry-create-vat = Sazba DPH v procentech (cena neobsahuje DPH)
+help-entry-create-git = Přidat novou položku do gitu
+help-payment-import-input = Cesta k bankovnímu výpisu (- pro standardní vstup)
+help-payment-import-format = Formát výpisu (výchozí je podle obsahu)
+help-payment-import-encoding = Kódování výpisu (pro soubory, které nejsou v UTF-8, se výchozí použije windows-1250)
+help-payment-import-dry-run = Jen vypsat spárování, platby nezaznamenávat
+help-payment-import-git = Přidat zaznamenané platby do gitu
+help-report-format = Výstupní formát
+help-report-period = Délka období
+help-report-by-customer = Rozdělit částky podle zákazníků
+help-report-from = Jen faktury vystavené od tohoto data (RRRR-MM-DD)
+help-report-to = Jen faktury vystavené do tohoto data (RRRR-MM-DD)
+help-report-date = Datum, ke kterému je přehled sestaven (RRRR-MM-DD, výchozí je dnešek)
+help-tax-period = Zdaňovací období - čtvrtletí (2026Q3) nebo měsíc (2026-07)
+help-tax-legal-person = Plátce je právnická osoba (výchozí je fyzická osoba)
+help-tax-office = Kód finančního úřadu
+help-tax-date = Datum podání (RRRR-MM-DD, výchozí je dnešek)
+help-web-port = Port, který použije webový server
+help-completions-shell = Pro který shell se má doplňování vygenerovat
+help-tui-git = Přidávat záznamy vytvořené v textovém rozhraní do gitu
+help-lang = Jazyk zpráv (např. cs, má přednost před prostředím a config.yml)
+
+## TUI
+
+tui-page-dashboard = Přehled
+tui-page-accounts = Účty
+tui-page-customers = Zákazníci
+tui-page-entries = Položky
+tui-page-identities = Identity
+tui-page-invoices = Faktury
+tui-page-templates = Šablony
+tui-help-title = Klávesy - { $page } (Esc zavře)
+tui-help-next-field = další/předchozí seznam nebo pole
+tui-help-move = pohyb v seznamu
+tui-help-scroll = posun seznamu
+tui-help-first-last = první/poslední položka
+tui-help-search = hledání v seznamu
+tui-help-help = zobrazit/skrýt nápovědu
+tui-help-pages = přejít na seznam stránek
+tui-help-quit = konec
+tui-help-edit = upravit vybraný záznam
+tui-help-delete = smazat vybraný záznam
+tui-help-confirm = potvrdit/zrušit smazání
+tui-help-cancel-editing = zrušit úpravy
+tui-help-sort-entries = řadit podle id, názvu nebo ceny
+tui-help-customer = filtrovat podle zákazníka
+tui-help-year = filtrovat podle roku
+tui-help-unpaid = jen nezaplacené
+tui-help-sort-invoices = řadit podle id, data, zákazníka nebo částky
+tui-help-template = další šablona
+tui-help-render = vytvořit fakturu
+tui-help-preview = náhled faktury
+tui-help-close-preview = zavřít náhled
+tui-help-invoice = vyplnit další fakturou
+tui-help-show-template = zobrazit šablonu
+tui-search-not-found = /{ $query } (nenalezeno)
+tui-list-failed = nepodařilo se načíst { $dir } - { $error }
+tui-form-hint = Více hodnot se odděluje `{ $separator }`, Tab přejde na další pole, Esc zruší úpravy.
+tui-save = Uložit
+tui-confirm-delete = Smazat { $name }? ({ $confirm }/{ $cancel })
+tui-create = <Vytvořit>
+tui-record-hint = { $edit } - upravit, { $delete } - smazat, { $search } - hledat
+tui-entry-hint = { $edit } - upravit, { $delete } - smazat, { $sort } - řadit (podle: { $by }), { $search } - hledat
+tui-edit-account = Upravit účet
+tui-new-account = Nový účet
+tui-edit-customer = Upravit zákazníka
+tui-new-customer = Nový zákazník
+tui-edit-entry = Upravit položku
+tui-new-entry = Nová položka
+tui-edit-identity = Upravit identitu
+tui-new-identity = Nová identita
+tui-new-invoice = Nová faktura
+tui-id = ID:
+tui-name = Název:
+tui-bank-name = Banka:
+tui-account-name = Název účtu:
+tui-account-number = Číslo účtu:
+tui-iban = IBAN:
+tui-bic = BIC:
+tui-currency = Měna:
+tui-address = Adresa:
+tui-email = E-mail:
+tui-phone = Telefon:
+tui-www = WWW:
+tui-locale = Jazyk:
+tui-registration = IČO:
+tui-tax = DIČ:
+tui-identifications = Identifikace:
+tui-price = Cena:
+tui-details = Podrobnosti:
+tui-vat-rate = Sazba DPH:
+tui-issue-date = Datum vystavení:
+tui-due-date = Datum splatnosti:
+tui-paid = Zaplaceno:
+tui-issuer = Dodavatel:
+tui-customer = Zákazník:
+tui-identity = Identita:
+tui-account = Účet:
+tui-entries = Položky:
+tui-entry-vat = { $price } + { $rate }{ "\u00A0" }% DPH
+tui-variable-symbol = Variabilní symbol:
+tui-total = Celkem:
+tui-sort-id = id
+tui-sort-name = název
+tui-sort-price = cena
+tui-sort-issue-date = datum vystavení
+tui-sort-due-date = datum splatnosti
+tui-sort-customer = zákazník
+tui-sort-total = částka
+tui-filter = Zobrazeno: { $shown }, řazeno podle: { $sort }
+tui-filter-all = vše
+tui-filter-unpaid = nezaplacené
+tui-filter-hint = { $filter } ({ $customer } - zákazník, { $year } - rok, { $unpaid } - nezaplacené, { $sort } - řazení, { $search } - hledat)
+tui-template = Šablona: { $template } ({ $next } - další, { $render } - vytvořit, { $preview } - náhled)
+tui-no-templates = Nenalezeny žádné šablony
+tui-rendered = Vytvořeno do { $path }
+tui-preview-title = { $invoice } - { $template } (Esc zavře)
+tui-template-filled = { $template } vyplněná fakturou { $invoice } ({ $key } - další, Esc - šablona)
+tui-template-fill = { $template } ({ $key } - vyplnit fakturou)
+tui-create-records-first = Nejdříve vytvořte zákazníka, identitu, účet a položku.
+tui-invalid-days = Neplatný počet dní { $days }
+tui-no-entries-selected = Nejsou vybrány žádné položky
+tui-select-records = Vyberte zákazníka, identitu a účet
+tui-entries-select = Položky (Mezerník vybírá):
+tui-due-days = Splatnost (dny):
+tui-create-button = Vytvořit
+tui-invoiced = Vyfakturováno
+tui-this-month = Tento měsíc:
+tui-this-year = Tento rok:
+tui-unpaid = Nezaplaceno:
+tui-latest-invoices = Poslední faktury
+tui-no-invoices = Žádné faktury
+tui-overdue-invoices = Faktury po splatnosti
+tui-no-overdue-invoices = Žádné faktury po splatnosti
+tui-overdue-invoice =
+    { $invoice } { $customer } - { $amount } { $currency } ({ $days ->
+        [one] { $days } den
+        [few] { $days } dny
+       *[other] { $days } dní
+    } po { $due })
+
+## Template formatting
+
+number-decimal-separator = ,
+number-group-separator = { "\u00A0" }
+money =
+    { $currency ->
+        [CZK] { $amount } Kč
+        [EUR] { $amount } €
+        [USD] { $amount } US$
+       *[other] { $amount } { $currency }
+    }
+percent = { $value }{ "\u00A0" }%
+amount-in-words = { $words } { $cents }/100
+date-format = %-d. %B %Y
+date-month-1 = ledna
+date-month-2 = února
+date-month-3 = března
+date-month-4 = dubna
+date-month-5 = května
+date-month-6 = června
+date-month-7 = července
+date-month-8 = srpna
+date-month-9 = září
+date-month-10 = října
+date-month-11 = listopadu
+date-month-12 = prosince
+date-month-short-1 = led
+date-month-short-2 = úno
+date-month-short-3 = bře
+date-month-short-4 = dub
+date-month-short-5 = kvě
+date-month-short-6 = čvn
+date-month-short-7 = čvc
+date-month-short-8 = srp
+date-month-short-9 = zář
+date-month-short-10 = říj
+date-month-short-11 = lis
+date-month-short-12 = pro
+date-weekday-1 = pondělí
+date-weekday-2 = úterý
+date-weekday-3 = středa
+date-weekday-4 = čtvrtek
+date-weekday-5 = pátek
+date-weekday-6 = sobota
+date-weekday-7 = neděle
+date-weekday-short-1 = po
+date-weekday-short-2 = út
+date-weekday-short-3 = st
+date-weekday-short-4 = čt
+date-weekday-short-5 = pá
+date-weekday-short-6 = so
+date-weekday-short-7 = ne
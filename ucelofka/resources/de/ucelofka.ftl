@@ -0,0 +1,434 @@
+is-not-a-directory = `{ $path }` ist kein Verzeichnis
+path-not-exits = Pfad `{ $path }` existiert nicht
+data-directory-is-missing-subdir = dem Datenverzeichnis `{ $dir_path }` fehlt das Unterverzeichnis `{ $subdir_path }`
+data-directory-path = Pfad zum Datenverzeichnis
+entries-different-invoice = Posten sollten nicht in verschiedenen Währungen sein ({ $currencies })
+invoice-rendered = Rechnung `{ $invoice }` wurde in `{ $filename }` gerendert
+invoice-exported = Rechnung `{ $invoice }` wurde als { $format } nach `{ $filename }` exportiert
+invoice-sent = Rechnung `{ $invoice }` wurde an { $recipients } gesendet
+invoice-eml-written = E-Mail mit Rechnung `{ $invoice }` wurde in `{ $filename }` geschrieben
+invoices-built = { $rendered } gerendert, { $skipped } aktuell, { $failed } fehlgeschlagen
+records-imported = { $imported } importiert, { $skipped } übersprungen
+record-exists = Datensatz { $id } existiert bereits (verwenden Sie --conflict skip oder overwrite)
+payment-paid = { $date } { $amount } { $currency } - Rechnung { $invoice } ist bezahlt
+payment-partial = { $date } { $amount } { $currency } - Rechnung { $invoice } ist teilweise bezahlt, { $remaining } offen
+payment-review = { $date } { $amount } { $currency } - übersteigt die offenen { $remaining } der Rechnung { $invoice }, bitte prüfen (nicht erfasst)
+payment-unmatched = { $date } { $amount } { $currency } - keine passende Rechnung (Verwendungszweck "{ $variable_symbol }", { $counterparty })
+payment-recorded = { $date } { $amount } { $currency } - bereits erfasst
+payments-imported = { $matched } zugeordnet, { $unmatched } nicht zugeordnet, { $review } zu prüfen, { $recorded } bereits erfasst
+reminder-sent = Mahnung { $level } der Rechnung `{ $invoice }` ({ $days } Tage überfällig) wurde in `{ $filename }` gerendert
+response-msg =
+    { $value ->
+        [one] "{ $input }" has one Collatz step.
+       *[other] "{ $input }" has { $value } Collatz steps.
+    }
+not-a-port-number = `{ $port }` ist keine gültige Portnummer
+invoice-created = Rechnung { $invoice } erstellt
+project-file-added = füge { $path } hinzu
+web-started = Starte die Weboberfläche von ucelofka auf http://localhost:{ $port }
+
+## Errors
+
+account-not-found = Konto { $id } nicht gefunden.
+customer-not-found = Kunde { $id } nicht gefunden.
+entry-not-found = Posten { $id } nicht gefunden.
+identity-not-found = Identität { $id } nicht gefunden.
+invoice-not-found = Rechnung { $id } nicht gefunden.
+payment-not-found = Zahlung { $id } nicht gefunden.
+template-not-found = Vorlage { $id } nicht gefunden.
+template-date-format-invalid = Ungültiges Datumsformat { $format }
+template-path-outside = { $path } liegt nicht im Vorlagenverzeichnis
+record-not-found = Datensatz { $id } nicht gefunden.
+record-not-unique = Datensatz `{ $id }`: ID ist nicht eindeutig
+record-index-error = Datensatz #{ $index }: { $error }
+record-id-error = Datensatz `{ $id }`: { $error }
+record-convert-failed = { $path } konnte nicht konvertiert werden - { $error }
+git-open-failed = Git-Repository konnte nicht geöffnet werden ({ $error })
+git-index-failed = Index des Repositorys konnte nicht gelesen werden ({ $error })
+git-add-failed = Datei { $path } konnte nicht hinzugefügt werden ({ $error })
+git-remove-failed = Datei { $path } konnte nicht entfernt werden ({ $error })
+git-write-failed = Index konnte nicht geschrieben werden ({ $error })
+git-no-workdir = Git-Repository hat kein Arbeitsverzeichnis
+git-outside-repo = Datei { $path } liegt nicht im Git-Repository
+git-create-failed = Git-Repository in { $path } konnte nicht erstellt werden ({ $error })
+invalid-path = Ungültiger Pfad { $path }
+file-read-failed = { $path } konnte nicht gelesen werden ({ $error })
+unknown-encoding = Unbekannte Kodierung { $encoding }
+file-write-failed = { $path } konnte nicht geschrieben werden ({ $error })
+dir-create-failed = Verzeichnis { $path } konnte nicht erstellt werden ({ $error })
+output-dir-failed = Ausgabeverzeichnis konnte nicht erstellt werden ({ $error })
+output-write-failed = Ausgabedatei konnte nicht geschrieben werden ({ $error })
+output-pattern-invalid = Ausgabemuster { $pattern } muss ein relativer Pfad innerhalb des Ausgabeverzeichnisses sein
+output-name-conflict = Rechnung { $invoice } mit Vorlage { $template } und Rechnung { $other_invoice } mit Vorlage { $other_template } würden beide in { $filename } erstellt (verwenden Sie { "{" }template{ "}" } im Ausgabemuster)
+fonts-dir-failed = Schriftartenverzeichnis konnte nicht gelesen werden ({ $error })
+font-not-found = Keine Schriftart in { $path } gefunden
+font-read-failed = Schriftart konnte nicht gelesen werden ({ $error })
+font-parse-failed = Schriftart konnte nicht verarbeitet werden ({ $error })
+font-subset-failed = Teilmenge der Schriftart konnte nicht erstellt werden ({ $error })
+facturx-empty-text = Vorlage { $template } hat keinen Text für das PDF erzeugt
+customer-no-email = Der Kunde der Rechnung { $invoice } hat keine E-Mail (verwenden Sie --to)
+issuer-no-email = Der Aussteller der Rechnung { $invoice } hat keine E-Mail (verwenden Sie --from)
+invoice-invalid-issue-date = Rechnung { $invoice } hat ein ungültiges Ausstellungsdatum { $date } ({ $error })
+export-single-invoice = Für den { $format }-Export muss genau eine Rechnung ausgewählt sein
+unknown-conflict-policy = Unbekannte Konfliktbehandlung { $value }
+unknown-period = Unbekannter Zeitraum { $value }
+unknown-export-format = Unbekanntes Exportformat { $value }
+unknown-accounting-format = Unbekanntes Buchhaltungsformat { $value }
+unknown-report-format = Unbekanntes Berichtsformat { $value }
+unknown-statement-format = Unbekanntes Kontoauszugsformat { $value }
+unknown-format = Unbekanntes Format { $value }
+format-not-detected = Format von { $path } konnte nicht erkannt werden, verwenden Sie --format
+statement-format-not-detected = Format des Kontoauszugs konnte nicht erkannt werden, verwenden Sie --format
+invalid-date = Ungültiges Datum { $date } ({ $error })
+invalid-amount = Ungültiger Betrag { $amount } ({ $error })
+invalid-locale = Ungültiges Locale `{ $locale }` ({ $error })
+invalid-tax-period = Ungültiger Steuerzeitraum { $period } (z. B. 2026Q3 oder 2026-07)
+reminder-templates-failed = Mahnungsvorlagen aus templates/{ $dir } konnten nicht geladen werden ({ $error })
+reminder-template-not-found = Mahnungsvorlage { $template } wurde in templates/{ $dir } nicht gefunden
+smtp-invalid-port = Ungültiger SMTP-Port { $port } ({ $error })
+smtp-send-failed = Senden der E-Mail über { $host }:{ $port } fehlgeschlagen ({ $error })
+smtp-credentials-unencrypted = SMTP-Zugangsdaten werden nicht über eine unverschlüsselte Verbindung gesendet (verwenden Sie --smtp-security starttls oder tls)
+smtp-unknown-security = Unbekannte SMTP-Sicherheit { $value }
+invalid-email-address = Ungültige E-Mail-Adresse { $address } ({ $error })
+command-run-failed = { $path } konnte nicht ausgeführt werden ({ $error })
+command-write-failed = In { $path } konnte nicht geschrieben werden
+command-failed = { $path } ist fehlgeschlagen ({ $status })
+statement-entry-without-amount = Kontoauszugsposten ohne Betrag
+statement-entry-without-date = Kontoauszugsposten ohne Datum
+statement-invalid-camt = Ungültiger CAMT-Kontoauszug ({ $error })
+statement-invalid-amount-on-line = Ungültiger Betrag in Zeile { $line } ({ $error })
+statement-invalid-date-on-line = Ungültiges Datum in Zeile { $line } ({ $error })
+statement-invalid-line = Ungültige Kontoauszugszeile :61:{ $line }
+vat-rate-unsupported = Rechnung { $invoice } hat einen nicht unterstützten Umsatzsteuersatz { $rate }
+isdoc-foreign-currency = Rechnung { $invoice } ist in { $currency }, ISDOC-Rechnungen tschechischer Aussteller können nur in CZK exportiert werden
+issuer-without-czech-tax-id = Aussteller { $issuer } hat keine tschechische Steuernummer
+no-invoices-in-period = Im Zeitraum { $period } wurden keine Rechnungen ausgestellt
+invoice-vat-not-in-currency = Rechnung { $invoice } mit Umsatzsteuer ist nicht in { $currency }
+config-read-failed = { $file } konnte nicht gelesen werden ({ $error })
+config-parse-failed = { $file } konnte nicht verarbeitet werden ({ $error })
+config-invalid-key = Ungültige Taste '{ $key }' in { $file } (verwenden Sie ein einzelnes Zeichen)
+config-duplicate-key = Taste '{ $key }' in { $file } wird für mehrere Aktionen auf derselben Seite verwendet
+watch-failed = { $path } kann nicht überwacht werden ({ $error })
+value-missing = Wert von `{ $column }` fehlt
+value-invalid = ungültiger Wert von `{ $column }` ({ $error })
+id-invalid = ungültige ID `{ $id }`
+id-not-unique = ID ist nicht eindeutig
+price-invalid = ungültiger Preis
+currency-missing = Währung fehlt
+vat-rate-invalid = ungültiger Umsatzsteuersatz
+invoice-without-rows = keine Rechnungszeilen
+invoice-without-entries = Rechnung hat keine Posten
+entry-different-currency = Posten `{ $entry }` hat eine andere Währung als die Rechnung
+reminder-invalid = ungültige Mahnung `{ $reminder }`
+reminder-level-invalid = ungültige Mahnung `{ $reminder }` ({ $error })
+delivery-invalid = ungültige Zustellung `{ $delivery }`
+json-invalid = ungültiges JSON ({ $error })
+
+## Command line help
+
+about-ucelofka = Einfaches Programm zum Ausstellen von Rechnungen
+about-invoice = Rechnungsverwaltung
+about-invoice-create = Erstellt eine neue Rechnung
+about-invoice-list = Listet Rechnungen auf
+about-invoice-ids = Listet die IDs der Rechnungen auf
+about-invoice-get = Zeigt eine Rechnung an
+about-invoice-render = Rendert eine Rechnung
+about-invoice-build = Rendert alle (oder ausgewählte) Rechnungen, die nicht aktuell sind
+about-invoice-export = Exportiert Rechnungen in ein maschinenlesbares Format
+about-invoice-send = Sendet die gerenderte Rechnung per E-Mail an den Kunden
+about-invoice-paid = Markiert die Rechnung als bezahlt
+about-invoice-import = Importiert Rechnungen
+about-remind = Rendert Zahlungserinnerungen für überfällige Rechnungen
+about-project = Verwaltet das Datenprojekt
+about-project-make = Erstellt ein neues Datenverzeichnis
+about-account = Kontenverwaltung
+about-account-list = Listet Konten auf
+about-account-ids = Listet die IDs der Konten auf
+about-account-get = Zeigt ein Konto an
+about-account-export = Exportiert Konten
+about-account-import = Importiert Konten
+about-customer = Kundenverwaltung
+about-customer-list = Listet Kunden auf
+about-customer-ids = Listet die IDs der Kunden auf
+about-customer-get = Zeigt einen Kunden an
+about-customer-export = Exportiert Kunden
+about-customer-import = Importiert Kunden
+about-entry = Postenverwaltung
+about-entry-list = Listet Posten auf
+about-entry-ids = Listet die IDs der Posten auf
+about-entry-get = Zeigt einen Posten an
+about-entry-export = Exportiert Posten
+about-entry-import = Importiert Posten
+about-entry-create = Erstellt einen Posten
+about-identity = Identitätsverwaltung
+about-identity-list = Listet Identitäten auf
+about-identity-ids = Listet die IDs der Identitäten auf
+about-identity-get = Zeigt eine Identität an
+about-identity-export = Exportiert Identitäten
+about-identity-import = Importiert Identitäten
+about-payment = Zahlungsverwaltung
+about-payment-list = Listet Zahlungen auf
+about-payment-ids = Listet die IDs der Zahlungen auf
+about-payment-get = Zeigt eine Zahlung an
+about-payment-import = Importiert eingehende Zahlungen aus einem Kontoauszug und ordnet sie Rechnungen zu
+about-report = Berichte über die Rechnungen
+about-report-revenue = Rechnungsbeträge pro Zeitraum und Währung
+about-report-aging = Offene Beträge pro Kunde nach Tagen seit Fälligkeit
+about-tax = Umsatzsteuer der ausgestellten Rechnungen
+about-tax-report = Bemessungsgrundlage und Steuer pro Umsatzsteuersatz
+about-tax-control-statement = Tschechische Umsatzsteuer-Kontrollmeldung (kontrolní hlášení) als XML für das EPO-Portal
+about-template = Vorlagenverwaltung
+about-template-list = Listet Vorlagen auf
+about-template-get = Zeigt eine Vorlage an
+about-web = Startet die Weboberfläche von ucelofka
+about-completions = Generator für Shell-Vervollständigungen
+about-ids = Gibt die IDs aller Entitäten aus
+about-tui = Startet die Textoberfläche von ucelofka
+help-output-pattern = Muster des Ausgabedateinamens ({ "{" }id{ "}" }, { "{" }customer{ "}" }, { "{" }issue_date{ "}" }, { "{" }template{ "}" } und { "{" }ext{ "}" } werden ersetzt)
+help-output = Pfad der Ausgabedatei
+help-output-or-stdout = Pfad der Ausgabedatei (standardmäßig wird auf stdout ausgegeben)
+help-export-format = Exportformat
+help-import-input = Pfad der importierten Datei (- für stdin)
+help-import-format = Importformat (standardmäßig anhand der Dateiendung erkannt)
+help-import-conflict = Was mit bereits vorhandenen Datensätzen geschehen soll
+help-import-git = Importierte Datensätze zu Git hinzufügen
+help-account-id = Konto-ID
+help-customer-id = Kunden-ID
+help-entry-id = Posten-ID
+help-identity-id = Identitäts-ID
+help-invoice-id = Rechnungs-ID
+help-template-id = Vorlagen-ID
+help-invoice-create-git = Neu erstellte Rechnung zu Git hinzufügen
+help-invoice-due = Zahlungsfrist (in Tagen)
+help-render-locale = Locale der gerenderten Rechnung (standardmäßig die des Kunden)
+help-render-facturx = Factur-X-PDF mit eingebettetem CII-XML rendern (HTML-Vorlagen werden in reinen Text umgewandelt)
+help-render-stdout = Gerenderte Rechnung auf stdout ausgeben
+help-output-git = Neu erstellte Datei zu Git hinzufügen
+help-build-invoice = Rechnungs-ID (ohne Angabe werden alle Rechnungen gerendert)
+help-build-locale = Locale der gerenderten Rechnungen (standardmäßig die des Kunden)
+help-build-force = Auch aktuelle Rechnungen rendern
+help-build-jobs = Anzahl paralleler Jobs (standardmäßig die Anzahl der CPUs)
+help-build-git = Neu erstellte Dateien zu Git hinzufügen
+help-export-invoice = Rechnungs-ID (csv, json, pohoda und money-s3 exportieren standardmäßig alle Rechnungen)
+help-export-from = Nur Rechnungen exportieren, die ab diesem Datum ausgestellt wurden (JJJJ-MM-TT)
+help-export-to = Nur Rechnungen exportieren, die bis zu diesem Datum ausgestellt wurden (JJJJ-MM-TT)
+help-export-stdout = Exportierte Rechnung auf stdout ausgeben
+help-send-template = Vorlage der angehängten Rechnung (aktuelle Ausgabe wird wiederverwendet)
+help-send-email-template = Vorlage der E-Mail (in templates/emails, die erste Zeile ist der Betreff)
+help-send-locale = Locale der Rechnung und der E-Mail (standardmäßig die des Kunden)
+help-send-from = Absender (standardmäßig die E-Mail des Ausstellers)
+help-send-to = Empfänger (standardmäßig die E-Mails des Kunden)
+help-send-smtp = SMTP-Server (host[:port]), der statt sendmail verwendet wird
+help-send-smtp-security = Verschlüsselung der SMTP-Verbindung (none ist nur für ein lokales Relay gedacht)
+help-send-smtp-user = SMTP-Benutzer
+help-send-smtp-password = SMTP-Passwort
+help-send-sendmail = Sendmail-kompatibles Programm
+help-send-dry-run = E-Mail nach output/<Rechnung>.eml schreiben statt sie zu senden
+help-send-git = Geänderte Rechnung und gerenderte Ausgabe zu Git hinzufügen
+help-paid-date = Datum der Zahlung (JJJJ-MM-TT, standardmäßig heute)
+help-paid-git = Geänderte Rechnung zu Git hinzufügen
+help-remind-template = Mahnungsvorlage (in templates/reminders)
+help-remind-date = Datum der Mahnungen (JJJJ-MM-TT, standardmäßig heute)
+help-remind-interest = Jährlicher Verzugszins in Prozent
+help-remind-interval = Mindestanzahl der Tage zwischen Mahnungen derselben Rechnung
+help-remind-locale = Locale der Mahnungen (standardmäßig die des Kunden)
+help-remind-git = Mahnungen und geänderte Rechnungen zu Git hinzufügen
+help-project-target = Wo das Projekt angelegt werden soll
+help-project-git = Mit einem Git-Repository initialisieren
+help-entry-create-id = ID des neuen Postens
+help-entry-create-name = Name des neuen Postens
+help-entry-create-price = Preis des neuen Postens
+help-entry-create-currency = Währung des neuen Postens
+help-entry-create-details = Details des neuen Postens
+help-entry-create-vat = Umsatzsteuersatz in Prozent (der Preis enthält keine Umsatzsteuer)
+help-entry-create-git = Neu erstellten Posten zu Git hinzufügen
+help-payment-import-input = Pfad des Kontoauszugs (- für stdin)
+help-payment-import-format = Format des Kontoauszugs (standardmäßig anhand des Inhalts erkannt)
+help-payment-import-encoding = Kodierung des Kontoauszugs (für Dateien, die nicht in UTF-8 sind, wird standardmäßig windows-1250 verwendet)
+help-payment-import-dry-run = Nur die Zuordnungen ausgeben, Zahlungen nicht erfassen
+help-payment-import-git = Erfasste Zahlungen zu Git hinzufügen
+help-report-format = Ausgabeformat
+help-report-period = Länge des Zeitraums
+help-report-by-customer = Beträge nach Kunden aufteilen
+help-report-from = Nur Rechnungen, die ab diesem Datum ausgestellt wurden (JJJJ-MM-TT)
+help-report-to = Nur Rechnungen, die bis zu diesem Datum ausgestellt wurden (JJJJ-MM-TT)
+help-report-date = Stichtag des Berichts (JJJJ-MM-TT, standardmäßig heute)
+help-tax-period = Steuerzeitraum - Quartal (2026Q3) oder Monat (2026-07)
+help-tax-legal-person = Der Steuerpflichtige ist eine juristische Person (standardmäßig eine natürliche Person)
+help-tax-office = Code des Finanzamts
+help-tax-date = Datum der Abgabe (JJJJ-MM-TT, standardmäßig heute)
+help-web-port = Port, den der Webserver verwendet
+help-completions-shell = Für welche Shell die Vervollständigung erzeugt werden soll
+help-tui-git = In der Textoberfläche erstellte Datensätze zu Git hinzufügen
+help-lang = Sprache der Meldungen (z. B. cs, hat Vorrang vor der Umgebung und config.yml)
+
+## TUI
+
+tui-page-dashboard = Übersicht
+tui-page-accounts = Konten
+tui-page-customers = Kunden
+tui-page-entries = Posten
+tui-page-identities = Identitäten
+tui-page-invoices = Rechnungen
+tui-page-templates = Vorlagen
+tui-help-title = Tasten - { $page } (Esc schließt)
+tui-help-next-field = nächste/vorherige Liste oder nächstes/vorheriges Feld
+tui-help-move = in der Liste bewegen
+tui-help-scroll = die Liste blättern
+tui-help-first-last = erster/letzter Eintrag
+tui-help-search = in der Liste suchen
+tui-help-help = Hilfe ein-/ausblenden
+tui-help-pages = zur Liste der Seiten wechseln
+tui-help-quit = beenden
+tui-help-edit = den ausgewählten Datensatz bearbeiten
+tui-help-delete = den ausgewählten Datensatz löschen
+tui-help-confirm = Löschen bestätigen/abbrechen
+tui-help-cancel-editing = Bearbeiten abbrechen
+tui-help-sort-entries = nach ID, Name oder Preis sortieren
+tui-help-customer = nach Kunde filtern
+tui-help-year = nach Jahr filtern
+tui-help-unpaid = nur unbezahlte anzeigen
+tui-help-sort-invoices = nach ID, Datum, Kunde oder Betrag sortieren
+tui-help-template = nächste Vorlage
+tui-help-render = die Rechnung erstellen
+tui-help-preview = Vorschau der Rechnung
+tui-help-close-preview = die Vorschau schließen
+tui-help-invoice = mit der nächsten Rechnung füllen
+tui-help-show-template = die Vorlage anzeigen
+tui-search-not-found = /{ $query } (nicht gefunden)
+tui-list-failed = { $dir } konnte nicht aufgelistet werden - { $error }
+tui-form-hint = Mehrere Werte werden durch `{ $separator }` getrennt, Tab wechselt zum nächsten Feld, Esc bricht ab.
+tui-save = Speichern
+tui-confirm-delete = { $name } löschen? ({ $confirm }/{ $cancel })
+tui-create = <Erstellen>
+tui-record-hint = { $edit } - bearbeiten, { $delete } - löschen, { $search } - suchen
+tui-entry-hint = { $edit } - bearbeiten, { $delete } - löschen, { $sort } - sortieren (nach: { $by }), { $search } - suchen
+tui-edit-account = Konto bearbeiten
+tui-new-account = Neues Konto
+tui-edit-customer = Kunde bearbeiten
+tui-new-customer = Neuer Kunde
+tui-edit-entry = Posten bearbeiten
+tui-new-entry = Neuer Posten
+tui-edit-identity = Identität bearbeiten
+tui-new-identity = Neue Identität
+tui-new-invoice = Neue Rechnung
+tui-id = ID:
+tui-name = Name:
+tui-bank-name = Bank:
+tui-account-name = Kontoname:
+tui-account-number = Kontonummer:
+tui-iban = IBAN:
+tui-bic = BIC:
+tui-currency = Währung:
+tui-address = Adresse:
+tui-email = E-Mail:
+tui-phone = Telefon:
+tui-www = WWW:
+tui-locale = Sprache:
+tui-registration = Registernummer:
+tui-tax = USt-IdNr.:
+tui-identifications = Kennungen:
+tui-price = Preis:
+tui-details = Details:
+tui-vat-rate = USt-Satz:
+tui-issue-date = Rechnungsdatum:
+tui-due-date = Fälligkeitsdatum:
+tui-paid = Bezahlt:
+tui-issuer = Aussteller:
+tui-customer = Kunde:
+tui-identity = Identität:
+tui-account = Konto:
+tui-entries = Posten:
+tui-entry-vat = { $price } + { $rate }{ "\u00A0" }% USt
+tui-variable-symbol = Verwendungszweck:
+tui-total = Gesamt:
+tui-sort-id = ID
+tui-sort-name = Name
+tui-sort-price = Preis
+tui-sort-issue-date = Rechnungsdatum
+tui-sort-due-date = Fälligkeitsdatum
+tui-sort-customer = Kunde
+tui-sort-total = Betrag
+tui-filter = Angezeigt: { $shown }, sortiert nach: { $sort }
+tui-filter-all = alle
+tui-filter-unpaid = unbezahlt
+tui-filter-hint = { $filter } ({ $customer } - Kunde, { $year } - Jahr, { $unpaid } - unbezahlt, { $sort } - sortieren, { $search } - suchen)
+tui-template = Vorlage: { $template } ({ $next } - nächste, { $render } - erstellen, { $preview } - Vorschau)
+tui-no-templates = Keine Vorlagen gefunden
+tui-rendered = Erstellt in { $path }
+tui-preview-title = { $invoice } - { $template } (Esc schließt)
+tui-template-filled = { $template } gefüllt mit Rechnung { $invoice } ({ $key } - nächste, Esc - Vorlage)
+tui-template-fill = { $template } ({ $key } - mit einer Rechnung füllen)
+tui-create-records-first = Legen Sie zuerst einen Kunden, eine Identität, ein Konto und einen Posten an.
+tui-invalid-days = Ungültige Anzahl von Tagen { $days }
+tui-no-entries-selected = Keine Posten ausgewählt
+tui-select-records = Wählen Sie einen Kunden, eine Identität und ein Konto
+tui-entries-select = Posten (Leertaste wählt aus):
+tui-due-days = Fällig (Tage):
+tui-create-button = Erstellen
+tui-invoiced = Fakturiert
+tui-this-month = Dieser Monat:
+tui-this-year = Dieses Jahr:
+tui-unpaid = Unbezahlt:
+tui-latest-invoices = Neueste Rechnungen
+tui-no-invoices = Keine Rechnungen
+tui-overdue-invoices = Überfällige Rechnungen
+tui-no-overdue-invoices = Keine überfälligen Rechnungen
+tui-overdue-invoice =
+    { $invoice } { $customer } - { $amount } { $currency } ({ $days ->
+        [one] { $days } Tag
+       *[other] { $days } Tage
+    } nach { $due })
+
+## Template formatting
+
+number-decimal-separator = ,
+number-group-separator = .
+money =
+    { $currency ->
+        [EUR] { $amount } €
+        [USD] { $amount } $
+        [CHF] { $amount } CHF
+       *[other] { $amount } { $currency }
+    }
+percent = { $value }{ "\u00A0" }%
+amount-in-words = { $words } { $cents }/100
+date-format = %-d. %B %Y
+date-month-1 = Januar
+date-month-2 = Februar
+date-month-3 = März
+date-month-4 = April
+date-month-5 = Mai
+date-month-6 = Juni
+date-month-7 = Juli
+date-month-8 = August
+date-month-9 = September
+date-month-10 = Oktober
+date-month-11 = November
+date-month-12 = Dezember
+date-month-short-1 = Jan
+date-month-short-2 = Feb
+date-month-short-3 = Mär
+date-month-short-4 = Apr
+date-month-short-5 = Mai
+date-month-short-6 = Jun
+date-month-short-7 = Jul
+date-month-short-8 = Aug
+date-month-short-9 = Sep
+date-month-short-10 = Okt
+date-month-short-11 = Nov
+date-month-short-12 = Dez
+date-weekday-1 = Montag
+date-weekday-2 = Dienstag
+date-weekday-3 = Mittwoch
+date-weekday-4 = Donnerstag
+date-weekday-5 = Freitag
+date-weekday-6 = Samstag
+date-weekday-7 = Sonntag
+date-weekday-short-1 = Mo
+date-weekday-short-2 = Di
+date-weekday-short-3 = Mi
+date-weekday-short-4 = Do
+date-weekday-short-5 = Fr
+date-weekday-short-6 = Sa
+date-weekday-short-7 = So
@@ -4,9 +4,431 @@ data-directory-is-missing-subdir = data directory `{ $dir_path }` is missing `{
 data-directory-path = path to data directory
 entries-different-invoice = Entries are not supposed to be in different currencies ({ $currencies })
 invoice-rendered = invoice `{ $invoice }` was rendered into `{ $filename }`
+invoice-exported = invoice `{ $invoice }` was exported to { $format } into `{ $filename }`
+invoice-sent = invoice `{ $invoice }` was sent to { $recipients }
+invoice-eml-written = email with invoice `{ $invoice }` was written into `{ $filename }`
+invoices-built = { $rendered } rendered, { $skipped } up to date, { $failed } failed
+records-imported = { $imported } imported, { $skipped } skipped
+record-exists = record { $id } already exists (use --conflict skip or overwrite)
+payment-paid = { $date } { $amount } { $currency } - invoice { $invoice } is paid
+payment-partial = { $date } { $amount } { $currency } - invoice { $invoice } is partially paid, { $remaining } remaining
+payment-review = { $date } { $amount } { $currency } - exceeds { $remaining } remaining on invoice { $invoice }, check it (not recorded)
+payment-unmatched = { $date } { $amount } { $currency } - no matching invoice (variable symbol "{ $variable_symbol }", { $counterparty })
+payment-recorded = { $date } { $amount } { $currency } - already recorded
+payments-imported = { $matched } matched, { $unmatched } unmatched, { $review } to review, { $recorded } already recorded
+reminder-sent = reminder { $level } of invoice `{ $invoice }` ({ $days } days overdue) was rendered into `{ $filename }`
 response-msg =
     { $value ->
         [one] "{ $input }" has one Collatz step.
        *[other] "{ $input }" has { $value } Collatz steps.
     }
 not-a-port-number = `{ $port }` is not a valid port number
+invoice-created = Created invoice { $invoice }
+project-file-added = adding { $path }
+web-started = Starting ucelofka web on http://localhost:{ $port }
+
+## Errors
+
+account-not-found = Account { $id } not found.
+customer-not-found = Customer { $id } not found.
+entry-not-found = Entry { $id } not found.
+identity-not-found = Identity { $id } not found.
+invoice-not-found = Invoice { $id } not found.
+payment-not-found = Payment { $id } not found.
+template-not-found = Template { $id } not found.
+template-date-format-invalid = Invalid date format { $format }
+template-path-outside = { $path } is not within the templates directory
+record-not-found = Record { $id } not found.
+record-not-unique = record `{ $id }`: id is not unique
+record-index-error = record #{ $index }: { $error }
+record-id-error = record `{ $id }`: { $error }
+record-convert-failed = failed to convert { $path } - { $error }
+git-open-failed = Failed to open git repository ({ $error })
+git-index-failed = Failed to get repo index ({ $error })
+git-add-failed = Failed to add a file { $path } ({ $error })
+git-remove-failed = Failed to remove a file { $path } ({ $error })
+git-write-failed = Failed to write to index ({ $error })
+git-no-workdir = Git repository has no working directory
+git-outside-repo = File { $path } is not within the git repository
+git-create-failed = Failed to create git repository in { $path } ({ $error })
+invalid-path = Invalid path { $path }
+file-read-failed = Failed to read { $path } ({ $error })
+unknown-encoding = Unknown encoding { $encoding }
+file-write-failed = Failed to write { $path } ({ $error })
+dir-create-failed = Failed to create directory { $path } ({ $error })
+output-dir-failed = Failed to create output directory ({ $error })
+output-write-failed = Failed to write to output file ({ $error })
+output-pattern-invalid = Output pattern { $pattern } has to be a relative path within the output directory
+output-name-conflict = Invoice { $invoice } with template { $template } and invoice { $other_invoice } with template { $other_template } would be both rendered into { $filename } (use { "{" }template{ "}" } in the output pattern)
+fonts-dir-failed = Failed to read fonts directory ({ $error })
+font-not-found = No font found in { $path }
+font-read-failed = Failed to read font ({ $error })
+font-parse-failed = Failed to parse font ({ $error })
+font-subset-failed = Failed to subset font ({ $error })
+facturx-empty-text = Template { $template } produced no text for the PDF
+customer-no-email = Customer of invoice { $invoice } has no email (use --to)
+issuer-no-email = Issuer of invoice { $invoice } has no email (use --from)
+invoice-invalid-issue-date = Invoice { $invoice } has invalid issue date { $date } ({ $error })
+export-single-invoice = Exactly one invoice has to be selected for { $format } export
+unknown-conflict-policy = Unknown conflict policy { $value }
+unknown-period = Unknown period { $value }
+unknown-export-format = Unknown export format { $value }
+unknown-accounting-format = Unknown accounting format { $value }
+unknown-report-format = Unknown report format { $value }
+unknown-statement-format = Unknown statement format { $value }
+unknown-format = Unknown format { $value }
+format-not-detected = Unable to detect format of { $path }, use --format
+statement-format-not-detected = Unable to detect the statement format, use --format
+invalid-date = Invalid date { $date } ({ $error })
+invalid-amount = Invalid amount { $amount } ({ $error })
+invalid-locale = Invalid locale `{ $locale }` ({ $error })
+invalid-tax-period = Invalid tax period { $period } (use e.g. 2026Q3 or 2026-07)
+reminder-templates-failed = Failed to load reminder templates from templates/{ $dir } ({ $error })
+reminder-template-not-found = Reminder template { $template } not found in templates/{ $dir }
+smtp-invalid-port = Invalid SMTP port { $port } ({ $error })
+smtp-send-failed = Sending the email via { $host }:{ $port } failed ({ $error })
+smtp-credentials-unencrypted = SMTP credentials are not sent over an unencrypted connection (use --smtp-security starttls or tls)
+smtp-unknown-security = Unknown SMTP security { $value }
+invalid-email-address = Invalid email address { $address } ({ $error })
+command-run-failed = Failed to run { $path } ({ $error })
+command-write-failed = Failed to write to { $path }
+command-failed = { $path } failed ({ $status })
+statement-entry-without-amount = Statement entry without an amount
+statement-entry-without-date = Statement entry without a date
+statement-invalid-camt = Invalid CAMT statement ({ $error })
+statement-invalid-amount-on-line = Invalid amount on line { $line } ({ $error })
+statement-invalid-date-on-line = Invalid date on line { $line } ({ $error })
+statement-invalid-line = Invalid statement line :61:{ $line }
+vat-rate-unsupported = Invoice { $invoice } has unsupported VAT rate { $rate }
+isdoc-foreign-currency = Invoice { $invoice } is in { $currency }, ISDOC invoices of czech issuers can be exported only in CZK
+issuer-without-czech-tax-id = Issuer { $issuer } doesn't have a czech tax id
+no-invoices-in-period = No invoices issued in { $period }
+invoice-vat-not-in-currency = Invoice { $invoice } with VAT is not in { $currency }
+config-read-failed = Failed to read { $file } ({ $error })
+config-parse-failed = Failed to parse { $file } ({ $error })
+config-invalid-key = Invalid key '{ $key }' in { $file } (use a single character)
+config-duplicate-key = Key '{ $key }' in { $file } is used for more actions on the same page
+watch-failed = Failed to watch { $path } ({ $error })
+value-missing = missing value of `{ $column }`
+value-invalid = invalid value of `{ $column }` ({ $error })
+id-invalid = invalid id `{ $id }`
+id-not-unique = id is not unique
+price-invalid = invalid price
+currency-missing = missing currency
+vat-rate-invalid = invalid VAT rate
+invoice-without-rows = no invoice rows
+invoice-without-entries = invoice has no entries
+entry-different-currency = entry `{ $entry }` has a different currency than the invoice
+reminder-invalid = invalid reminder `{ $reminder }`
+reminder-level-invalid = invalid reminder `{ $reminder }` ({ $error })
+delivery-invalid = invalid delivery `{ $delivery }`
+json-invalid = invalid JSON ({ $error })
+
+## Command line help
+
+about-ucelofka = Simple program to issue invoices
+about-invoice = Invoice management
+about-invoice-create = Creates a new invoice
+about-invoice-list = Lists invoices
+about-invoice-ids = Lists invoice ids
+about-invoice-get = Get invoice
+about-invoice-render = Renders invoice
+about-invoice-build = Renders all (or selected) invoices which are not up to date
+about-invoice-export = Exports invoices into a machine readable format
+about-invoice-send = Sends the rendered invoice to the customer by email
+about-invoice-paid = Marks the invoice as paid
+about-invoice-import = Imports invoices
+about-remind = Renders payment reminders of the overdue invoices
+about-project = Manages data project
+about-project-make = Creates new data dir
+about-account = Account management
+about-account-list = Lists accounts
+about-account-ids = Lists accounts ids
+about-account-get = Get account
+about-account-export = Exports accounts
+about-account-import = Imports accounts
+about-customer = Customer management
+about-customer-list = Lists customers
+about-customer-ids = Lists customers ids
+about-customer-get = Get customer
+about-customer-export = Exports customers
+about-customer-import = Imports customers
+about-entry = Entry management
+about-entry-list = Lists entries
+about-entry-ids = Lists entries ids
+about-entry-get = Get entry
+about-entry-export = Exports entries
+about-entry-import = Imports entries
+about-entry-create = Create an entry
+about-identity = Identity management
+about-identity-list = Lists identities
+about-identity-ids = Lists identities ids
+about-identity-get = Get identity
+about-identity-export = Exports identities
+about-identity-import = Imports identities
+about-payment = Payment management
+about-payment-list = Lists payments
+about-payment-ids = Lists payments ids
+about-payment-get = Get payment
+about-payment-import = Imports incoming payments from a bank statement and matches them to invoices
+about-report = Reports over the invoices
+about-report-revenue = Invoiced amounts per period and currency
+about-report-aging = Unpaid amounts per customer by the days past the due date
+about-tax = VAT of the issued invoices
+about-tax-report = Taxable base and tax per VAT rate
+about-tax-control-statement = Czech VAT control statement (kontrolní hlášení) XML for the EPO portal
+about-template = Template management
+about-template-list = Lists templates
+about-template-get = Get template
+about-web = Starts webserver frontend for ucelofka
+about-completions = Completions generator
+about-ids = Prints ids of all entities
+about-tui = Starts ucelofka's tui
+help-output-pattern = Output filename pattern ({ "{" }id{ "}" }, { "{" }customer{ "}" }, { "{" }issue_date{ "}" }, { "{" }template{ "}" } and { "{" }ext{ "}" } are replaced)
+help-output = Path of the output file
+help-output-or-stdout = Path of the output file (prints to stdout by default)
+help-export-format = Export format
+help-import-input = Path of the imported file (- for stdin)
+help-import-format = Import format (detected from the file extension by default)
+help-import-conflict = What to do with records which already exist
+help-import-git = Add imported records to git
+help-account-id = Account id
+help-customer-id = Customer id
+help-entry-id = Entry id
+help-identity-id = Identity id
+help-invoice-id = Invoice id
+help-template-id = Template id
+help-invoice-create-git = Add newly created invoice to git
+help-invoice-due = Due time (in days)
+help-render-locale = Locale of the rendered invoice (defaults to the customer's one)
+help-render-facturx = Render a Factur-X PDF with embedded CII XML (HTML templates are converted to plain text)
+help-render-stdout = Print the rendered invoice to stdout
+help-output-git = Add newly created file to git
+help-build-invoice = Invoice id (all invoices are rendered when not set)
+help-build-locale = Locale of the rendered invoices (defaults to the customer's one)
+help-build-force = Render even the invoices which are up to date
+help-build-jobs = Number of parallel jobs (defaults to the number of CPUs)
+help-build-git = Add newly created files to git
+help-export-invoice = Invoice id (csv, json, pohoda and money-s3 export all invoices by default)
+help-export-from = Export only invoices issued since this date (YYYY-MM-DD)
+help-export-to = Export only invoices issued until this date (YYYY-MM-DD)
+help-export-stdout = Print the exported invoice to stdout
+help-send-template = Template of the attached invoice (up to date output is reused)
+help-send-email-template = Template of the email (placed in templates/emails, the first line is the subject)
+help-send-locale = Locale of the invoice and the email (defaults to the customer's one)
+help-send-from = Sender (defaults to the issuer's email)
+help-send-to = Recipients (defaults to the customer's emails)
+help-send-smtp = SMTP server (host[:port]) used instead of sendmail
+help-send-smtp-security = Encryption of the SMTP connection (none is meant for a local relay)
+help-send-smtp-user = SMTP user
+help-send-smtp-password = SMTP password
+help-send-sendmail = Sendmail compatible binary
+help-send-dry-run = Write the email into output/<invoice>.eml instead of sending it
+help-send-git = Add the changed invoice and rendered output to git
+help-paid-date = Date of the payment (YYYY-MM-DD, today by default)
+help-paid-git = Add the changed invoice to git
+help-remind-template = Reminder template (placed in templates/reminders)
+help-remind-date = Date of the reminders (YYYY-MM-DD, today by default)
+help-remind-interest = Yearly late interest rate in percent
+help-remind-interval = Minimal number of days between reminders of the same invoice
+help-remind-locale = Locale of the reminders (defaults to the customer's one)
+help-remind-git = Add the reminders and changed invoices to git
+help-project-target = Where it should be placed
+help-project-git = Initialize with a git repository
+help-entry-create-id = New entry ID
+help-entry-create-name = New entry name
+help-entry-create-price = New entry price
+help-entry-create-currency = New entry currency
+help-entry-create-details = New entry detail
+help-entry-create-vat = VAT rate in percent (price doesn't include VAT)
+help-entry-create-git = Add newly created entry to git
+help-payment-import-input = Path of the bank statement (- for stdin)
+help-payment-import-format = Statement format (detected from the content by default)
+help-payment-import-encoding = Encoding of the statement (windows-1250 is used for files which are not in UTF-8 by default)
+help-payment-import-dry-run = Only report the matches, don't record the payments
+help-payment-import-git = Add recorded payments to git
+help-report-format = Output format
+help-report-period = Length of the period
+help-report-by-customer = Split the amounts per customer
+help-report-from = Only invoices issued since this date (YYYY-MM-DD)
+help-report-to = Only invoices issued until this date (YYYY-MM-DD)
+help-report-date = Date the report is made for (YYYY-MM-DD, today by default)
+help-tax-period = Tax period - quarter (2026Q3) or month (2026-07)
+help-tax-legal-person = The taxpayer is a legal person (a natural person by default)
+help-tax-office = Code of the tax office
+help-tax-date = Date of filing (YYYY-MM-DD, today by default)
+help-web-port = Port which will be used for the web server
+help-completions-shell = For which shell the completion is supposed to be generated
+help-tui-git = Add records created in the tui to git
+help-lang = Language of the messages (e.g. cs, overrides the environment and config.yml)
+
+## TUI
+
+tui-page-dashboard = Dashboard
+tui-page-accounts = Accounts
+tui-page-customers = Customers
+tui-page-entries = Entries
+tui-page-identities = Identities
+tui-page-invoices = Invoices
+tui-page-templates = Templates
+tui-help-title = Keys - { $page } (Esc closes)
+tui-help-next-field = next/previous list or field
+tui-help-move = move in the list
+tui-help-scroll = scroll the list
+tui-help-first-last = first/last item
+tui-help-search = search in the list
+tui-help-help = show/hide help
+tui-help-pages = focus the list of the pages
+tui-help-quit = quit
+tui-help-edit = edit the selected record
+tui-help-delete = delete the selected record
+tui-help-confirm = confirm/cancel deleting
+tui-help-cancel-editing = cancel editing
+tui-help-sort-entries = sort by id, name or price
+tui-help-customer = filter by customer
+tui-help-year = filter by year
+tui-help-unpaid = show only unpaid
+tui-help-sort-invoices = sort by id, dates, customer or total
+tui-help-template = next template
+tui-help-render = render the invoice
+tui-help-preview = preview the invoice
+tui-help-close-preview = close the preview
+tui-help-invoice = fill with the next invoice
+tui-help-show-template = show the template
+tui-search-not-found = /{ $query } (not found)
+tui-list-failed = failed to list { $dir } - { $error }
+tui-form-hint = Multiple values are separated by `{ $separator }`, Tab moves to the next field, Esc cancels.
+tui-save = Save
+tui-confirm-delete = Delete { $name }? ({ $confirm }/{ $cancel })
+tui-create = <Create>
+tui-record-hint = { $edit } - edit, { $delete } - delete, { $search } - search
+tui-entry-hint = { $edit } - edit, { $delete } - delete, { $sort } - sort (by { $by }), { $search } - search
+tui-edit-account = Edit account
+tui-new-account = New account
+tui-edit-customer = Edit customer
+tui-new-customer = New customer
+tui-edit-entry = Edit entry
+tui-new-entry = New entry
+tui-edit-identity = Edit identity
+tui-new-identity = New identity
+tui-new-invoice = New invoice
+tui-id = ID:
+tui-name = Name:
+tui-bank-name = Bank name:
+tui-account-name = Account name:
+tui-account-number = Account number:
+tui-iban = IBAN:
+tui-bic = BIC:
+tui-currency = Currency:
+tui-address = Address:
+tui-email = Email:
+tui-phone = Phone:
+tui-www = WWW:
+tui-locale = Locale:
+tui-registration = Registration:
+tui-tax = Tax:
+tui-identifications = Identifications:
+tui-price = Price:
+tui-details = Details:
+tui-vat-rate = VAT rate:
+tui-issue-date = Issue date:
+tui-due-date = Due date:
+tui-paid = Paid:
+tui-issuer = Issuer:
+tui-customer = Customer:
+tui-identity = Identity:
+tui-account = Account:
+tui-entries = Entries:
+tui-entry-vat = { $price } + { $rate }% VAT
+tui-variable-symbol = Variable symbol:
+tui-total = Total:
+tui-sort-id = id
+tui-sort-name = name
+tui-sort-price = price
+tui-sort-issue-date = issue date
+tui-sort-due-date = due date
+tui-sort-customer = customer
+tui-sort-total = total
+tui-filter = Showing: { $shown }, sorted by { $sort }
+tui-filter-all = all
+tui-filter-unpaid = unpaid
+tui-filter-hint = { $filter } ({ $customer } - customer, { $year } - year, { $unpaid } - unpaid, { $sort } - sort, { $search } - search)
+tui-template = Template: { $template } ({ $next } - next, { $render } - render, { $preview } - preview)
+tui-no-templates = No templates found
+tui-rendered = Rendered into { $path }
+tui-preview-title = { $invoice } - { $template } (Esc closes)
+tui-template-filled = { $template } filled with invoice { $invoice } ({ $key } - next, Esc - template)
+tui-template-fill = { $template } ({ $key } - fill with an invoice)
+tui-create-records-first = Create a customer, an identity, an account and an entry first.
+tui-invalid-days = Invalid number of days { $days }
+tui-no-entries-selected = No entries selected
+tui-select-records = Select a customer, an identity and an account
+tui-entries-select = Entries (Space selects):
+tui-due-days = Due (days):
+tui-create-button = Create
+tui-invoiced = Invoiced
+tui-this-month = This month:
+tui-this-year = This year:
+tui-unpaid = Unpaid:
+tui-latest-invoices = Latest invoices
+tui-no-invoices = No invoices
+tui-overdue-invoices = Overdue invoices
+tui-no-overdue-invoices = No overdue invoices
+tui-overdue-invoice =
+    { $invoice } { $customer } - { $amount } { $currency } ({ $days ->
+        [one] { $days } day
+       *[other] { $days } days
+    } after { $due })
+
+## Template formatting
+
+number-decimal-separator = .
+number-group-separator = ,
+money =
+    { $currency ->
+        [USD] ${ $amount }
+        [EUR] €{ $amount }
+        [GBP] £{ $amount }
+       *[other] { $amount } { $currency }
+    }
+percent = { $value }%
+amount-in-words = { $words } and { $cents }/100
+date-format = %B %-d, %Y
+date-month-1 = January
+date-month-2 = February
+date-month-3 = March
+date-month-4 = April
+date-month-5 = May
+date-month-6 = June
+date-month-7 = July
+date-month-8 = August
+date-month-9 = September
+date-month-10 = October
+date-month-11 = November
+date-month-12 = December
+date-month-short-1 = Jan
+date-month-short-2 = Feb
+date-month-short-3 = Mar
+date-month-short-4 = Apr
+date-month-short-5 = May
+date-month-short-6 = Jun
+date-month-short-7 = Jul
+date-month-short-8 = Aug
+date-month-short-9 = Sep
+date-month-short-10 = Oct
+date-month-short-11 = Nov
+date-month-short-12 = Dec
+date-weekday-1 = Monday
+date-weekday-2 = Tuesday
+date-weekday-3 = Wednesday
+date-weekday-4 = Thursday
+date-weekday-5 = Friday
+date-weekday-6 = Saturday
+date-weekday-7 = Sunday
+date-weekday-short-1 = Mon
+date-weekday-short-2 = Tue
+date-weekday-short-3 = Wed
+date-weekday-short-4 = Thu
+date-weekday-short-5 = Fri
+date-weekday-short-6 = Sat
+date-weekday-short-7 = Sun
@@ -0,0 +1,435 @@
+is-not-a-directory = `{ $path }` nie je adresár
+path-not-exits = cesta `{ $path }` neexistuje
+data-directory-is-missing-subdir = adresáru s dátami `{ $dir_path }` chýba podadresár `{ $subdir_path }`
+data-directory-path = cesta k adresáru s dátami
+entries-different-invoice = Položky by nemali mať rôzne meny ({ $currencies })
+invoice-rendered = faktúra `{ $invoice }` bola vykreslená do `{ $filename }`
+invoice-exported = faktúra `{ $invoice }` bola exportovaná do { $format } do `{ $filename }`
+invoice-sent = faktúra `{ $invoice }` bola odoslaná na { $recipients }
+invoice-eml-written = email s faktúrou `{ $invoice }` bol zapísaný do `{ $filename }`
+invoices-built = vykreslených { $rendered }, aktuálnych { $skipped }, zlyhalo { $failed }
+records-imported = { $imported } importovaných, { $skipped } preskočených
+record-exists = záznam { $id } už existuje (použite --conflict skip alebo overwrite)
+payment-paid = { $date } { $amount } { $currency } - faktúra { $invoice } je uhradená
+payment-partial = { $date } { $amount } { $currency } - faktúra { $invoice } je uhradená čiastočne, zostáva { $remaining }
+payment-review = { $date } { $amount } { $currency } - prevyšuje zostávajúcich { $remaining } faktúry { $invoice }, skontrolujte ju (nezaznamenané)
+payment-unmatched = { $date } { $amount } { $currency } - žiadna zodpovedajúca faktúra (variabilný symbol "{ $variable_symbol }", { $counterparty })
+payment-recorded = { $date } { $amount } { $currency } - už zaznamenané
+payments-imported = { $matched } spárovaných, { $unmatched } nespárovaných, { $review } na kontrolu, { $recorded } už zaznamenaných
+reminder-sent = upomienka { $level } faktúry `{ $invoice }` ({ $days } dní po splatnosti) bola vykreslená do `{ $filename }`
+response-msg =
+    { $value ->
+        [one] "{ $input }" has one Collatz step.
+       *[other] "{ $input }" has { $value } Collatz steps.
+    }
+not-a-port-number = `{ $port }` nie je platné číslo portu
+invoice-created = Vytvorená faktúra { $invoice }
+project-file-added = pridávam { $path }
+web-started = Spúšťam webové rozhranie ucelofky na http://localhost:{ $port }
+
+## Errors
+
+account-not-found = Účet { $id } nebol nájdený.
+customer-not-found = Zákazník { $id } nebol nájdený.
+entry-not-found = Položka { $id } nebola nájdená.
+identity-not-found = Identita { $id } nebola nájdená.
+invoice-not-found = Faktúra { $id } nebola nájdená.
+payment-not-found = Platba { $id } nebola nájdená.
+template-not-found = Šablóna { $id } nebola nájdená.
+template-date-format-invalid = Neplatný formát dátumu { $format }
+template-path-outside = { $path } nie je vnútri adresára so šablónami
+record-not-found = Záznam { $id } nebol nájdený.
+record-not-unique = záznam `{ $id }`: id nie je unikátne
+record-index-error = záznam #{ $index }: { $error }
+record-id-error = záznam `{ $id }`: { $error }
+record-convert-failed = nepodarilo sa previesť { $path } - { $error }
+git-open-failed = Nepodarilo sa otvoriť git repozitár ({ $error })
+git-index-failed = Nepodarilo sa načítať index repozitára ({ $error })
+git-add-failed = Nepodarilo sa pridať súbor { $path } ({ $error })
+git-remove-failed = Nepodarilo sa odobrať súbor { $path } ({ $error })
+git-write-failed = Nepodarilo sa zapísať index ({ $error })
+git-no-workdir = Git repozitár nemá pracovný adresár
+git-outside-repo = Súbor { $path } nie je vnútri git repozitára
+git-create-failed = Nepodarilo sa vytvoriť git repozitár v { $path } ({ $error })
+invalid-path = Neplatná cesta { $path }
+file-read-failed = Nepodarilo sa prečítať { $path } ({ $error })
+unknown-encoding = Neznáme kódovanie { $encoding }
+file-write-failed = Nepodarilo sa zapísať { $path } ({ $error })
+dir-create-failed = Nepodarilo sa vytvoriť adresár { $path } ({ $error })
+output-dir-failed = Nepodarilo sa vytvoriť výstupný adresár ({ $error })
+output-write-failed = Nepodarilo sa zapísať výstupný súbor ({ $error })
+output-pattern-invalid = Vzor výstupu { $pattern } musí byť relatívna cesta vnútri výstupného adresára
+output-name-conflict = Faktúra { $invoice } so šablónou { $template } a faktúra { $other_invoice } so šablónou { $other_template } by sa vytvorili do rovnakého súboru { $filename } (použite { "{" }template{ "}" } vo vzore výstupu)
+fonts-dir-failed = Nepodarilo sa prečítať adresár s fontmi ({ $error })
+font-not-found = V { $path } nebol nájdený žiadny font
+font-read-failed = Nepodarilo sa prečítať font ({ $error })
+font-parse-failed = Nepodarilo sa spracovať font ({ $error })
+font-subset-failed = Nepodarilo sa vytvoriť podmnožinu fontu ({ $error })
+facturx-empty-text = Šablóna { $template } nevytvorila žiadny text pre PDF
+customer-no-email = Zákazník faktúry { $invoice } nemá email (použite --to)
+issuer-no-email = Vystaviteľ faktúry { $invoice } nemá email (použite --from)
+invoice-invalid-issue-date = Faktúra { $invoice } má neplatný dátum vystavenia { $date } ({ $error })
+export-single-invoice = Pre export do { $format } musí byť vybraná práve jedna faktúra
+unknown-conflict-policy = Neznáme riešenie konfliktov { $value }
+unknown-period = Neznáme obdobie { $value }
+unknown-export-format = Neznámy formát exportu { $value }
+unknown-accounting-format = Neznámy účtovný formát { $value }
+unknown-report-format = Neznámy formát prehľadu { $value }
+unknown-statement-format = Neznámy formát výpisu { $value }
+unknown-format = Neznámy formát { $value }
+format-not-detected = Nepodarilo sa rozpoznať formát { $path }, použite --format
+statement-format-not-detected = Nepodarilo sa rozpoznať formát výpisu, použite --format
+invalid-date = Neplatný dátum { $date } ({ $error })
+invalid-amount = Neplatná suma { $amount } ({ $error })
+invalid-locale = Neplatné locale `{ $locale }` ({ $error })
+invalid-tax-period = Neplatné zdaňovacie obdobie { $period } (použite napr. 2026Q3 alebo 2026-07)
+reminder-templates-failed = Nepodarilo sa načítať šablóny upomienok z templates/{ $dir } ({ $error })
+reminder-template-not-found = Šablóna upomienky { $template } nebola v templates/{ $dir } nájdená
+smtp-invalid-port = Neplatný SMTP port { $port } ({ $error })
+smtp-send-failed = Odoslanie emailu cez { $host }:{ $port } zlyhalo ({ $error })
+smtp-credentials-unencrypted = Prihlasovacie údaje SMTP sa neposielajú cez nešifrované spojenie (použite --smtp-security starttls alebo tls)
+smtp-unknown-security = Neznáme zabezpečenie SMTP { $value }
+invalid-email-address = Neplatná emailová adresa { $address } ({ $error })
+command-run-failed = Nepodarilo sa spustiť { $path } ({ $error })
+command-write-failed = Nepodarilo sa zapísať do { $path }
+command-failed = { $path } zlyhal ({ $status })
+statement-entry-without-amount = Položka výpisu nemá sumu
+statement-entry-without-date = Položka výpisu nemá dátum
+statement-invalid-camt = Neplatný CAMT výpis ({ $error })
+statement-invalid-amount-on-line = Neplatná suma na riadku { $line } ({ $error })
+statement-invalid-date-on-line = Neplatný dátum na riadku { $line } ({ $error })
+statement-invalid-line = Neplatný riadok výpisu :61:{ $line }
+vat-rate-unsupported = Faktúra { $invoice } má nepodporovanú sadzbu DPH { $rate }
+isdoc-foreign-currency = Faktúra { $invoice } je v { $currency }, ISDOC faktúry českých dodávateľov je možné exportovať len v CZK
+issuer-without-czech-tax-id = Vystaviteľ { $issuer } nemá české DIČ
+no-invoices-in-period = V období { $period } neboli vystavené žiadne faktúry
+invoice-vat-not-in-currency = Faktúra { $invoice } s DPH nie je v { $currency }
+config-read-failed = Nepodarilo sa prečítať { $file } ({ $error })
+config-parse-failed = Nepodarilo sa spracovať { $file } ({ $error })
+config-invalid-key = Neplatný kláves '{ $key }' v { $file } (použite jeden znak)
+config-duplicate-key = Kláves '{ $key }' v { $file } je použitý pre viac akcií na tej istej stránke
+watch-failed = Nepodarilo sa sledovať { $path } ({ $error })
+value-missing = chýba hodnota `{ $column }`
+value-invalid = neplatná hodnota `{ $column }` ({ $error })
+id-invalid = neplatné id `{ $id }`
+id-not-unique = id nie je unikátne
+price-invalid = neplatná cena
+currency-missing = chýba mena
+vat-rate-invalid = neplatná sadzba DPH
+invoice-without-rows = žiadne riadky faktúry
+invoice-without-entries = faktúra nemá žiadne položky
+entry-different-currency = položka `{ $entry }` má inú menu než faktúra
+reminder-invalid = neplatná upomienka `{ $reminder }`
+reminder-level-invalid = neplatná upomienka `{ $reminder }` ({ $error })
+delivery-invalid = neplatné doručenie `{ $delivery }`
+json-invalid = neplatný JSON ({ $error })
+
+## Command line help
+
+about-ucelofka = Jednoduchý program na vystavovanie faktúr
+about-invoice = Správa faktúr
+about-invoice-create = Vytvorí novú faktúru
+about-invoice-list = Vypíše faktúry
+about-invoice-ids = Vypíše id faktúr
+about-invoice-get = Zobrazí faktúru
+about-invoice-render = Vykreslí faktúru
+about-invoice-build = Vykreslí všetky (alebo vybrané) faktúry, ktoré nie sú aktuálne
+about-invoice-export = Exportuje faktúry do strojovo čitateľného formátu
+about-invoice-send = Odošle vykreslenú faktúru zákazníkovi emailom
+about-invoice-paid = Označí faktúru ako uhradenú
+about-invoice-import = Importuje faktúry
+about-remind = Vykreslí upomienky faktúr po splatnosti
+about-project = Správa dátového projektu
+about-project-make = Vytvorí nový adresár s dátami
+about-account = Správa účtov
+about-account-list = Vypíše účty
+about-account-ids = Vypíše id účtov
+about-account-get = Zobrazí účet
+about-account-export = Exportuje účty
+about-account-import = Importuje účty
+about-customer = Správa zákazníkov
+about-customer-list = Vypíše zákazníkov
+about-customer-ids = Vypíše id zákazníkov
+about-customer-get = Zobrazí zákazníka
+about-customer-export = Exportuje zákazníkov
+about-customer-import = Importuje zákazníkov
+about-entry = Správa položiek
+about-entry-list = Vypíše položky
+about-entry-ids = Vypíše id položiek
+about-entry-get = Zobrazí položku
+about-entry-export = Exportuje položky
+about-entry-import = Importuje položky
+about-entry-create = Vytvorí položku
+about-identity = Správa identít
+about-identity-list = Vypíše identity
+about-identity-ids = Vypíše id identít
+about-identity-get = Zobrazí identitu
+about-identity-export = Exportuje identity
+about-identity-import = Importuje identity
+about-payment = Správa platieb
+about-payment-list = Vypíše platby
+about-payment-ids = Vypíše id platieb
+about-payment-get = Zobrazí platbu
+about-payment-import = Importuje prichádzajúce platby z bankového výpisu a spáruje ich s faktúrami
+about-report = Prehľady faktúr
+about-report-revenue = Fakturované sumy podľa obdobia a meny
+about-report-aging = Neuhradené sumy zákazníkov podľa počtu dní po splatnosti
+about-tax = DPH vystavených faktúr
+about-tax-report = Základ dane a daň podľa sadzieb DPH
+about-tax-control-statement = XML českého kontrolného výkazu DPH (kontrolní hlášení) pre portál EPO
+about-template = Správa šablón
+about-template-list = Vypíše šablóny
+about-template-get = Zobrazí šablónu
+about-web = Spustí webové rozhranie ucelofky
+about-completions = Generátor dopĺňania pre shell
+about-ids = Vypíše id všetkých entít
+about-tui = Spustí textové rozhranie ucelofky
+help-output-pattern = Vzor názvu výstupného súboru (nahrádza sa { "{" }id{ "}" }, { "{" }customer{ "}" }, { "{" }issue_date{ "}" }, { "{" }template{ "}" } a { "{" }ext{ "}" })
+help-output = Cesta k výstupnému súboru
+help-output-or-stdout = Cesta k výstupnému súboru (predvolene sa vypisuje na štandardný výstup)
+help-export-format = Formát exportu
+help-import-input = Cesta k importovanému súboru (- pre štandardný vstup)
+help-import-format = Formát importu (predvolene podľa prípony súboru)
+help-import-conflict = Čo robiť so záznamami, ktoré už existujú
+help-import-git = Pridať importované záznamy do gitu
+help-account-id = Id účtu
+help-customer-id = Id zákazníka
+help-entry-id = Id položky
+help-identity-id = Id identity
+help-invoice-id = Id faktúry
+help-template-id = Id šablóny
+help-invoice-create-git = Pridať novú faktúru do gitu
+help-invoice-due = Doba splatnosti (v dňoch)
+help-render-locale = Locale vykreslenej faktúry (predvolene locale zákazníka)
+help-render-facturx = Vykresliť PDF Factur-X s vloženým CII XML (HTML šablóny sú prevedené na čistý text)
+help-render-stdout = Vypísať vykreslenú faktúru na štandardný výstup
+help-output-git = Pridať nový súbor do gitu
+help-build-invoice = Id faktúry (predvolene sa vykreslia všetky faktúry)
+help-build-locale = Locale vykreslených faktúr (predvolene locale zákazníka)
+help-build-force = Vykresliť aj faktúry, ktoré sú aktuálne
+help-build-jobs = Počet paralelných úloh (predvolene počet CPU)
+help-build-git = Pridať nové súbory do gitu
+help-export-invoice = Id faktúry (csv, json, pohoda a money-s3 predvolene exportujú všetky faktúry)
+help-export-from = Exportovať len faktúry vystavené od tohto dátumu (RRRR-MM-DD)
+help-export-to = Exportovať len faktúry vystavené do tohto dátumu (RRRR-MM-DD)
+help-export-stdout = Vypísať exportovanú faktúru na štandardný výstup
+help-send-template = Šablóna priloženej faktúry (aktuálny výstup sa použije znova)
+help-send-email-template = Šablóna emailu (v templates/emails, prvý riadok je predmet)
+help-send-locale = Locale faktúry a emailu (predvolene locale zákazníka)
+help-send-from = Odosielateľ (predvolene email vystaviteľa)
+help-send-to = Príjemcovia (predvolene emaily zákazníka)
+help-send-smtp = SMTP server (host[:port]) použitý namiesto sendmailu
+help-send-smtp-security = Šifrovanie SMTP spojenia (none je určené pre lokálny relay)
+help-send-smtp-user = Používateľ SMTP
+help-send-smtp-password = Heslo SMTP
+help-send-sendmail = Program kompatibilný so sendmailom
+help-send-dry-run = Zapísať email do output/<faktúra>.eml namiesto odoslania
+help-send-git = Pridať zmenenú faktúru a vykreslený výstup do gitu
+help-paid-date = Dátum platby (RRRR-MM-DD, predvolene dnes)
+help-paid-git = Pridať zmenenú faktúru do gitu
+help-remind-template = Šablóna upomienky (v templates/reminders)
+help-remind-date = Dátum upomienok (RRRR-MM-DD, predvolene dnes)
+help-remind-interest = Ročný úrok z omeškania v percentách
+help-remind-interval = Najmenší počet dní medzi upomienkami tej istej faktúry
+help-remind-locale = Locale upomienok (predvolene locale zákazníka)
+help-remind-git = Pridať upomienky a zmenené faktúry do gitu
+help-project-target = Kam sa má projekt umiestniť
+help-project-git = Inicializovať s git repozitárom
+help-entry-create-id = Id novej položky
+help-entry-create-name = Názov novej položky
+help-entry-create-price = Cena novej položky
+help-entry-create-currency = Mena novej položky
+help-entry-create-details = Detail novej položky
+help-entry-create-vat = Sadzba DPH v percentách (cena neobsahuje DPH)
+help-entry-create-git = Pridať novú položku do gitu
+help-payment-import-input = Cesta k bankovému výpisu (- pre štandardný vstup)
+help-payment-import-format = Formát výpisu (predvolene podľa obsahu)
+help-payment-import-encoding = Kódovanie výpisu (pre súbory, ktoré nie sú v UTF-8, sa predvolene použije windows-1250)
+help-payment-import-dry-run = Len vypísať spárovanie, platby nezaznamenávať
+help-payment-import-git = Pridať zaznamenané platby do gitu
+help-report-format = Výstupný formát
+help-report-period = Dĺžka obdobia
+help-report-by-customer = Rozdeliť sumy podľa zákazníkov
+help-report-from = Len faktúry vystavené od tohto dátumu (RRRR-MM-DD)
+help-report-to = Len faktúry vystavené do tohto dátumu (RRRR-MM-DD)
+help-report-date = Dátum, ku ktorému je prehľad zostavený (RRRR-MM-DD, predvolene dnes)
+help-tax-period = Zdaňovacie obdobie - štvrťrok (2026Q3) alebo mesiac (2026-07)
+help-tax-legal-person = Platiteľ je právnická osoba (predvolene fyzická osoba)
+help-tax-office = Kód finančného úradu
+help-tax-date = Dátum podania (RRRR-MM-DD, predvolene dnes)
+help-web-port = Port, ktorý použije webový server
+help-completions-shell = Pre ktorý shell sa má dopĺňanie vygenerovať
+help-tui-git = Pridávať záznamy vytvorené v textovom rozhraní do gitu
+help-lang = Jazyk správ (napr. cs, má prednosť pred prostredím a config.yml)
+
+## TUI
+
+tui-page-dashboard = Prehľad
+tui-page-accounts = Účty
+tui-page-customers = Zákazníci
+tui-page-entries = Položky
+tui-page-identities = Identity
+tui-page-invoices = Faktúry
+tui-page-templates = Šablóny
+tui-help-title = Klávesy - { $page } (Esc zatvorí)
+tui-help-next-field = ďalší/predchádzajúci zoznam alebo pole
+tui-help-move = pohyb v zozname
+tui-help-scroll = posun zoznamu
+tui-help-first-last = prvá/posledná položka
+tui-help-search = hľadanie v zozname
+tui-help-help = zobraziť/skryť nápovedu
+tui-help-pages = prejsť na zoznam stránok
+tui-help-quit = koniec
+tui-help-edit = upraviť vybraný záznam
+tui-help-delete = zmazať vybraný záznam
+tui-help-confirm = potvrdiť/zrušiť zmazanie
+tui-help-cancel-editing = zrušiť úpravy
+tui-help-sort-entries = zoradiť podľa id, názvu alebo ceny
+tui-help-customer = filtrovať podľa zákazníka
+tui-help-year = filtrovať podľa roku
+tui-help-unpaid = len nezaplatené
+tui-help-sort-invoices = zoradiť podľa id, dátumu, zákazníka alebo sumy
+tui-help-template = ďalšia šablóna
+tui-help-render = vytvoriť faktúru
+tui-help-preview = náhľad faktúry
+tui-help-close-preview = zatvoriť náhľad
+tui-help-invoice = vyplniť ďalšou faktúrou
+tui-help-show-template = zobraziť šablónu
+tui-search-not-found = /{ $query } (nenájdené)
+tui-list-failed = nepodarilo sa načítať { $dir } - { $error }
+tui-form-hint = Viac hodnôt sa oddeľuje `{ $separator }`, Tab prejde na ďalšie pole, Esc zruší úpravy.
+tui-save = Uložiť
+tui-confirm-delete = Zmazať { $name }? ({ $confirm }/{ $cancel })
+tui-create = <Vytvoriť>
+tui-record-hint = { $edit } - upraviť, { $delete } - zmazať, { $search } - hľadať
+tui-entry-hint = { $edit } - upraviť, { $delete } - zmazať, { $sort } - zoradiť (podľa: { $by }), { $search } - hľadať
+tui-edit-account = Upraviť účet
+tui-new-account = Nový účet
+tui-edit-customer = Upraviť zákazníka
+tui-new-customer = Nový zákazník
+tui-edit-entry = Upraviť položku
+tui-new-entry = Nová položka
+tui-edit-identity = Upraviť identitu
+tui-new-identity = Nová identita
+tui-new-invoice = Nová faktúra
+tui-id = ID:
+tui-name = Názov:
+tui-bank-name = Banka:
+tui-account-name = Názov účtu:
+tui-account-number = Číslo účtu:
+tui-iban = IBAN:
+tui-bic = BIC:
+tui-currency = Mena:
+tui-address = Adresa:
+tui-email = E-mail:
+tui-phone = Telefón:
+tui-www = WWW:
+tui-locale = Jazyk:
+tui-registration = IČO:
+tui-tax = DIČ:
+tui-identifications = Identifikácia:
+tui-price = Cena:
+tui-details = Podrobnosti:
+tui-vat-rate = Sadzba DPH:
+tui-issue-date = Dátum vystavenia:
+tui-due-date = Dátum splatnosti:
+tui-paid = Zaplatené:
+tui-issuer = Dodávateľ:
+tui-customer = Zákazník:
+tui-identity = Identita:
+tui-account = Účet:
+tui-entries = Položky:
+tui-entry-vat = { $price } + { $rate }{ "\u00A0" }% DPH
+tui-variable-symbol = Variabilný symbol:
+tui-total = Spolu:
+tui-sort-id = id
+tui-sort-name = názov
+tui-sort-price = cena
+tui-sort-issue-date = dátum vystavenia
+tui-sort-due-date = dátum splatnosti
+tui-sort-customer = zákazník
+tui-sort-total = suma
+tui-filter = Zobrazené: { $shown }, zoradené podľa: { $sort }
+tui-filter-all = všetko
+tui-filter-unpaid = nezaplatené
+tui-filter-hint = { $filter } ({ $customer } - zákazník, { $year } - rok, { $unpaid } - nezaplatené, { $sort } - zoradenie, { $search } - hľadať)
+tui-template = Šablóna: { $template } ({ $next } - ďalšia, { $render } - vytvoriť, { $preview } - náhľad)
+tui-no-templates = Nenašli sa žiadne šablóny
+tui-rendered = Vytvorené do { $path }
+tui-preview-title = { $invoice } - { $template } (Esc zatvorí)
+tui-template-filled = { $template } vyplnená faktúrou { $invoice } ({ $key } - ďalšia, Esc - šablóna)
+tui-template-fill = { $template } ({ $key } - vyplniť faktúrou)
+tui-create-records-first = Najskôr vytvorte zákazníka, identitu, účet a položku.
+tui-invalid-days = Neplatný počet dní { $days }
+tui-no-entries-selected = Nie sú vybrané žiadne položky
+tui-select-records = Vyberte zákazníka, identitu a účet
+tui-entries-select = Položky (Medzerník vyberá):
+tui-due-days = Splatnosť (dni):
+tui-create-button = Vytvoriť
+tui-invoiced = Vyfakturované
+tui-this-month = Tento mesiac:
+tui-this-year = Tento rok:
+tui-unpaid = Nezaplatené:
+tui-latest-invoices = Posledné faktúry
+tui-no-invoices = Žiadne faktúry
+tui-overdue-invoices = Faktúry po splatnosti
+tui-no-overdue-invoices = Žiadne faktúry po splatnosti
+tui-overdue-invoice =
+    { $invoice } { $customer } - { $amount } { $currency } ({ $days ->
+        [one] { $days } deň
+        [few] { $days } dni
+       *[other] { $days } dní
+    } po { $due })
+
+## Template formatting
+
+number-decimal-separator = ,
+number-group-separator = { "\u00A0" }
+money =
+    { $currency ->
+        [EUR] { $amount } €
+        [CZK] { $amount } Kč
+        [USD] { $amount } US$
+       *[other] { $amount } { $currency }
+    }
+percent = { $value }{ "\u00A0" }%
+amount-in-words = { $words } { $cents }/100
+date-format = %-d. %B %Y
+date-month-1 = januára
+date-month-2 = februára
+date-month-3 = marca
+date-month-4 = apríla
+date-month-5 = mája
+date-month-6 = júna
+date-month-7 = júla
+date-month-8 = augusta
+date-month-9 = septembra
+date-month-10 = októbra
+date-month-11 = novembra
+date-month-12 = decembra
+date-month-short-1 = jan
+date-month-short-2 = feb
+date-month-short-3 = mar
+date-month-short-4 = apr
+date-month-short-5 = máj
+date-month-short-6 = jún
+date-month-short-7 = júl
+date-month-short-8 = aug
+date-month-short-9 = sep
+date-month-short-10 = okt
+date-month-short-11 = nov
+date-month-short-12 = dec
+date-weekday-1 = pondelok
+date-weekday-2 = utorok
+date-weekday-3 = streda
+date-weekday-4 = štvrtok
+date-weekday-5 = piatok
+date-weekday-6 = sobota
+date-weekday-7 = nedeľa
+date-weekday-short-1 = po
+date-weekday-short-2 = ut
+date-weekday-short-3 = st
+date-weekday-short-4 = št
+date-weekday-short-5 = pi
+date-weekday-short-6 = so
+date-weekday-short-7 = ne
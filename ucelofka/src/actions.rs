@@ -4,5 +4,10 @@ pub mod entry;
 pub mod identity;
 pub mod ids;
 pub mod invoice;
+pub mod payment;
 pub mod project;
+pub mod records;
+pub mod reminder;
+pub mod report;
+pub mod tax;
 pub mod template;
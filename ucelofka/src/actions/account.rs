@@ -1,9 +1,13 @@
 use anyhow::{anyhow, Result};
+use fluent::fluent_args;
 use std::path::Path;
 
 use crate::{
+    actions::records::{self, Conflict, ImportSummary},
     data::account::{Account, Accounts},
     storage::Records,
+    tabular::{self, Format},
+    translations::get_message,
 };
 
 pub fn ids(data_path: &Path) -> Result<String> {
@@ -17,7 +21,24 @@ pub fn list(data_path: &Path) -> Result<Accounts> {
 }
 
 pub fn get(data_path: &Path, id: &str) -> Result<Account> {
-    list(data_path)?
-        .get(id)
-        .ok_or_else(|| anyhow!("Account {} not found.", id))
+    list(data_path)?.get(id).ok_or_else(|| {
+        anyhow!(get_message(
+            "account-not-found",
+            Some(fluent_args!["id" => id.to_string()])
+        ))
+    })
+}
+
+pub fn export(data_path: &Path, format: Format) -> Result<String> {
+    tabular::to_string(list(data_path)?.records(), format)
+}
+
+pub fn import(
+    data_path: &Path,
+    data: &str,
+    format: Format,
+    conflict: Conflict,
+    git: bool,
+) -> Result<ImportSummary> {
+    records::import::<Account, Accounts>(data_path, "accounts", data, format, conflict, git)
 }
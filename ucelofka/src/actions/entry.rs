@@ -1,10 +1,14 @@
 use anyhow::{anyhow, Result};
+use fluent::fluent_args;
 use git2::Repository;
 use std::path::Path;
 
 use crate::{
+    actions::records::{self, Conflict, ImportSummary},
     data::entry::{Entries, Entry},
     storage::{Record, Records},
+    tabular::{self, Format},
+    translations::get_message,
 };
 
 pub fn ids(data_path: &Path) -> Result<String> {
@@ -18,11 +22,15 @@ pub fn list(data_path: &Path) -> Result<Entries> {
 }
 
 pub fn get(data_path: &Path, id: &str) -> Result<Entry> {
-    list(data_path)?
-        .get(id)
-        .ok_or_else(|| anyhow!("Entry {} not found.", id))
+    list(data_path)?.get(id).ok_or_else(|| {
+        anyhow!(get_message(
+            "entry-not-found",
+            Some(fluent_args!["id" => id.to_string()])
+        ))
+    })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create(
     data_path: &Path,
     id: String,
@@ -30,16 +38,20 @@ pub fn create(
     price: f32,
     currency: String,
     details: Vec<String>,
+    vat_rate: Option<f32>,
     git: bool,
 ) -> Result<Entry> {
     let entry_path = data_path.join(Path::new("entries"));
-    let new_entry = Entry::new(id, name, price, currency, details);
+    let mut new_entry = Entry::new(id, name, price, currency, details);
+    new_entry.vat_rate = vat_rate;
 
     let mut repository = if git {
-        Some(
-            Repository::open(data_path)
-                .map_err(|err| anyhow!("Faield to open git repository {}", err))?,
-        )
+        Some(Repository::open(data_path).map_err(|err| {
+            anyhow!(get_message(
+                "git-open-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?)
     } else {
         None
     };
@@ -51,21 +63,42 @@ pub fn create(
     if let Some(repo) = repository.as_mut() {
         let new_path = Path::new("entries").join(new_entry.filename());
 
-        let mut index = repo
-            .index()
-            .map_err(|err| anyhow!("Failed to get repo index ({})", err))?;
+        let mut index = repo.index().map_err(|err| {
+            anyhow!(get_message(
+                "git-index-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?;
         index.add_path(&new_path).map_err(|err| {
-            anyhow!(
-                "Failed to add a file {} ({})",
-                new_path.to_string_lossy(),
-                err
-            )
+            anyhow!(get_message(
+                "git-add-failed",
+                Some(
+                    fluent_args!["path" => new_path.to_string_lossy(), "error" => err.to_string()]
+                )
+            ))
         })?;
 
-        index
-            .write()
-            .map_err(|err| anyhow!("Failed to write to index ({})", err))?;
+        index.write().map_err(|err| {
+            anyhow!(get_message(
+                "git-write-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?;
     }
 
     Ok(new_entry)
 }
+
+pub fn export(data_path: &Path, format: Format) -> Result<String> {
+    tabular::to_string(list(data_path)?.records(), format)
+}
+
+pub fn import(
+    data_path: &Path,
+    data: &str,
+    format: Format,
+    conflict: Conflict,
+    git: bool,
+) -> Result<ImportSummary> {
+    records::import::<Entry, Entries>(data_path, "entries", data, format, conflict, git)
+}
@@ -1,27 +1,36 @@
 use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
 use fluent::fluent_args;
 use git2::Repository;
 use minijinja::{context, Environment, Value};
-use std::{collections::HashSet, fs, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs,
+    path::{Component, Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::SystemTime,
+};
 
 use crate::{
-    actions,
+    actions::{
+        self,
+        records::{self, Conflict, ImportSummary},
+    },
     data::{
-        invoice::{Invoice, Invoices},
-        template::Templates,
+        invoice::{latest::Delivery, Invoice, Invoices},
+        template::{html_to_text, Templates},
     },
+    export::{self, Accounting, Format},
+    mail::{self, Transport},
     storage::{Record, Records},
-    translations::get_message,
+    tabular, tax, templating,
+    translations::{get_message, resolve_language},
 };
 
-struct WrappedInvoice(Invoice);
-
-impl From<Invoice> for WrappedInvoice {
-    fn from(val: Invoice) -> Self {
-        WrappedInvoice(val)
-    }
-}
-
 pub fn create(
     data_path: &Path,
     customer: &str,
@@ -69,23 +78,34 @@ pub fn create(
 
     if git {
         let git_path = Path::new("invoices").join(Path::new(&format!("{}.yml", new_invoice.id)));
-        let repo = Repository::open(data_path)
-            .map_err(|err| anyhow!("Faield to open git repository {}", err))?;
+        let repo = Repository::open(data_path).map_err(|err| {
+            anyhow!(get_message(
+                "git-open-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?;
 
-        let mut index = repo
-            .index()
-            .map_err(|err| anyhow!("Failed to get repo index ({})", err))?;
+        let mut index = repo.index().map_err(|err| {
+            anyhow!(get_message(
+                "git-index-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?;
 
         index.add_path(&git_path).map_err(|err| {
-            anyhow!(
-                "Failed to add a file {} ({})",
-                git_path.to_string_lossy(),
-                err
-            )
+            anyhow!(get_message(
+                "git-add-failed",
+                Some(
+                    fluent_args!["path" => git_path.to_string_lossy(), "error" => err.to_string()]
+                )
+            ))
+        })?;
+        index.write().map_err(|err| {
+            anyhow!(get_message(
+                "git-write-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
         })?;
-        index
-            .write()
-            .map_err(|err| anyhow!("Failed to write to index ({})", err))?;
     }
 
     Ok(new_invoice.id.to_string())
@@ -102,78 +122,777 @@ pub fn list(data_path: &Path) -> Result<Invoices> {
 }
 
 pub fn get(data_path: &Path, id: &str) -> Result<Invoice> {
-    list(data_path)?
-        .get(id)
-        .ok_or_else(|| anyhow!("Invoice {} not found.", id))
+    list(data_path)?.get(id).ok_or_else(|| {
+        anyhow!(get_message(
+            "invoice-not-found",
+            Some(fluent_args!["id" => id.to_string()])
+        ))
+    })
+}
+
+/// Marks the invoice as paid
+pub fn mark_paid(data_path: &Path, id: &str, date: NaiveDate, git: bool) -> Result<()> {
+    let mut invoice = get(data_path, id)?;
+    invoice.paid = Some(date.to_string());
+    let invoice_path = data_path.join(Path::new("invoices"));
+    invoice.store(&invoice_path)?;
+    if git {
+        add_outputs_to_git(data_path, &[invoice_path.join(invoice.filename())])?;
+    }
+    Ok(())
+}
+
+fn render_invoice(
+    jinja_env: &Environment,
+    data: &Invoice,
+    template: &str,
+    locale: Option<&str>,
+) -> Result<String> {
+    render_with_context(
+        jinja_env,
+        data,
+        template,
+        locale,
+        Value::from_serializable(&data),
+    )
+}
+
+/// Renders a template of the invoice where `ctx` contains the invoice data
+/// (possibly extended with other values)
+pub(crate) fn render_with_context(
+    jinja_env: &Environment,
+    data: &Invoice,
+    template: &str,
+    locale: Option<&str>,
+    ctx: Value,
+) -> Result<String> {
+    let currency = data.entries[0].currency.clone();
+    // explicit locale takes precedence over the one preferred by the customer
+    let locale = match locale.map(String::from).or(data.customer.locale.clone()) {
+        Some(locale) => locale,
+        None => resolve_language(None)?.to_string(),
+    };
+    // Inject currency, locale and the VAT summary into context
+    let vat = Value::from_serializable(&tax::Summary::new(data));
+    let ctx = context!(currency => currency, locale => locale, vat => vat, ..ctx);
+    let tmpl = jinja_env.get_template(template)?;
+    Ok(tmpl.render(ctx)?)
+}
+
+pub const DEFAULT_OUTPUT_PATTERN: &str = "{id}.{ext}";
+
+/// Makes the value usable within a filename
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Creates an output filename from the pattern
+///
+/// Available placeholders are `{id}`, `{customer}`, `{issue_date}`, `{template}` and `{ext}`.
+/// The extension is taken from the template unless set.
+pub(crate) fn output_name(
+    invoice: &Invoice,
+    template: &str,
+    pattern: &str,
+    extension: Option<&str>,
+) -> Result<String> {
+    let template_path = Path::new(template);
+    let suffix: String = match (extension, template_path.extension()) {
+        (Some(extension), _) => extension.to_string(),
+        (None, Some(os_string)) => os_string
+            .to_str()
+            .ok_or_else(|| {
+                anyhow!(get_message(
+                    "invalid-path",
+                    Some(fluent_args!["path" => template])
+                ))
+            })?
+            .to_string(),
+        (None, None) => String::new(),
+    };
+    let stem = template_path
+        .file_stem()
+        .and_then(|e| e.to_str())
+        .ok_or_else(|| {
+            anyhow!(get_message(
+                "invalid-path",
+                Some(fluent_args!["path" => template])
+            ))
+        })?;
+
+    // the outputs have to stay within the output directory
+    let outside = Path::new(pattern)
+        .components()
+        .any(|e| !matches!(e, Component::Normal(_) | Component::CurDir));
+    if outside {
+        return Err(anyhow!(get_message(
+            "output-pattern-invalid",
+            Some(fluent_args!["pattern" => pattern])
+        )));
+    }
+
+    Ok(pattern
+        .replace("{id}", &invoice.id.to_string())
+        .replace("{customer}", &sanitize(&invoice.customer.name))
+        .replace("{issue_date}", &sanitize(&invoice.issue_date))
+        .replace("{template}", &sanitize(stem))
+        .replace("{ext}", &suffix))
+}
+
+pub(crate) fn add_outputs_to_git(data_path: &Path, output_paths: &[PathBuf]) -> Result<()> {
+    let repo = Repository::open(data_path).map_err(|err| {
+        anyhow!(get_message(
+            "git-open-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| anyhow!(get_message("git-no-workdir", None)))?
+        .canonicalize()?;
+
+    let mut index = repo.index().map_err(|err| {
+        anyhow!(get_message(
+            "git-index-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })?;
+
+    for output_path in output_paths {
+        let git_path = output_path
+            .canonicalize()?
+            .strip_prefix(&workdir)
+            .map(Path::to_path_buf)
+            .map_err(|_| {
+                anyhow!(get_message(
+                    "git-outside-repo",
+                    Some(fluent_args!["path" => output_path.to_string_lossy()])
+                ))
+            })?;
+        index.add_path(&git_path).map_err(|err| {
+            anyhow!(get_message(
+                "git-add-failed",
+                Some(
+                    fluent_args!["path" => git_path.to_string_lossy(), "error" => err.to_string()]
+                )
+            ))
+        })?;
+    }
+    index.write().map_err(|err| {
+        anyhow!(get_message(
+            "git-write-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })?;
+
+    Ok(())
 }
 
-pub fn render(data_path: &Path, invoice: &str, template: &str, git: bool) -> Result<String> {
+pub(crate) fn write_output<C: AsRef<[u8]>>(output_path: &Path, output: C) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(|err| {
+            anyhow!(get_message(
+                "output-dir-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?;
+    }
+    fs::write(output_path, output).map_err(|err| {
+        anyhow!(get_message(
+            "output-write-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })
+}
+
+/// Renders the invoice into a string
+pub fn render_string(
+    data_path: &Path,
+    invoice: &str,
+    template: &str,
+    locale: Option<&str>,
+) -> Result<String> {
     // get the invoice data
     let invoice_path = data_path.join(Path::new("invoices"));
     let invoices = Invoices::load(invoice_path.as_path())?;
-    let data = invoices
-        .get(invoice)
-        .ok_or_else(|| anyhow!("failed to find invoice {}", invoice))?;
+    let data = invoices.get(invoice).ok_or_else(|| {
+        anyhow!(get_message(
+            "invoice-not-found",
+            Some(fluent_args!["id" => invoice.to_string()])
+        ))
+    })?;
 
     // Load the templates
     let templates_path = data_path.join(Path::new("templates"));
     let templates = Templates::load(&templates_path)?;
 
-    let template_instance = templates
-        .get(template)?
-        .ok_or_else(|| anyhow!("failed to find template {}", template))?;
+    let template_instance = templates.get(template)?.ok_or_else(|| {
+        anyhow!(get_message(
+            "template-not-found",
+            Some(fluent_args!["id" => template.to_string()])
+        ))
+    })?;
 
     // Render
-    let mut jinja_env = Environment::new();
-    // Read template file
-    jinja_env.add_template_owned(&template_instance.name, template_instance.raw.unwrap())?;
+    let jinja_env = templating::environment(data_path);
+    render_invoice(&jinja_env, &data, &template_instance.name, locale)
+}
 
-    let ctx = Value::from_serializable(&data);
-    let currency = data.entries[0].currency.clone();
-    // Inject currency into context
-    let ctx = context!(currency => currency, ..ctx);
-    let tmpl = jinja_env.get_template(&template_instance.name)?;
-    let output = match tmpl.render(ctx) {
-        Ok(data) => data,
-        Err(err) => return Err(err.into()),
-    };
+/// Renders the invoice into a file
+///
+/// The file is placed to `output` directory and named according to the `pattern`
+/// unless the `output` path is set.
+#[allow(clippy::too_many_arguments)]
+pub fn render(
+    data_path: &Path,
+    invoice: &str,
+    template: &str,
+    locale: Option<&str>,
+    output: Option<&Path>,
+    pattern: &str,
+    git: bool,
+) -> Result<String> {
+    let rendered = render_string(data_path, invoice, template, locale)?;
 
     // Store output
-    let suffix: String = match Path::new(template).extension() {
-        Some(os_string) => os_string
-            .to_str()
-            .ok_or_else(|| anyhow!("Wrong path string"))?
-            .to_string(),
-        None => String::new(),
+    let output_path = match output {
+        Some(path) => path.to_path_buf(),
+        None => {
+            let data = get(data_path, invoice)?;
+            data_path
+                .join(Path::new("output"))
+                .join(output_name(&data, template, pattern, None)?)
+        }
     };
-    let output_name = format!("{}.{}", invoice, suffix);
+    write_output(&output_path, rendered)?;
+
+    if git {
+        add_outputs_to_git(data_path, &[output_path.clone()])?;
+    }
+
+    Ok(output_path.to_string_lossy().into())
+}
+
+/// Email templates are placed in this subdir of `templates`
+pub const EMAIL_TEMPLATES_DIR: &str = "emails";
+pub const DEFAULT_EMAIL_TEMPLATE: &str = "invoice.txt";
+
+/// Outcome of sending an invoice
+#[derive(Debug)]
+pub enum Sent {
+    Delivered(Vec<String>),
+    /// The message was only written into a file (dry run)
+    Written(PathBuf),
+}
+
+/// Sends the rendered invoice to the customer by email
+///
+/// The invoice is rendered unless the output is newer than the invoice and the templates.
+/// The first line of the rendered email template is used as the subject. When no `transport`
+/// is set the message is written into `output/<invoice>.eml` instead.
+#[allow(clippy::too_many_arguments)]
+pub fn send(
+    data_path: &Path,
+    invoice: &str,
+    template: &str,
+    email_template: &str,
+    locale: Option<&str>,
+    pattern: &str,
+    from: Option<&str>,
+    to: &[String],
+    transport: Option<&Transport>,
+    git: bool,
+) -> Result<Sent> {
+    let mut data = get(data_path, invoice)?;
+    let recipients = if to.is_empty() {
+        data.customer.email.clone()
+    } else {
+        to.to_vec()
+    };
+    if recipients.is_empty() {
+        return Err(anyhow!(get_message(
+            "customer-no-email",
+            Some(fluent_args!["invoice" => invoice.to_string()])
+        )));
+    }
+    let from = match from {
+        Some(from) => from.to_string(),
+        None => data
+            .issuer
+            .email
+            .first()
+            .map(|email| format!("{} <{}>", data.issuer.name, email))
+            .ok_or_else(|| {
+                anyhow!(get_message(
+                    "issuer-no-email",
+                    Some(fluent_args!["invoice" => invoice.to_string()])
+                ))
+            })?,
+    };
+
     let output_path = data_path
         .join(Path::new("output"))
-        .join(Path::new(&output_name[..]));
+        .join(output_name(&data, template, pattern, None)?);
+    let mut changed = vec![];
+    // the invoice or the templates might have been changed since the last rendering
+    if !is_up_to_date(
+        data_path,
+        &data,
+        &output_path,
+        shared_inputs_modified(data_path)?,
+    ) {
+        render(data_path, invoice, template, locale, None, pattern, false)?;
+        changed.push(output_path.clone());
+    }
+    let attachment = mail::Attachment::new(
+        output_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        fs::read(&output_path)
+            .map_err(|err| anyhow!(get_message("file-read-failed", Some(fluent_args!["path" => output_path.to_string_lossy(), "error" => err.to_string()]))))?,
+    );
+
+    let jinja_env = templating::environment(data_path);
+    let email = render_with_context(
+        &jinja_env,
+        &data,
+        &format!("{}/{}", EMAIL_TEMPLATES_DIR, email_template),
+        locale,
+        Value::from_serializable(&data),
+    )?;
+    let (subject, body) = email.split_once('\n').unwrap_or((&email, ""));
+    let message = mail::Message {
+        from,
+        to: recipients.clone(),
+        subject: subject.trim().to_string(),
+        body: body.trim_start_matches(['\r', '\n']).to_string(),
+        attachments: vec![attachment],
+    };
 
-    fs::write(output_path.clone(), output)
-        .map_err(|err| anyhow!("failed to write to output file: {}", err))?;
+    let Some(transport) = transport else {
+        let eml_path = data_path
+            .join(Path::new("output"))
+            .join(format!("{}.eml", data.id));
+        write_output(&eml_path, message.to_eml())?;
+        return Ok(Sent::Written(eml_path));
+    };
+    transport.send(&message)?;
 
+    data.sent.push(Delivery {
+        date: chrono::Local::now().date_naive().to_string(),
+        recipients: recipients.clone(),
+    });
+    let invoice_path = data_path.join(Path::new("invoices"));
+    data.store(&invoice_path)?;
+    changed.push(invoice_path.join(data.filename()));
     if git {
-        let git_path = Path::new("output").join(Path::new(&output_name));
-        let repo = Repository::open(data_path)
-            .map_err(|err| anyhow!("Faield to open git repository {}", err))?;
+        add_outputs_to_git(data_path, &changed)?;
+    }
 
-        let mut index = repo
-            .index()
-            .map_err(|err| anyhow!("Failed to get repo index ({})", err))?;
+    Ok(Sent::Delivered(recipients))
+}
 
-        index.add_path(&git_path).map_err(|err| {
-            anyhow!(
-                "Failed to add a file {} ({})",
-                output_path.to_string_lossy(),
-                err
+/// Loads the font used in PDF outputs (the first font found in `fonts` directory)
+fn load_font(data_path: &Path) -> Result<Vec<u8>> {
+    let fonts_path = data_path.join(Path::new("fonts"));
+    let mut fonts: Vec<PathBuf> = fonts_path
+        .read_dir()
+        .map_err(|err| {
+            anyhow!(get_message(
+                "fonts-dir-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|e| e.to_str())
+                .map(|e| e.eq_ignore_ascii_case("ttf") || e.eq_ignore_ascii_case("otf"))
+                .unwrap_or(false)
+        })
+        .collect();
+    fonts.sort();
+    let font = fonts.first().ok_or_else(|| {
+        anyhow!(get_message(
+            "font-not-found",
+            Some(fluent_args!["path" => fonts_path.to_string_lossy()])
+        ))
+    })?;
+    fs::read(font).map_err(|err| {
+        anyhow!(get_message(
+            "font-read-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })
+}
+
+/// Renders the invoice as a plain text, HTML templates are converted to text
+pub fn render_text(
+    data_path: &Path,
+    invoice: &str,
+    template: &str,
+    locale: Option<&str>,
+) -> Result<String> {
+    let rendered = render_string(data_path, invoice, template, locale)?;
+    let is_html = Path::new(template)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("html") || e.eq_ignore_ascii_case("htm"));
+    if is_html {
+        Ok(html_to_text(rendered.as_bytes()))
+    } else {
+        Ok(rendered)
+    }
+}
+
+/// Renders the invoice into a Factur-X PDF with embedded CII XML
+///
+/// The text of the rendered template is placed into the PDF.
+#[allow(clippy::too_many_arguments)]
+pub fn render_facturx(
+    data_path: &Path,
+    invoice: &str,
+    template: &str,
+    locale: Option<&str>,
+    output: Option<&Path>,
+    pattern: &str,
+    git: bool,
+) -> Result<String> {
+    let rendered = render_text(data_path, invoice, template, locale)?;
+    if rendered.trim().is_empty() {
+        return Err(anyhow!(get_message(
+            "facturx-empty-text",
+            Some(fluent_args!["template" => template])
+        )));
+    }
+    let data = get(data_path, invoice)?;
+    let pdf = export::facturx::export(&data, &rendered, &load_font(data_path)?)?;
+
+    let output_path = match output {
+        Some(path) => path.to_path_buf(),
+        None => data_path.join(Path::new("output")).join(output_name(
+            &data,
+            template,
+            pattern,
+            Some("pdf"),
+        )?),
+    };
+    write_output(&output_path, pdf)?;
+
+    if git {
+        add_outputs_to_git(data_path, &[output_path.clone()])?;
+    }
+
+    Ok(output_path.to_string_lossy().into())
+}
+
+/// Exports the invoice into a machine readable format
+pub fn export_string(data_path: &Path, invoice: &str, format: Format) -> Result<String> {
+    export::export(&get(data_path, invoice)?, format)
+}
+
+/// Exports the invoice into a file (`output/<invoice>.<format extension>` by default)
+pub fn export(
+    data_path: &Path,
+    invoice: &str,
+    format: Format,
+    output: Option<&Path>,
+    git: bool,
+) -> Result<String> {
+    let exported = export_string(data_path, invoice, format)?;
+
+    let output_path = match output {
+        Some(path) => path.to_path_buf(),
+        None => {
+            data_path
+                .join(Path::new("output"))
+                .join(format!("{}.{}", invoice, format.extension()))
+        }
+    };
+    write_output(&output_path, exported)?;
+
+    if git {
+        add_outputs_to_git(data_path, &[output_path.clone()])?;
+    }
+
+    Ok(output_path.to_string_lossy().into())
+}
+
+/// Parses a date of the invoice data (`YYYY-MM-DD`)
+pub fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|err| {
+        anyhow!(get_message(
+            "invalid-date",
+            Some(fluent_args!["date" => value, "error" => err.to_string()])
+        ))
+    })
+}
+
+/// Selects the invoices by id (all when none are selected) and by the issue date range
+pub fn select(
+    data_path: &Path,
+    invoices: &[String],
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<Vec<Invoice>> {
+    let records = if invoices.is_empty() {
+        list(data_path)?.records().to_vec()
+    } else {
+        invoices
+            .iter()
+            .map(|invoice| get(data_path, invoice))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let mut selected = vec![];
+    for record in records {
+        let issue_date =
+            NaiveDate::parse_from_str(&record.issue_date, "%Y-%m-%d").map_err(|err| {
+                anyhow!(get_message("invoice-invalid-issue-date", Some(fluent_args!["invoice" => record.id, "date" => record.issue_date.as_str(), "error" => err.to_string()])))
+            })?;
+        if from.is_none_or(|from| from <= issue_date) && to.is_none_or(|to| issue_date <= to) {
+            selected.push(record);
+        }
+    }
+    Ok(selected)
+}
+
+/// Exports the selected invoices into a flat format
+pub fn export_table(
+    data_path: &Path,
+    invoices: &[String],
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    format: tabular::Format,
+) -> Result<String> {
+    tabular::to_string(&select(data_path, invoices, from, to)?, format)
+}
+
+/// Exports the selected invoices for the accounting software
+pub fn export_accounting(
+    data_path: &Path,
+    invoices: &[String],
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    format: Accounting,
+) -> Result<String> {
+    export::export_accounting(&select(data_path, invoices, from, to)?, format)
+}
+
+pub fn import(
+    data_path: &Path,
+    data: &str,
+    format: tabular::Format,
+    conflict: Conflict,
+    git: bool,
+) -> Result<ImportSummary> {
+    records::import::<Invoice, Invoices>(data_path, "invoices", data, format, conflict, git)
+}
+
+#[derive(Debug, Default)]
+pub struct BuildSummary {
+    pub rendered: Vec<String>,
+    pub skipped: Vec<String>,
+    pub failed: Vec<(String, anyhow::Error)>,
+}
+
+impl fmt::Display for BuildSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (output, err) in &self.failed {
+            writeln!(f, "{}: {}", output, err)?;
+        }
+        write!(
+            f,
+            "{}",
+            get_message(
+                "invoices-built",
+                Some(fluent_args![
+                    "rendered" => self.rendered.len(),
+                    "skipped" => self.skipped.len(),
+                    "failed" => self.failed.len()
+                ])
             )
+        )
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Time of the last change of the files within the directory and its subdirectories
+fn newest_modified(path: &Path) -> Result<Option<SystemTime>> {
+    let mut newest = None;
+    for entry in path.read_dir()? {
+        let path = entry?.path();
+        let modified = if path.is_dir() {
+            newest_modified(&path)?
+        } else {
+            modified(&path)
+        };
+        newest = newest.max(modified);
+    }
+    Ok(newest)
+}
+
+/// Time of the last change of the shared template inputs (templates and translations)
+///
+/// Any template can be included by another one so all of them are considered.
+fn shared_inputs_modified(data_path: &Path) -> Result<Option<SystemTime>> {
+    let templates_modified = newest_modified(&data_path.join(Path::new("templates")))?;
+    let locales_path = data_path.join(Path::new("locales"));
+    let locales_modified = if locales_path.is_dir() {
+        newest_modified(&locales_path)?
+    } else {
+        None
+    };
+    Ok(templates_modified.max(locales_modified))
+}
+
+/// Output is newer than the invoice and the shared template inputs
+fn is_up_to_date(
+    data_path: &Path,
+    invoice: &Invoice,
+    output_path: &Path,
+    shared_modified: Option<SystemTime>,
+) -> bool {
+    let invoice_modified = modified(
+        &data_path
+            .join(Path::new("invoices"))
+            .join(Path::new(&invoice.filename())),
+    );
+    match (modified(output_path), invoice_modified.max(shared_modified)) {
+        (Some(output), Some(input)) => output > input,
+        _ => false,
+    }
+}
+
+/// Renders invoices with given templates in parallel
+///
+/// Outputs which are newer than the invoice and the template are skipped
+/// unless `force` is set. When no invoices are passed all invoices are rendered.
+#[allow(clippy::too_many_arguments)]
+pub fn build(
+    data_path: &Path,
+    invoices: &[String],
+    templates: &[String],
+    locale: Option<&str>,
+    pattern: &str,
+    force: bool,
+    jobs: usize,
+    git: bool,
+) -> Result<BuildSummary> {
+    let all_invoices = list(data_path)?;
+    let selected: Vec<Invoice> = if invoices.is_empty() {
+        all_invoices.invoices
+    } else {
+        invoices
+            .iter()
+            .map(|id| {
+                all_invoices.get(id).ok_or_else(|| {
+                    anyhow!(get_message(
+                        "invoice-not-found",
+                        Some(fluent_args!["id" => id.to_string()])
+                    ))
+                })
+            })
+            .collect::<Result<_>>()?
+    };
+
+    let templates_path = data_path.join(Path::new("templates"));
+    let available_templates = Templates::load(&templates_path)?;
+    for template in templates {
+        available_templates.get(template)?.ok_or_else(|| {
+            anyhow!(get_message(
+                "template-not-found",
+                Some(fluent_args!["id" => template.to_string()])
+            ))
         })?;
-        index
-            .write()
-            .map_err(|err| anyhow!("Failed to write to index ({})", err))?;
+    }
+    let shared_modified = shared_inputs_modified(data_path)?;
+
+    // prepare the work which needs to be done
+    let mut summary = BuildSummary::default();
+    let mut todo: Vec<(&Invoice, &str, String)> = vec![];
+    // the outputs would overwrite each other
+    let mut outputs: HashMap<String, (String, &str)> = HashMap::new();
+    for invoice in &selected {
+        for template in templates {
+            let output_name = output_name(invoice, template, pattern, None)?;
+            if let Some((other_invoice, other_template)) = outputs.insert(
+                output_name.clone(),
+                (invoice.id.to_string(), template.as_str()),
+            ) {
+                return Err(anyhow!(get_message(
+                    "output-name-conflict",
+                    Some(fluent_args![
+                        "filename" => output_name,
+                        "invoice" => invoice.id.to_string(),
+                        "template" => template.as_str(),
+                        "other_invoice" => other_invoice,
+                        "other_template" => other_template
+                    ])
+                )));
+            }
+            let output_path = data_path.join(Path::new("output")).join(&output_name);
+            if !force && is_up_to_date(data_path, invoice, &output_path, shared_modified) {
+                summary.skipped.push(output_name);
+            } else {
+                todo.push((invoice, template, output_name));
+            }
+        }
+    }
+
+    let jinja_env = templating::environment(data_path);
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<(String, Result<()>)>> = Mutex::new(vec![]);
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1).min(todo.len()) {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::SeqCst);
+                let (invoice, template, output_name) = match todo.get(idx) {
+                    Some(item) => item,
+                    None => break,
+                };
+                let result =
+                    render_invoice(&jinja_env, invoice, template, locale).and_then(|output| {
+                        write_output(
+                            &data_path.join(Path::new("output")).join(output_name),
+                            output,
+                        )
+                    });
+                results.lock().unwrap().push((output_name.clone(), result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    for (output_name, result) in results {
+        match result {
+            Ok(()) => summary.rendered.push(output_name),
+            Err(err) => summary.failed.push((output_name, err)),
+        }
+    }
+
+    if git && !summary.rendered.is_empty() {
+        let output_paths: Vec<PathBuf> = summary
+            .rendered
+            .iter()
+            .map(|name| data_path.join(Path::new("output")).join(name))
+            .collect();
+        add_outputs_to_git(data_path, &output_paths)?;
     }
 
-    Ok(output_path.to_str().unwrap().into())
+    Ok(summary)
 }
@@ -0,0 +1,297 @@
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    actions::invoice,
+    data::{
+        invoice::Invoice,
+        payment::{self, Payment, Payments},
+    },
+    statement::{self, normalize_variable_symbol, Format, Transaction},
+    storage::{Record, Records},
+    translations::get_message,
+};
+
+/// Amounts which differ less than this are considered to be equal
+const TOLERANCE: f32 = 0.005;
+
+pub fn ids(data_path: &Path) -> Result<String> {
+    let data = list(data_path)?;
+    Ok(data.ids().join("\n"))
+}
+
+pub fn list(data_path: &Path) -> Result<Payments> {
+    let payment_path = data_path.join(Path::new("payments"));
+    Payments::load(payment_path.as_path())
+}
+
+pub fn get(data_path: &Path, id: &str) -> Result<Payment> {
+    list(data_path)?.get(id).ok_or_else(|| {
+        anyhow!(get_message(
+            "payment-not-found",
+            Some(fluent_args!["id" => id.to_string()])
+        ))
+    })
+}
+
+/// Sums of the recorded payments per invoice
+pub fn paid_amounts(payments: &Payments) -> HashMap<u64, f32> {
+    let mut res = HashMap::new();
+    for payment in payments.records() {
+        *res.entry(payment.invoice).or_insert(0.0) += payment.amount;
+    }
+    res
+}
+
+/// Part of the invoice total which is not covered by the payments
+pub fn outstanding(invoice: &Invoice, paid: &HashMap<u64, f32>) -> f32 {
+    invoice.billing.total - paid.get(&invoice.id).copied().unwrap_or(0.0)
+}
+
+/// Invoice is neither marked as paid nor covered by the payments
+pub fn is_unpaid(invoice: &Invoice, paid: &HashMap<u64, f32>) -> bool {
+    invoice.paid.is_none() && outstanding(invoice, paid) >= TOLERANCE
+}
+
+/// Result of matching a single incoming payment
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Paid {
+        invoice: u64,
+    },
+    Partial {
+        invoice: u64,
+        remaining: f32,
+    },
+    /// The variable symbol matches, but the amount exceeds the remaining balance
+    Review {
+        invoice: u64,
+        remaining: f32,
+    },
+    Unmatched,
+    /// The payment was imported before
+    Recorded,
+}
+
+#[derive(Debug, Default)]
+pub struct Reconciliation {
+    pub items: Vec<(Transaction, Status)>,
+}
+
+impl Reconciliation {
+    pub fn count<F: Fn(&Status) -> bool>(&self, filter: F) -> usize {
+        self.items
+            .iter()
+            .filter(|(_, status)| filter(status))
+            .count()
+    }
+}
+
+impl fmt::Display for Reconciliation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (transaction, status) in &self.items {
+            let mut args = fluent_args![
+                "date" => transaction.date.to_string(),
+                "amount" => format!("{:.2}", transaction.amount),
+                "currency" => transaction.currency.as_str()
+            ];
+            let message_id = match status {
+                Status::Paid { invoice } => {
+                    args.set("invoice", invoice.to_string());
+                    "payment-paid"
+                }
+                Status::Partial { invoice, remaining } => {
+                    args.set("invoice", invoice.to_string());
+                    args.set("remaining", format!("{:.2}", remaining));
+                    "payment-partial"
+                }
+                Status::Review { invoice, remaining } => {
+                    args.set("invoice", invoice.to_string());
+                    args.set("remaining", format!("{:.2}", remaining));
+                    "payment-review"
+                }
+                Status::Unmatched => {
+                    args.set(
+                        "variable_symbol",
+                        transaction.variable_symbol.clone().unwrap_or_default(),
+                    );
+                    args.set("counterparty", transaction.counterparty.as_str());
+                    "payment-unmatched"
+                }
+                Status::Recorded => "payment-recorded",
+            };
+            writeln!(f, "{}", get_message(message_id, Some(args)))?;
+        }
+
+        let unmatched = self.count(|status| *status == Status::Unmatched);
+        let recorded = self.count(|status| *status == Status::Recorded);
+        let review = self.count(|status| matches!(status, Status::Review { .. }));
+        write!(
+            f,
+            "{}",
+            get_message(
+                "payments-imported",
+                Some(fluent_args![
+                    "matched" => self.items.len() - unmatched - recorded - review,
+                    "unmatched" => unmatched,
+                    "review" => review,
+                    "recorded" => recorded
+                ])
+            )
+        )
+    }
+}
+
+/// Payment ids are derived from the transaction so the same statement can be imported repeatedly
+///
+/// Transactions without a reference are distinguished by their `occurrence` among the transactions
+/// with the same date, variable symbol and amount within the statement.
+fn payment_id(transaction: &Transaction, occurrence: usize) -> String {
+    let reference = if transaction.reference.is_empty() {
+        let id = format!(
+            "{}-{:.0}",
+            transaction.variable_symbol.as_deref().unwrap_or("0"),
+            transaction.amount * 100.0
+        );
+        if occurrence > 1 {
+            format!("{}-{}", id, occurrence)
+        } else {
+            id
+        }
+    } else {
+        transaction.reference.clone()
+    };
+    let reference: String = reference
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{}-{}", transaction.date, reference)
+}
+
+/// Finds the invoice by the variable symbol or (when the symbol is missing)
+/// by the amount if it matches exactly one unpaid invoice
+///
+/// Amounts of the payments matched by the variable symbol are checked by the caller.
+fn find_invoice<'a>(
+    invoices: &'a [Invoice],
+    paid: &HashMap<u64, f32>,
+    transaction: &Transaction,
+) -> Option<&'a Invoice> {
+    let remaining =
+        |invoice: &Invoice| invoice.billing.total - paid.get(&invoice.id).copied().unwrap_or(0.0);
+    let same_currency = |invoice: &&Invoice| invoice.billing.currency == transaction.currency;
+
+    if let Some(symbol) = &transaction.variable_symbol {
+        return invoices.iter().filter(same_currency).find(|invoice| {
+            normalize_variable_symbol(&invoice.billing.variable_symbol).as_ref() == Some(symbol)
+        });
+    }
+
+    let mut candidates = invoices
+        .iter()
+        .filter(same_currency)
+        .filter(|invoice| (remaining(invoice) - transaction.amount).abs() < TOLERANCE);
+    match (candidates.next(), candidates.next()) {
+        (Some(invoice), None) => Some(invoice),
+        _ => None,
+    }
+}
+
+/// Matches incoming payments from the bank statement to the invoices
+///
+/// Matched payments are stored into `payments` dir unless `dry_run` is set.
+pub fn import(
+    data_path: &Path,
+    data: &str,
+    format: Option<Format>,
+    dry_run: bool,
+    git: bool,
+) -> Result<Reconciliation> {
+    let format = format
+        .or_else(|| Format::detect(data))
+        .ok_or_else(|| anyhow!(get_message("statement-format-not-detected", None)))?;
+    let transactions = statement::parse(data, format)?;
+
+    let invoices = invoice::list(data_path)?.records().to_vec();
+    let payments = list(data_path)?;
+    let mut paid = paid_amounts(&payments);
+
+    let mut reconciliation = Reconciliation::default();
+    let mut new_payments = vec![];
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    for transaction in transactions.into_iter().filter(|t| t.credit) {
+        let occurrence = occurrences.entry(payment_id(&transaction, 1)).or_insert(0);
+        *occurrence += 1;
+        let id = payment_id(&transaction, *occurrence);
+        if payments.get(&id).is_some() || new_payments.iter().any(|p: &Payment| p.id == id) {
+            reconciliation.items.push((transaction, Status::Recorded));
+            continue;
+        }
+        let Some(invoice) = find_invoice(&invoices, &paid, &transaction) else {
+            reconciliation.items.push((transaction, Status::Unmatched));
+            continue;
+        };
+
+        let paid_amount = paid.entry(invoice.id).or_insert(0.0);
+        let remaining = invoice.billing.total - *paid_amount;
+        // the amount doesn't fit the invoice so it is not recorded
+        if transaction.amount - remaining >= TOLERANCE {
+            let status = Status::Review {
+                invoice: invoice.id,
+                remaining,
+            };
+            reconciliation.items.push((transaction, status));
+            continue;
+        }
+        *paid_amount += transaction.amount;
+        let remaining = remaining - transaction.amount;
+        let status = if remaining.abs() < TOLERANCE {
+            Status::Paid {
+                invoice: invoice.id,
+            }
+        } else {
+            Status::Partial {
+                invoice: invoice.id,
+                remaining,
+            }
+        };
+
+        new_payments.push(Payment {
+            _version: payment::CURRENT_VERSION,
+            id,
+            invoice: invoice.id,
+            date: transaction.date.to_string(),
+            amount: transaction.amount,
+            currency: transaction.currency.clone(),
+            variable_symbol: invoice.billing.variable_symbol.clone(),
+            counterparty: transaction.counterparty.clone(),
+            reference: transaction.reference.clone(),
+        });
+        reconciliation.items.push((transaction, status));
+    }
+
+    if dry_run || new_payments.is_empty() {
+        return Ok(reconciliation);
+    }
+
+    let payment_path = data_path.join(Path::new("payments"));
+    fs::create_dir_all(&payment_path)?;
+    for payment in &new_payments {
+        payment.store(&payment_path)?;
+    }
+
+    if git {
+        let payment_paths: Vec<PathBuf> = new_payments
+            .iter()
+            .map(|payment| payment_path.join(payment.filename()))
+            .collect();
+        invoice::add_outputs_to_git(data_path, &payment_paths)?;
+    }
+
+    Ok(reconciliation)
+}
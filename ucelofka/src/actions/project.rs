@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Result};
+use fluent::fluent_args;
 use git2::Repository;
 use include_dir::{include_dir, Dir, DirEntry};
 use std::{
@@ -6,21 +7,29 @@ use std::{
     path::Path,
 };
 
+use crate::translations::get_message;
+
 static DEFAULTS: Dir = include_dir!("default/");
 
 pub fn make(data_path: &Path, git: bool) -> Result<()> {
     // create dirs
-    create_dir_all(data_path).map_err(|err| anyhow!("failed to create project root {}", err))?;
+    create_dir_all(data_path).map_err(|err| {
+        anyhow!(get_message(
+            "dir-create-failed",
+            Some(fluent_args!["path" => data_path.to_string_lossy(), "error" => err.to_string()])
+        ))
+    })?;
 
     // initialize git repository
     let mut repo = if git {
         // initialize a repo
         Some(Repository::init(data_path).map_err(|err| {
-            anyhow!(
-                "failed to create git repository in {} ({})",
-                data_path.to_string_lossy(),
-                err
-            )
+            anyhow!(get_message(
+                "git-create-failed",
+                Some(
+                    fluent_args!["path" => data_path.to_string_lossy(), "error" => err.to_string()]
+                )
+            ))
         })?)
     } else {
         None
@@ -38,29 +47,37 @@ pub fn make(data_path: &Path, git: bool) -> Result<()> {
                 let relative_path = file.path();
                 let full = Path::new(data_path).join(relative_path);
                 write(full.clone(), file.contents())
-                    .map_err(|err| anyhow!("failed to write file {}", err))?;
+                    .map_err(|err| anyhow!(get_message("file-write-failed", Some(fluent_args!["path" => full.to_string_lossy(), "error" => err.to_string()]))))?;
                 if let Some(repo_instance) = repo.as_mut() {
-                    println!("adding {:?}", relative_path);
+                    println!(
+                        "{}",
+                        get_message(
+                            "project-file-added",
+                            Some(fluent_args!["path" => relative_path.to_string_lossy()])
+                        )
+                    );
                     // add a file to repository
-                    let mut index = repo_instance
-                        .index()
-                        .map_err(|err| anyhow!("Failed to get repo index ({})", err))?;
+                    let mut index = repo_instance.index().map_err(|err| {
+                        anyhow!(get_message(
+                            "git-index-failed",
+                            Some(fluent_args!["error" => err.to_string()])
+                        ))
+                    })?;
                     index.add_path(relative_path).map_err(|err| {
-                        anyhow!(
-                            "Failed to add a file {} ({})",
-                            data_path.to_string_lossy(),
-                            err
-                        )
+                        anyhow!(get_message("git-add-failed", Some(fluent_args!["path" => data_path.to_string_lossy(), "error" => err.to_string()])))
+                    })?;
+                    index.write().map_err(|err| {
+                        anyhow!(get_message(
+                            "git-write-failed",
+                            Some(fluent_args!["error" => err.to_string()])
+                        ))
                     })?;
-                    index
-                        .write()
-                        .map_err(|err| anyhow!("Failed to write to index ({})", err))?;
                 }
             }
             DirEntry::Dir(dir) => {
                 let full = Path::new(data_path).join(dir.path());
                 create_dir_all(full.clone())
-                    .map_err(|err| anyhow!("failed to create dir {}", err))?;
+                    .map_err(|err| anyhow!(get_message("dir-create-failed", Some(fluent_args!["path" => full.to_string_lossy(), "error" => err.to_string()]))))?;
             }
         };
     }
@@ -0,0 +1,272 @@
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use git2::Repository;
+use std::{collections::HashSet, convert::TryFrom, fmt, fs, path::Path, str::FromStr};
+
+use crate::{
+    storage::Records,
+    tabular::{self, Format, Tabular},
+    translations::get_message,
+};
+
+/// What to do when an imported record already exists
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Conflict {
+    Fail,
+    Skip,
+    Overwrite,
+}
+
+impl Conflict {
+    pub const NAMES: &'static [&'static str] = &["fail", "skip", "overwrite"];
+}
+
+impl FromStr for Conflict {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "fail" => Ok(Self::Fail),
+            "skip" => Ok(Self::Skip),
+            "overwrite" => Ok(Self::Overwrite),
+            _ => Err(anyhow!(get_message(
+                "unknown-conflict-policy",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ImportSummary {
+    pub imported: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+impl fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            get_message(
+                "records-imported",
+                Some(fluent_args![
+                    "imported" => self.imported.len(),
+                    "skipped" => self.skipped.len()
+                ])
+            )
+        )
+    }
+}
+
+/// Imports records into `dir_name` subdirectory of the project
+///
+/// All the records are validated and checked for conflicts before anything is written.
+pub fn import<ITEM, RECORDS>(
+    data_path: &Path,
+    dir_name: &str,
+    data: &str,
+    format: Format,
+    conflict: Conflict,
+    git: bool,
+) -> Result<ImportSummary>
+where
+    ITEM: Tabular + TryFrom<String> + Clone,
+    <ITEM as TryFrom<String>>::Error: std::fmt::Debug,
+    RECORDS: Records<ITEM>,
+{
+    let records: Vec<ITEM> = tabular::from_str(data, format)?;
+
+    let mut seen = HashSet::new();
+    for record in &records {
+        if !seen.insert(record.id()) {
+            return Err(anyhow!(get_message(
+                "record-not-unique",
+                Some(fluent_args!["id" => record.id()])
+            )));
+        }
+    }
+
+    let dir = data_path.join(dir_name);
+    let existing = RECORDS::load(&dir)?;
+    let mut summary = ImportSummary::default();
+    let mut new_records = vec![];
+    for record in records {
+        if existing.get(&record.id()).is_some() {
+            match conflict {
+                Conflict::Fail => {
+                    return Err(anyhow!(get_message(
+                        "record-exists",
+                        Some(fluent_args!["id" => record.id()])
+                    )))
+                }
+                Conflict::Skip => {
+                    summary.skipped.push(record.id());
+                    continue;
+                }
+                Conflict::Overwrite => {}
+            }
+        }
+        new_records.push(record);
+    }
+
+    for record in &new_records {
+        record.store(&dir)?;
+        summary.imported.push(record.id());
+    }
+
+    if git && !new_records.is_empty() {
+        let added: Vec<String> = new_records.iter().map(|record| record.filename()).collect();
+        stage(data_path, dir_name, &added, &[])?;
+    }
+
+    Ok(summary)
+}
+
+/// Validates and stores a new record into `dir_name` subdirectory of the project
+pub fn create<ITEM, RECORDS>(
+    data_path: &Path,
+    dir_name: &str,
+    record: &ITEM,
+    git: bool,
+) -> Result<()>
+where
+    ITEM: Tabular + TryFrom<String> + Clone,
+    <ITEM as TryFrom<String>>::Error: std::fmt::Debug,
+    RECORDS: Records<ITEM>,
+{
+    record.validate()?;
+
+    let dir = data_path.join(dir_name);
+    check_not_exists::<ITEM, RECORDS>(&dir, &record.id())?;
+    record.store(&dir)?;
+
+    if git {
+        stage(data_path, dir_name, &[record.filename()], &[])?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the record `id` in `dir_name` subdirectory of the project
+///
+/// The file of the original record is removed when the id is changed.
+pub fn update<ITEM, RECORDS>(
+    data_path: &Path,
+    dir_name: &str,
+    id: &str,
+    record: &ITEM,
+    git: bool,
+) -> Result<()>
+where
+    ITEM: Tabular + TryFrom<String> + Clone,
+    <ITEM as TryFrom<String>>::Error: std::fmt::Debug,
+    RECORDS: Records<ITEM>,
+{
+    record.validate()?;
+
+    let dir = data_path.join(dir_name);
+    let original = RECORDS::load(&dir)?.get(id).ok_or_else(|| {
+        anyhow!(get_message(
+            "record-not-found",
+            Some(fluent_args!["id" => id.to_string()])
+        ))
+    })?;
+    let mut removed = vec![];
+    if record.id() != id {
+        check_not_exists::<ITEM, RECORDS>(&dir, &record.id())?;
+        record.store(&dir)?;
+        fs::remove_file(dir.join(original.filename()))?;
+        removed.push(original.filename());
+    } else {
+        record.store(&dir)?;
+    }
+
+    if git {
+        stage(data_path, dir_name, &[record.filename()], &removed)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the record `id` from `dir_name` subdirectory of the project
+pub fn delete<ITEM, RECORDS>(data_path: &Path, dir_name: &str, id: &str, git: bool) -> Result<()>
+where
+    ITEM: Tabular + TryFrom<String> + Clone,
+    <ITEM as TryFrom<String>>::Error: std::fmt::Debug,
+    RECORDS: Records<ITEM>,
+{
+    let dir = data_path.join(dir_name);
+    let record = RECORDS::load(&dir)?.get(id).ok_or_else(|| {
+        anyhow!(get_message(
+            "record-not-found",
+            Some(fluent_args!["id" => id.to_string()])
+        ))
+    })?;
+    fs::remove_file(dir.join(record.filename()))?;
+
+    if git {
+        stage(data_path, dir_name, &[], &[record.filename()])?;
+    }
+
+    Ok(())
+}
+
+fn check_not_exists<ITEM, RECORDS>(dir: &Path, id: &str) -> Result<()>
+where
+    ITEM: Tabular + TryFrom<String> + Clone,
+    <ITEM as TryFrom<String>>::Error: std::fmt::Debug,
+    RECORDS: Records<ITEM>,
+{
+    if RECORDS::load(dir)?.get(id).is_some() {
+        return Err(anyhow!(get_message(
+            "record-exists",
+            Some(fluent_args!["id" => id])
+        )));
+    }
+    Ok(())
+}
+
+/// Adds and removes the files from `dir_name` subdirectory in the git index
+fn stage(data_path: &Path, dir_name: &str, added: &[String], removed: &[String]) -> Result<()> {
+    let repo = Repository::open(data_path).map_err(|err| {
+        anyhow!(get_message(
+            "git-open-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })?;
+    let mut index = repo.index().map_err(|err| {
+        anyhow!(get_message(
+            "git-index-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })?;
+    for filename in added {
+        let new_path = Path::new(dir_name).join(filename);
+        index.add_path(&new_path).map_err(|err| {
+            anyhow!(get_message(
+                "git-add-failed",
+                Some(
+                    fluent_args!["path" => new_path.to_string_lossy(), "error" => err.to_string()]
+                )
+            ))
+        })?;
+    }
+    for filename in removed {
+        let old_path = Path::new(dir_name).join(filename);
+        index.remove_path(&old_path).map_err(|err| {
+            anyhow!(get_message(
+                "git-remove-failed",
+                Some(
+                    fluent_args!["path" => old_path.to_string_lossy(), "error" => err.to_string()]
+                )
+            ))
+        })?;
+    }
+    index.write().map_err(|err| {
+        anyhow!(get_message(
+            "git-write-failed",
+            Some(fluent_args!["error" => err.to_string()])
+        ))
+    })
+}
@@ -0,0 +1,188 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use fluent::fluent_args;
+use minijinja::{context, Value};
+use serde::Serialize;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    actions::{invoice, payment},
+    data::{
+        invoice::{latest::Reminder, Invoice},
+        template::Templates,
+    },
+    storage::{Record, Records},
+    templating,
+    translations::get_message,
+};
+
+/// Reminder templates are placed in this subdir of `templates`
+pub const TEMPLATES_DIR: &str = "reminders";
+pub const DEFAULT_TEMPLATE: &str = "reminder.txt";
+
+/// Values passed to the reminder template as `reminder`
+#[derive(Debug, Serialize)]
+struct Details {
+    level: u32,
+    date: String,
+    days_overdue: i64,
+    outstanding: f64,
+    /// Yearly late interest rate in percent
+    interest_rate: Option<f64>,
+    interest: f64,
+    total_due: f64,
+}
+
+/// Reminder which was rendered
+#[derive(Debug)]
+pub struct Sent {
+    pub invoice: u64,
+    pub level: u32,
+    pub days_overdue: i64,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Default)]
+pub struct Reminders {
+    pub sent: Vec<Sent>,
+}
+
+impl fmt::Display for Reminders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for sent in &self.sent {
+            writeln!(
+                f,
+                "{}",
+                get_message(
+                    "reminder-sent",
+                    Some(fluent_args![
+                        "invoice" => sent.invoice.to_string(),
+                        "level" => sent.level,
+                        "days" => sent.days_overdue,
+                        "filename" => sent.path.to_string_lossy().to_string()
+                    ])
+                )
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn templates(data_path: &Path) -> Result<Templates> {
+    Templates::load(&data_path.join("templates").join(TEMPLATES_DIR)).map_err(|err| {
+        anyhow!(get_message(
+            "reminder-templates-failed",
+            Some(fluent_args!["dir" => TEMPLATES_DIR, "error" => err.to_string()])
+        ))
+    })
+}
+
+/// Late interest for the days overdue (the yearly rate is in percent)
+pub fn interest(outstanding: f64, rate: f64, days: i64) -> f64 {
+    let interest = outstanding * rate / 100.0 * days as f64 / 365.0;
+    (interest * 100.0).round() / 100.0
+}
+
+/// Renders reminders of the invoices which are not paid after the due date
+///
+/// The invoices are not reminded again earlier than `interval` days after the last reminder.
+/// Each reminder escalates the level and is recorded in the invoice.
+#[allow(clippy::too_many_arguments)]
+pub fn remind(
+    data_path: &Path,
+    date: NaiveDate,
+    template: &str,
+    interest_rate: Option<f64>,
+    interval: i64,
+    locale: Option<&str>,
+    git: bool,
+) -> Result<Reminders> {
+    if templates(data_path)?.get(template)?.is_none() {
+        return Err(anyhow!(get_message(
+            "reminder-template-not-found",
+            Some(fluent_args!["template" => template, "dir" => TEMPLATES_DIR])
+        )));
+    }
+    let jinja_env = templating::environment(data_path);
+    let template_path = format!("{}/{}", TEMPLATES_DIR, template);
+    let extension = Path::new(template)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("txt");
+
+    let paid = payment::paid_amounts(&payment::list(data_path)?);
+    let invoice_path = data_path.join("invoices");
+    let mut reminders = Reminders::default();
+    let mut changed: Vec<PathBuf> = vec![];
+
+    for mut record in invoice::list(data_path)?.records().iter().cloned() {
+        if !payment::is_unpaid(&record, &paid) {
+            continue;
+        }
+        let outstanding = f64::from(payment::outstanding(&record, &paid));
+        let days_overdue = (date - invoice::parse_date(&record.due_date)?).num_days();
+        if days_overdue <= 0 {
+            continue;
+        }
+        if let Some(last) = record.reminders.last() {
+            if (date - invoice::parse_date(&last.date)?).num_days() < interval {
+                continue;
+            }
+        }
+
+        let level = record.reminders.last().map_or(1, |last| last.level + 1);
+        let interest = interest_rate.map_or(0.0, |rate| interest(outstanding, rate, days_overdue));
+        let details = Details {
+            level,
+            date: date.to_string(),
+            days_overdue,
+            outstanding,
+            interest_rate,
+            interest,
+            total_due: outstanding + interest,
+        };
+        let rendered = render(&jinja_env, &record, &template_path, locale, &details)?;
+        let output_path = data_path
+            .join("output")
+            .join(format!("{}-reminder-{}.{}", record.id, level, extension));
+        invoice::write_output(&output_path, rendered)?;
+
+        record.reminders.push(Reminder {
+            level,
+            date: date.to_string(),
+        });
+        record.store(&invoice_path)?;
+
+        changed.push(output_path.clone());
+        changed.push(invoice_path.join(record.filename()));
+        reminders.sent.push(Sent {
+            invoice: record.id,
+            level,
+            days_overdue,
+            path: output_path,
+        });
+    }
+
+    if git && !changed.is_empty() {
+        invoice::add_outputs_to_git(data_path, &changed)?;
+    }
+
+    Ok(reminders)
+}
+
+fn render(
+    jinja_env: &minijinja::Environment,
+    invoice: &Invoice,
+    template: &str,
+    locale: Option<&str>,
+    details: &Details,
+) -> Result<String> {
+    let ctx = context!(
+        reminder => Value::from_serializable(details),
+        ..Value::from_serializable(invoice)
+    );
+    invoice::render_with_context(jinja_env, invoice, template, locale, ctx)
+}
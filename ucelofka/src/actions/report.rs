@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use fluent::fluent_args;
+use std::{collections::BTreeMap, fmt, path::Path, str::FromStr};
+
+use crate::{
+    actions::{invoice, payment},
+    report::{Cell, Table},
+    translations::get_message,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Period {
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Period {
+    pub const NAMES: &'static [&'static str] = &["month", "quarter", "year"];
+
+    /// Label of the period the date belongs to (`2024-01`, `2024-Q1` or `2024`)
+    pub fn label(&self, date: NaiveDate) -> String {
+        match self {
+            Self::Month => format!("{}-{:02}", date.year(), date.month()),
+            Self::Quarter => format!("{}-Q{}", date.year(), (date.month() - 1) / 3 + 1),
+            Self::Year => date.year().to_string(),
+        }
+    }
+}
+
+impl FromStr for Period {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "month" => Ok(Self::Month),
+            "quarter" => Ok(Self::Quarter),
+            "year" => Ok(Self::Year),
+            _ => Err(anyhow!(get_message(
+                "unknown-period",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Month => write!(f, "month"),
+            Self::Quarter => write!(f, "quarter"),
+            Self::Year => write!(f, "year"),
+        }
+    }
+}
+
+/// Invoiced amounts grouped by the period of the issue date, currency and optionally customer
+pub fn revenue(
+    data_path: &Path,
+    period: Period,
+    by_customer: bool,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<Table> {
+    let mut groups: BTreeMap<(String, String, String), (usize, f64)> = BTreeMap::new();
+    for invoice in invoice::select(data_path, &[], from, to)? {
+        let key = (
+            period.label(invoice::parse_date(&invoice.issue_date)?),
+            if by_customer {
+                invoice.customer.name.clone()
+            } else {
+                String::new()
+            },
+            invoice.billing.currency.clone(),
+        );
+        let group = groups.entry(key).or_default();
+        group.0 += 1;
+        group.1 += f64::from(invoice.billing.total);
+    }
+
+    let mut table = if by_customer {
+        Table::new(vec!["period", "customer", "currency", "invoices", "total"])
+    } else {
+        Table::new(vec!["period", "currency", "invoices", "total"])
+    };
+    for ((period, customer, currency), (count, total)) in groups {
+        let mut row = vec![Cell::Text(period)];
+        if by_customer {
+            row.push(Cell::Text(customer));
+        }
+        row.extend([
+            Cell::Text(currency),
+            Cell::Count(count),
+            Cell::Amount(total),
+        ]);
+        table.rows.push(row);
+    }
+    Ok(table)
+}
+
+/// Outstanding amounts per customer split by the number of days past the due date
+pub fn aging(data_path: &Path, date: NaiveDate) -> Result<Table> {
+    let paid = payment::paid_amounts(&payment::list(data_path)?);
+
+    // current, 1-30, 31-60, 60+
+    let mut groups: BTreeMap<(String, String), [f64; 4]> = BTreeMap::new();
+    for invoice in invoice::select(data_path, &[], None, None)? {
+        if !payment::is_unpaid(&invoice, &paid) {
+            continue;
+        }
+        let outstanding = f64::from(payment::outstanding(&invoice, &paid));
+        let days = (date - invoice::parse_date(&invoice.due_date)?).num_days();
+        let bucket = match days {
+            ..=0 => 0,
+            1..=30 => 1,
+            31..=60 => 2,
+            _ => 3,
+        };
+        groups
+            .entry((
+                invoice.customer.name.clone(),
+                invoice.billing.currency.clone(),
+            ))
+            .or_default()[bucket] += outstanding;
+    }
+
+    let mut table = Table::new(vec![
+        "customer", "currency", "current", "1-30", "31-60", "60+", "total",
+    ]);
+    for ((customer, currency), buckets) in groups {
+        let mut row = vec![Cell::Text(customer), Cell::Text(currency)];
+        row.extend(buckets.iter().map(|amount| Cell::Amount(*amount)));
+        row.push(Cell::Amount(buckets.iter().sum()));
+        table.rows.push(row);
+    }
+    Ok(table)
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::Path,
+};
+
+use crate::{
+    actions::invoice,
+    data::invoice::Invoice,
+    report::{Cell, Table},
+    tax::{self, control_statement::Taxpayer, Period},
+};
+
+fn issued(data_path: &Path, period: Period) -> Result<Vec<Invoice>> {
+    invoice::select(
+        data_path,
+        &[],
+        Some(period.first_day()),
+        Some(period.last_day()),
+    )
+}
+
+/// Taxable base and tax per currency and VAT rate of the invoices issued in the period
+pub fn summary(data_path: &Path, period: Period) -> Result<Table> {
+    let mut groups: BTreeMap<(String, String), (HashSet<u64>, f64, f64)> = BTreeMap::new();
+    for invoice in issued(data_path, period)? {
+        for entry in &invoice.entries {
+            let group = groups
+                .entry((
+                    invoice.billing.currency.clone(),
+                    tax::rate_label(entry.vat_rate),
+                ))
+                .or_default();
+            group.0.insert(invoice.id);
+            group.1 += f64::from(entry.price);
+            group.2 += f64::from(entry.tax());
+        }
+    }
+
+    let mut table = Table::new(vec![
+        "period", "currency", "rate", "invoices", "base", "tax", "total",
+    ]);
+    for ((currency, rate), (invoices, base, tax)) in groups {
+        table.rows.push(vec![
+            Cell::Text(period.to_string()),
+            Cell::Text(currency),
+            Cell::Text(rate),
+            Cell::Count(invoices.len()),
+            Cell::Amount(base),
+            Cell::Amount(tax),
+            Cell::Amount(base + tax),
+        ]);
+    }
+    Ok(table)
+}
+
+/// Control statement XML for the EPO tax portal
+pub fn control_statement(
+    data_path: &Path,
+    period: Period,
+    taxpayer: &Taxpayer,
+    filed: NaiveDate,
+) -> Result<String> {
+    tax::control_statement::export(&issued(data_path, period)?, period, taxpayer, filed)
+}
@@ -1,7 +1,11 @@
 use anyhow::{anyhow, Result};
+use fluent::fluent_args;
 use std::path::Path;
 
-use crate::data::template::{Template, Templates};
+use crate::{
+    data::template::{Template, Templates},
+    translations::get_message,
+};
 
 pub fn list(data_path: &Path) -> Result<Templates> {
     let templates_path = data_path.join(Path::new("templates"));
@@ -9,7 +13,10 @@ pub fn list(data_path: &Path) -> Result<Templates> {
 }
 
 pub fn get(data_path: &Path, id: &str) -> Result<Template> {
-    list(data_path)?
-        .get(id)?
-        .ok_or_else(|| anyhow!("Template {} not found.", id))
+    list(data_path)?.get(id)?.ok_or_else(|| {
+        anyhow!(get_message(
+            "template-not-found",
+            Some(fluent_args!["id" => id.to_string()])
+        ))
+    })
 }
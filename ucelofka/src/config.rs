@@ -0,0 +1,142 @@
+//! Project configuration stored in `config.yml` of the data directory
+//!
+//! The file is optional and all its values have defaults.
+
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use serde::Deserialize;
+
+use crate::translations::get_message;
+use std::{collections::HashSet, fs, path::Path};
+
+pub const CONFIG_FILE: &str = "config.yml";
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Language of the program's messages (e.g. `cs`)
+    pub lang: Option<String>,
+    pub tui: Tui,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Tui {
+    pub keys: Keys,
+}
+
+/// Characters which trigger the actions of the TUI
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Keys {
+    pub quit: String,
+    pub help: String,
+    /// Focuses the list of the pages
+    pub pages: String,
+    pub search: String,
+    pub edit: String,
+    pub delete: String,
+    pub confirm: String,
+    pub cancel: String,
+    pub sort: String,
+    /// Filters the invoices by customer
+    pub customer: String,
+    /// Filters the invoices by year
+    pub year: String,
+    /// Shows only the unpaid invoices
+    pub unpaid: String,
+    /// Selects the next template
+    pub template: String,
+    pub render: String,
+    pub preview: String,
+    /// Fills the template with the next invoice
+    pub invoice: String,
+}
+
+impl Default for Keys {
+    fn default() -> Self {
+        Self {
+            quit: "q".to_string(),
+            help: "?".to_string(),
+            pages: "p".to_string(),
+            search: "/".to_string(),
+            edit: "e".to_string(),
+            delete: "d".to_string(),
+            confirm: "y".to_string(),
+            cancel: "n".to_string(),
+            sort: "s".to_string(),
+            customer: "c".to_string(),
+            year: "y".to_string(),
+            unpaid: "u".to_string(),
+            template: "t".to_string(),
+            render: "r".to_string(),
+            preview: "v".to_string(),
+            invoice: "i".to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config of the project, the defaults are used when the file doesn't exist
+    pub fn load(data_path: &Path) -> Result<Self> {
+        let path = data_path.join(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path).map_err(|err| {
+            anyhow!(get_message(
+                "config-read-failed",
+                Some(fluent_args!["file" => CONFIG_FILE, "error" => err.to_string()])
+            ))
+        })?;
+        let config: Self = serde_yaml::from_str(&content).map_err(|err| {
+            anyhow!(get_message(
+                "config-parse-failed",
+                Some(fluent_args!["file" => CONFIG_FILE, "error" => err.to_string()])
+            ))
+        })?;
+        config.tui.keys.check()?;
+        Ok(config)
+    }
+}
+
+impl Keys {
+    /// Keys have to be single characters which are unique within each page
+    fn check(&self) -> Result<()> {
+        // keys which work on all the pages
+        let global = [&self.quit, &self.help, &self.pages, &self.search];
+        let records = [&self.edit, &self.delete, &self.confirm, &self.cancel];
+        let pages = [
+            records.to_vec(),
+            [records.as_slice(), &[&self.sort]].concat(),
+            vec![
+                &self.customer,
+                &self.year,
+                &self.unpaid,
+                &self.sort,
+                &self.template,
+                &self.render,
+                &self.preview,
+            ],
+            vec![&self.invoice],
+        ];
+        for page in pages {
+            let mut used = HashSet::new();
+            for key in global.iter().chain(page.iter()) {
+                if key.chars().count() != 1 {
+                    return Err(anyhow!(get_message(
+                        "config-invalid-key",
+                        Some(fluent_args!["key" => key.as_str(), "file" => CONFIG_FILE])
+                    )));
+                }
+                if !used.insert(key.as_str()) {
+                    return Err(anyhow!(get_message(
+                        "config-duplicate-key",
+                        Some(fluent_args!["key" => key.as_str(), "file" => CONFIG_FILE])
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
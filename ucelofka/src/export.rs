@@ -0,0 +1,299 @@
+pub mod cii;
+pub mod facturx;
+pub mod isdoc;
+pub mod money;
+pub mod pohoda;
+pub mod ubl;
+
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use quick_xml::{events::BytesText, Writer};
+use std::{fmt, io::Cursor, str::FromStr};
+use uuid::Uuid;
+
+use crate::{
+    data::{identification::Identification, invoice::Invoice},
+    translations::get_message,
+};
+
+/// Machine readable formats the invoices can be exported to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Cii,
+    Isdoc,
+    Ubl,
+}
+
+impl Format {
+    pub const NAMES: &'static [&'static str] = &["cii", "isdoc", "ubl"];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Cii => "cii.xml",
+            Self::Isdoc => "isdoc",
+            Self::Ubl => "ubl.xml",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "cii" => Ok(Self::Cii),
+            "isdoc" => Ok(Self::Isdoc),
+            "ubl" => Ok(Self::Ubl),
+            _ => Err(anyhow!(get_message(
+                "unknown-export-format",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cii => write!(f, "cii"),
+            Self::Isdoc => write!(f, "isdoc"),
+            Self::Ubl => write!(f, "ubl"),
+        }
+    }
+}
+
+pub fn export(invoice: &Invoice, format: Format) -> Result<String> {
+    match format {
+        Format::Cii => cii::export(invoice),
+        Format::Isdoc => isdoc::export(invoice),
+        Format::Ubl => ubl::export(invoice),
+    }
+}
+
+/// Czech accounting software the issued invoices can be exported to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Accounting {
+    Pohoda,
+    MoneyS3,
+}
+
+impl Accounting {
+    pub const NAMES: &'static [&'static str] = &["pohoda", "money-s3"];
+}
+
+impl FromStr for Accounting {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "pohoda" => Ok(Self::Pohoda),
+            "money-s3" => Ok(Self::MoneyS3),
+            _ => Err(anyhow!(get_message(
+                "unknown-accounting-format",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Accounting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pohoda => write!(f, "pohoda"),
+            Self::MoneyS3 => write!(f, "money-s3"),
+        }
+    }
+}
+
+/// Exports multiple invoices for the accounting software
+///
+/// The registration number of the accounting unit is taken from the issuer of the invoices.
+pub fn export_accounting(invoices: &[Invoice], format: Accounting) -> Result<String> {
+    let ico = invoices
+        .first()
+        .and_then(|invoice| identification(&invoice.issuer.identifications, "registration"))
+        .unwrap_or_default();
+    match format {
+        Accounting::Pohoda => pohoda::export(invoices, ico),
+        Accounting::MoneyS3 => money::export(invoices, ico),
+    }
+}
+
+/// Documents need to have a stable UUID so it is derived from the issuer and the invoice id
+pub(crate) fn document_uuid(invoice: &Invoice) -> Uuid {
+    let name = format!(
+        "{}/{}",
+        identification(&invoice.issuer.identifications, "registration")
+            .unwrap_or(&invoice.issuer.name),
+        invoice.id
+    );
+    Uuid::new_v5(&Uuid::NAMESPACE_OID, name.as_bytes())
+}
+
+pub(crate) type XmlWriter = Writer<Cursor<Vec<u8>>>;
+
+pub(crate) fn xml_writer() -> XmlWriter {
+    Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2)
+}
+
+pub(crate) fn xml_finish(writer: XmlWriter) -> Result<String> {
+    let body = String::from_utf8(writer.into_inner().into_inner())?;
+    Ok(format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}\n",
+        body
+    ))
+}
+
+pub(crate) fn text_element(writer: &mut XmlWriter, name: &str, value: &str) -> Result<()> {
+    writer
+        .create_element(name)
+        .write_text_content(BytesText::new(value))?;
+    Ok(())
+}
+
+pub(crate) fn text_element_with_attributes(
+    writer: &mut XmlWriter,
+    name: &str,
+    attributes: &[(&str, &str)],
+    value: &str,
+) -> Result<()> {
+    writer
+        .create_element(name)
+        .with_attributes(attributes.iter().copied())
+        .write_text_content(BytesText::new(value))?;
+    Ok(())
+}
+
+pub(crate) fn element<F>(writer: &mut XmlWriter, name: &str, content: F) -> Result<()>
+where
+    F: FnOnce(&mut XmlWriter) -> Result<()>,
+{
+    writer.create_element(name).write_inner_content(content)?;
+    Ok(())
+}
+
+/// Amounts are exported rounded to two decimal places
+pub(crate) fn amount(value: f32) -> String {
+    format!("{:.2}", value)
+}
+
+pub(crate) fn identification<'a>(
+    identifications: &'a [Identification],
+    name: &str,
+) -> Option<&'a str> {
+    identifications
+        .iter()
+        .find(|e| e.name == name)
+        .map(|e| e.value.as_str())
+}
+
+/// VAT category of the entries without VAT rate (EN 16931)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Untaxed {
+    /// Seller without VAT id is not subject to VAT (`O`)
+    NotSubject,
+    /// Seller registered for VAT supplies an exempt service (`E`)
+    Exempt,
+}
+
+impl Untaxed {
+    pub(crate) fn new(issuer: &[Identification]) -> Self {
+        if identification(issuer, "tax").is_some() {
+            Self::Exempt
+        } else {
+            Self::NotSubject
+        }
+    }
+
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Self::NotSubject => "O",
+            Self::Exempt => "E",
+        }
+    }
+
+    pub(crate) fn reason(self) -> &'static str {
+        match self {
+            Self::NotSubject => "Not subject to VAT",
+            Self::Exempt => "Exempt from VAT",
+        }
+    }
+
+    /// Rate is not allowed in the not subject to VAT category
+    pub(crate) fn percent(self) -> Option<&'static str> {
+        match self {
+            Self::NotSubject => None,
+            Self::Exempt => Some("0"),
+        }
+    }
+}
+
+/// Currency of the czech accounting software
+pub(crate) const HOME_CURRENCY: &str = "CZK";
+
+/// Used when the country can't be determined from the tax id
+pub(crate) const DEFAULT_COUNTRY_CODE: &str = "CZ";
+
+/// Address which is split to the parts required by the e-invoicing formats
+///
+/// The address lines are expected to be in `street number`, `postal code city`, `country`
+/// format (the same as in the default data).
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Address {
+    pub street: String,
+    pub building_number: String,
+    pub city: String,
+    pub postal_zone: String,
+    pub country: String,
+    pub country_code: Option<String>,
+}
+
+impl Address {
+    pub fn parse(lines: &[String], identifications: &[Identification]) -> Self {
+        let mut res = Self::default();
+
+        if let Some(line) = lines.first() {
+            let line = line.trim();
+            match line.rsplit_once(' ') {
+                Some((street, number)) if number.chars().any(|c| c.is_ascii_digit()) => {
+                    res.street = street.trim().to_string();
+                    res.building_number = number.to_string();
+                }
+                _ => res.street = line.to_string(),
+            }
+        }
+
+        if let Some(line) = lines.get(1) {
+            let line = line.trim();
+            let split = line
+                .find(|c: char| !(c.is_ascii_digit() || c.is_whitespace()))
+                .unwrap_or(line.len());
+            res.postal_zone = line[..split].trim().to_string();
+            res.city = line[split..].trim().to_string();
+        }
+
+        if let Some(line) = lines.get(2) {
+            res.country = line.trim().to_string();
+        }
+
+        // VAT ids are prefixed with the country code
+        res.country_code = identification(identifications, "tax")
+            .map(|tax| tax.chars().take(2).collect::<String>())
+            .filter(|code| code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase()));
+
+        res
+    }
+
+    /// Street with the building number
+    pub fn street_line(&self) -> String {
+        if self.building_number.is_empty() {
+            self.street.clone()
+        } else {
+            format!("{} {}", self.street, self.building_number)
+        }
+    }
+
+    pub fn country_code(&self) -> &str {
+        self.country_code.as_deref().unwrap_or(DEFAULT_COUNTRY_CODE)
+    }
+}
@@ -0,0 +1,289 @@
+//! UN/CEFACT Cross Industry Invoice (EN 16931 profile)
+//!
+//! This is the XML which is embedded into Factur-X / ZUGFeRD PDFs.
+//! Same as in UBL the lines are exported in the standard rated (`S`), exempt (`E`)
+//! or not subject to VAT (`O`) category.
+
+use anyhow::Result;
+
+use crate::{
+    data::{identification::Identification, invoice::Invoice},
+    tax::Summary,
+};
+
+use super::{
+    amount, element, identification, text_element, text_element_with_attributes, xml_finish,
+    xml_writer, Address, Untaxed, XmlWriter,
+};
+
+const NAMESPACE_RSM: &str = "urn:un:unece:uncefact:data:standard:CrossIndustryInvoice:100";
+const NAMESPACE_RAM: &str =
+    "urn:un:unece:uncefact:data:standard:ReusableAggregateBusinessInformationEntity:100";
+const NAMESPACE_UDT: &str = "urn:un:unece:uncefact:data:standard:UnqualifiedDataType:100";
+/// Factur-X EN 16931 (COMFORT) profile
+const GUIDELINE_ID: &str = "urn:cen.eu:en16931:2017";
+/// Commercial invoice
+const TYPE_CODE: &str = "380";
+/// Credit transfer
+const PAYMENT_MEANS_CODE: &str = "30";
+/// Standard rated
+const TAX_CATEGORY_STANDARD: &str = "S";
+/// Unit code for "one" (piece)
+const UNIT_CODE: &str = "C62";
+
+fn category(rate: f32, untaxed: Untaxed) -> &'static str {
+    if rate == 0.0 {
+        untaxed.code()
+    } else {
+        TAX_CATEGORY_STANDARD
+    }
+}
+
+fn percent(rate: f32, untaxed: Untaxed) -> Option<String> {
+    if rate == 0.0 {
+        untaxed.percent().map(str::to_string)
+    } else {
+        Some(rate.to_string())
+    }
+}
+
+/// Dates are written in `YYYYMMDD` format (code 102)
+fn date(writer: &mut XmlWriter, name: &str, value: &str) -> Result<()> {
+    element(writer, name, |writer| {
+        text_element_with_attributes(
+            writer,
+            "udt:DateTimeString",
+            &[("format", "102")],
+            &value.replace('-', ""),
+        )
+    })
+}
+
+fn party(
+    writer: &mut XmlWriter,
+    name: &str,
+    address: &[String],
+    identifications: &[Identification],
+    phone: Option<&str>,
+    email: Option<&str>,
+) -> Result<()> {
+    let address = Address::parse(address, identifications);
+    text_element(writer, "ram:Name", name)?;
+    if let Some(registration) = identification(identifications, "registration") {
+        element(writer, "ram:SpecifiedLegalOrganization", |writer| {
+            text_element(writer, "ram:ID", registration)
+        })?;
+    }
+    if phone.is_some() || email.is_some() {
+        element(writer, "ram:DefinedTradeContact", |writer| {
+            if let Some(phone) = phone {
+                element(writer, "ram:TelephoneUniversalCommunication", |writer| {
+                    text_element(writer, "ram:CompleteNumber", phone)
+                })?;
+            }
+            if let Some(email) = email {
+                element(writer, "ram:EmailURIUniversalCommunication", |writer| {
+                    text_element(writer, "ram:URIID", email)
+                })?;
+            }
+            Ok(())
+        })?;
+    }
+    element(writer, "ram:PostalTradeAddress", |writer| {
+        text_element(writer, "ram:PostcodeCode", &address.postal_zone)?;
+        text_element(writer, "ram:LineOne", &address.street_line())?;
+        text_element(writer, "ram:CityName", &address.city)?;
+        text_element(writer, "ram:CountryID", address.country_code())
+    })?;
+    if let Some(email) = email {
+        element(writer, "ram:URIUniversalCommunication", |writer| {
+            text_element_with_attributes(writer, "ram:URIID", &[("schemeID", "EM")], email)
+        })?;
+    }
+    if let Some(tax) = identification(identifications, "tax") {
+        element(writer, "ram:SpecifiedTaxRegistration", |writer| {
+            text_element_with_attributes(writer, "ram:ID", &[("schemeID", "VA")], tax)
+        })?;
+    }
+    Ok(())
+}
+
+pub fn export(invoice: &Invoice) -> Result<String> {
+    let mut writer = xml_writer();
+    let currency = invoice.billing.currency.as_str();
+    let vat = Summary::new(invoice);
+    let untaxed = Untaxed::new(&invoice.issuer.identifications);
+
+    writer
+        .create_element("rsm:CrossIndustryInvoice")
+        .with_attribute(("xmlns:rsm", NAMESPACE_RSM))
+        .with_attribute(("xmlns:ram", NAMESPACE_RAM))
+        .with_attribute(("xmlns:udt", NAMESPACE_UDT))
+        .write_inner_content(|writer| {
+            element(writer, "rsm:ExchangedDocumentContext", |writer| {
+                element(
+                    writer,
+                    "ram:GuidelineSpecifiedDocumentContextParameter",
+                    |writer| text_element(writer, "ram:ID", GUIDELINE_ID),
+                )
+            })?;
+
+            element(writer, "rsm:ExchangedDocument", |writer| {
+                text_element(writer, "ram:ID", &invoice.id.to_string())?;
+                text_element(writer, "ram:TypeCode", TYPE_CODE)?;
+                date(writer, "ram:IssueDateTime", &invoice.issue_date)
+            })?;
+
+            element(writer, "rsm:SupplyChainTradeTransaction", |writer| {
+                for (idx, entry) in invoice.entries.iter().enumerate() {
+                    let price = amount(entry.price);
+                    element(writer, "ram:IncludedSupplyChainTradeLineItem", |writer| {
+                        element(writer, "ram:AssociatedDocumentLineDocument", |writer| {
+                            text_element(writer, "ram:LineID", &(idx + 1).to_string())
+                        })?;
+                        element(writer, "ram:SpecifiedTradeProduct", |writer| {
+                            text_element(writer, "ram:Name", &entry.name)?;
+                            if !entry.details.is_empty() {
+                                text_element(writer, "ram:Description", &entry.details.join("; "))?;
+                            }
+                            Ok(())
+                        })?;
+                        element(writer, "ram:SpecifiedLineTradeAgreement", |writer| {
+                            element(writer, "ram:NetPriceProductTradePrice", |writer| {
+                                text_element(writer, "ram:ChargeAmount", &price)
+                            })
+                        })?;
+                        element(writer, "ram:SpecifiedLineTradeDelivery", |writer| {
+                            text_element_with_attributes(
+                                writer,
+                                "ram:BilledQuantity",
+                                &[("unitCode", UNIT_CODE)],
+                                "1",
+                            )
+                        })?;
+                        element(writer, "ram:SpecifiedLineTradeSettlement", |writer| {
+                            element(writer, "ram:ApplicableTradeTax", |writer| {
+                                let rate = entry.vat_rate.unwrap_or(0.0);
+                                text_element(writer, "ram:TypeCode", "VAT")?;
+                                text_element(writer, "ram:CategoryCode", category(rate, untaxed))?;
+                                if let Some(percent) = percent(rate, untaxed) {
+                                    text_element(writer, "ram:RateApplicablePercent", &percent)?;
+                                }
+                                Ok(())
+                            })?;
+                            element(
+                                writer,
+                                "ram:SpecifiedTradeSettlementLineMonetarySummation",
+                                |writer| text_element(writer, "ram:LineTotalAmount", &price),
+                            )
+                        })
+                    })?;
+                }
+
+                element(writer, "ram:ApplicableHeaderTradeAgreement", |writer| {
+                    if let Some(reference) =
+                        identification(&invoice.customer.identifications, "buyer_reference")
+                    {
+                        text_element(writer, "ram:BuyerReference", reference)?;
+                    }
+                    element(writer, "ram:SellerTradeParty", |writer| {
+                        party(
+                            writer,
+                            &invoice.issuer.name,
+                            &invoice.issuer.address,
+                            &invoice.issuer.identifications,
+                            invoice.issuer.phone.first().map(String::as_str),
+                            invoice.issuer.email.first().map(String::as_str),
+                        )
+                    })?;
+                    element(writer, "ram:BuyerTradeParty", |writer| {
+                        party(
+                            writer,
+                            &invoice.customer.name,
+                            &invoice.customer.address,
+                            &invoice.customer.identifications,
+                            None,
+                            invoice.customer.email.first().map(String::as_str),
+                        )
+                    })
+                })?;
+
+                writer
+                    .create_element("ram:ApplicableHeaderTradeDelivery")
+                    .write_empty()?;
+
+                element(writer, "ram:ApplicableHeaderTradeSettlement", |writer| {
+                    text_element(
+                        writer,
+                        "ram:PaymentReference",
+                        &invoice.billing.variable_symbol,
+                    )?;
+                    text_element(writer, "ram:InvoiceCurrencyCode", currency)?;
+                    element(
+                        writer,
+                        "ram:SpecifiedTradeSettlementPaymentMeans",
+                        |writer| {
+                            text_element(writer, "ram:TypeCode", PAYMENT_MEANS_CODE)?;
+                            element(writer, "ram:PayeePartyCreditorFinancialAccount", |writer| {
+                                text_element(
+                                    writer,
+                                    "ram:IBANID",
+                                    &invoice.billing.IBAN.replace(' ', ""),
+                                )?;
+                                text_element(
+                                    writer,
+                                    "ram:AccountName",
+                                    &invoice.billing.account_name,
+                                )
+                            })?;
+                            element(
+                                writer,
+                                "ram:PayeeSpecifiedCreditorFinancialInstitution",
+                                |writer| text_element(writer, "ram:BICID", &invoice.billing.BIC),
+                            )
+                        },
+                    )?;
+                    for subtotal in &vat.subtotals {
+                        element(writer, "ram:ApplicableTradeTax", |writer| {
+                            text_element(writer, "ram:CalculatedAmount", &amount(subtotal.tax))?;
+                            text_element(writer, "ram:TypeCode", "VAT")?;
+                            if subtotal.rate == 0.0 {
+                                text_element(writer, "ram:ExemptionReason", untaxed.reason())?;
+                            }
+                            text_element(writer, "ram:BasisAmount", &amount(subtotal.base))?;
+                            text_element(
+                                writer,
+                                "ram:CategoryCode",
+                                category(subtotal.rate, untaxed),
+                            )?;
+                            if let Some(percent) = percent(subtotal.rate, untaxed) {
+                                text_element(writer, "ram:RateApplicablePercent", &percent)?;
+                            }
+                            Ok(())
+                        })?;
+                    }
+                    element(writer, "ram:SpecifiedTradePaymentTerms", |writer| {
+                        date(writer, "ram:DueDateDateTime", &invoice.due_date)
+                    })?;
+                    element(
+                        writer,
+                        "ram:SpecifiedTradeSettlementHeaderMonetarySummation",
+                        |writer| {
+                            text_element(writer, "ram:LineTotalAmount", &amount(vat.base))?;
+                            text_element(writer, "ram:TaxBasisTotalAmount", &amount(vat.base))?;
+                            text_element_with_attributes(
+                                writer,
+                                "ram:TaxTotalAmount",
+                                &[("currencyID", currency)],
+                                &amount(vat.tax),
+                            )?;
+                            text_element(writer, "ram:GrandTotalAmount", &amount(vat.total))?;
+                            text_element(writer, "ram:DuePayableAmount", &amount(vat.total))
+                        },
+                    )
+                })
+            })
+        })?;
+
+    xml_finish(writer)
+}
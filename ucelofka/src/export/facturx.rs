@@ -0,0 +1,460 @@
+//! Factur-X / ZUGFeRD hybrid invoices
+//!
+//! A PDF which contains the human readable invoice (plain text rendered using an embedded
+//! TrueType font) and the CII XML as an attachment. The document contains the output intent
+//! and the Factur-X metadata, but it doesn't declare the PDF/A-3 conformance
+//! (`pdfaid`) as it isn't checked by a PDF/A validator.
+
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use pdf_writer::{
+    types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap},
+    Content, Date, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr,
+};
+use quick_xml::escape::escape;
+use std::collections::BTreeMap;
+use ttf_parser::{name_id, Face, GlyphId};
+
+use crate::{data::invoice::Invoice, translations::get_message};
+
+use super::{cii, document_uuid};
+
+/// Name of the attachment required by Factur-X
+const ATTACHMENT_NAME: &str = "factur-x.xml";
+const FACTURX_NAMESPACE: &str = "urn:factur-x:pdfa:CrossIndustryDocument:invoice:1p0#";
+const CONFORMANCE_LEVEL: &str = "EN 16931";
+
+/// A4 in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+const FONT_SIZE: f32 = 10.0;
+const LINE_HEIGHT: f32 = 13.0;
+
+const COMPRESSION_LEVEL: u8 = 6;
+
+const FONT_NAME: Name = Name(b"F1");
+const SYSTEM_INFO: SystemInfo = SystemInfo {
+    registry: Str(b"Adobe"),
+    ordering: Str(b"Identity"),
+    supplement: 0,
+};
+
+/// Text laid out to lines of glyphs
+struct Layout<'a> {
+    face: Face<'a>,
+    /// glyph id -> (char, width in font units)
+    glyphs: BTreeMap<u16, (char, u16)>,
+}
+
+impl<'a> Layout<'a> {
+    fn new(font: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            face: Face::parse(font, 0).map_err(|err| {
+                anyhow!(get_message(
+                    "font-parse-failed",
+                    Some(fluent_args!["error" => err.to_string()])
+                ))
+            })?,
+            glyphs: BTreeMap::new(),
+        })
+    }
+
+    fn scale(&self, value: f32) -> f32 {
+        value * 1000.0 / self.face.units_per_em() as f32
+    }
+
+    /// Encodes the line into glyph ids and wraps it to fit the page
+    ///
+    /// Characters which are missing in the font are skipped.
+    fn lines(&mut self, line: &str) -> Vec<Vec<u8>> {
+        let max_width = (PAGE_WIDTH - 2.0 * MARGIN) / FONT_SIZE * 1000.0;
+        let mut lines = vec![];
+        let mut current: Vec<u16> = vec![];
+        let mut width = 0.0;
+        let mut last_space = None;
+
+        for c in line.replace('\t', "    ").chars() {
+            let glyph = match self.face.glyph_index(c) {
+                Some(GlyphId(0)) | None => continue,
+                Some(glyph) => glyph,
+            };
+            let advance = self.face.glyph_hor_advance(glyph).unwrap_or(0);
+            self.glyphs.insert(glyph.0, (c, advance));
+
+            let advance = self.scale(advance as f32);
+            if width + advance > max_width && !current.is_empty() {
+                let rest = match last_space {
+                    Some(idx) => current.split_off(idx + 1),
+                    None => vec![],
+                };
+                lines.push(current);
+                width = rest
+                    .iter()
+                    .map(|glyph| self.scale(self.glyphs[glyph].1 as f32))
+                    .sum();
+                current = rest;
+                last_space = None;
+            }
+            if c == ' ' {
+                last_space = Some(current.len());
+            }
+            current.push(glyph.0);
+            width += advance;
+        }
+        lines.push(current);
+
+        lines
+            .into_iter()
+            .map(|line| line.iter().flat_map(|glyph| glyph.to_be_bytes()).collect())
+            .collect()
+    }
+
+    fn postscript_name(&self) -> String {
+        self.face
+            .names()
+            .into_iter()
+            .filter(|name| name.name_id == name_id::POST_SCRIPT_NAME)
+            .find_map(|name| name.to_string())
+            .unwrap_or_else(|| "Font".to_string())
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect()
+    }
+}
+
+/// Minimal grayscale monitor ICC profile (v2, gamma 2.2) for the PDF/A output intent
+fn gray_icc_profile() -> Vec<u8> {
+    fn s15fixed16(value: f64) -> [u8; 4] {
+        ((value * 65536.0).round() as i32).to_be_bytes()
+    }
+    // D50 white point
+    let d50: Vec<u8> = [0.9642, 1.0, 0.8249]
+        .iter()
+        .flat_map(|v| s15fixed16(*v))
+        .collect();
+
+    let description = b"ucelofka gray gamma 2.2\0";
+    let mut desc = b"desc\0\0\0\0".to_vec();
+    desc.extend((description.len() as u32).to_be_bytes());
+    desc.extend(description);
+    desc.extend([0u8; 4 + 4 + 2 + 1 + 67]);
+
+    let mut wtpt = b"XYZ \0\0\0\0".to_vec();
+    wtpt.extend(&d50);
+
+    let mut ktrc = b"curv\0\0\0\0".to_vec();
+    ktrc.extend(1u32.to_be_bytes());
+    ktrc.extend(0x0233u16.to_be_bytes());
+
+    let mut cprt = b"text\0\0\0\0".to_vec();
+    cprt.extend(b"No copyright, use freely\0");
+
+    let tags: [(&[u8; 4], Vec<u8>); 4] = [
+        (b"desc", desc),
+        (b"wtpt", wtpt),
+        (b"kTRC", ktrc),
+        (b"cprt", cprt),
+    ];
+
+    let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+    let mut data = vec![];
+    let mut offset = 128 + 4 + 12 * tags.len();
+    for (signature, tag) in &tags {
+        table.extend(*signature);
+        table.extend((offset as u32).to_be_bytes());
+        table.extend((tag.len() as u32).to_be_bytes());
+        data.extend(tag);
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+        offset = 128 + 4 + 12 * tags.len() + data.len();
+    }
+
+    let mut header = vec![0u8; 128];
+    header[0..4].copy_from_slice(&((128 + table.len() + data.len()) as u32).to_be_bytes());
+    header[8..12].copy_from_slice(&[2, 0x10, 0, 0]);
+    header[12..16].copy_from_slice(b"mntr");
+    header[16..20].copy_from_slice(b"GRAY");
+    header[20..24].copy_from_slice(b"XYZ ");
+    for (idx, value) in [2024u16, 1, 1, 0, 0, 0].iter().enumerate() {
+        header[24 + idx * 2..26 + idx * 2].copy_from_slice(&value.to_be_bytes());
+    }
+    header[36..40].copy_from_slice(b"acsp");
+    header[68..80].copy_from_slice(&d50);
+
+    [header, table, data].concat()
+}
+
+fn pdf_date(value: &str) -> Result<Date> {
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")?;
+    Ok(Date::new(chrono::Datelike::year(&date) as u16)
+        .month(chrono::Datelike::month(&date) as u8)
+        .day(chrono::Datelike::day(&date) as u8)
+        .hour(0)
+        .minute(0)
+        .second(0)
+        .utc_offset_hour(0))
+}
+
+/// XMP metadata with the Factur-X extension schema
+fn xmp_metadata(title: &str, date: &str, document_id: &str) -> String {
+    format!(
+        r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+ <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about=""
+    xmlns:dc="http://purl.org/dc/elements/1.1/"
+    xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+    xmlns:xmpMM="http://ns.adobe.com/xap/1.0/mm/"
+    xmlns:pdf="http://ns.adobe.com/pdf/1.3/"
+    xmlns:pdfaExtension="http://www.aiim.org/pdfa/ns/extension/"
+    xmlns:pdfaSchema="http://www.aiim.org/pdfa/ns/schema#"
+    xmlns:pdfaProperty="http://www.aiim.org/pdfa/ns/property#"
+    xmlns:fx="{fx}">
+   <dc:format>application/pdf</dc:format>
+   <dc:title><rdf:Alt><rdf:li xml:lang="x-default">{title}</rdf:li></rdf:Alt></dc:title>
+   <xmp:CreateDate>{date}T00:00:00Z</xmp:CreateDate>
+   <xmp:ModifyDate>{date}T00:00:00Z</xmp:ModifyDate>
+   <xmp:MetadataDate>{date}T00:00:00Z</xmp:MetadataDate>
+   <xmp:CreatorTool>ucelofka</xmp:CreatorTool>
+   <xmpMM:DocumentID>uuid:{document_id}</xmpMM:DocumentID>
+   <pdf:Producer>ucelofka</pdf:Producer>
+   <fx:DocumentType>INVOICE</fx:DocumentType>
+   <fx:DocumentFileName>{attachment}</fx:DocumentFileName>
+   <fx:Version>1.0</fx:Version>
+   <fx:ConformanceLevel>{conformance}</fx:ConformanceLevel>
+   <pdfaExtension:schemas>
+    <rdf:Bag>
+     <rdf:li rdf:parseType="Resource">
+      <pdfaSchema:schema>Factur-X PDFA Extension Schema</pdfaSchema:schema>
+      <pdfaSchema:namespaceURI>{fx}</pdfaSchema:namespaceURI>
+      <pdfaSchema:prefix>fx</pdfaSchema:prefix>
+      <pdfaSchema:property>
+       <rdf:Seq>
+{properties}
+       </rdf:Seq>
+      </pdfaSchema:property>
+     </rdf:li>
+    </rdf:Bag>
+   </pdfaExtension:schemas>
+  </rdf:Description>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#,
+        fx = FACTURX_NAMESPACE,
+        title = escape(title),
+        date = date,
+        document_id = document_id,
+        attachment = ATTACHMENT_NAME,
+        conformance = CONFORMANCE_LEVEL,
+        properties = [
+            ("DocumentFileName", "name of the embedded XML invoice file"),
+            ("DocumentType", "INVOICE"),
+            ("Version", "the version of the Factur-X XML schema"),
+            (
+                "ConformanceLevel",
+                "the conformance level of the embedded data"
+            ),
+        ]
+        .iter()
+        .map(|(name, description)| format!(
+            r#"        <rdf:li rdf:parseType="Resource">
+         <pdfaProperty:name>{}</pdfaProperty:name>
+         <pdfaProperty:valueType>Text</pdfaProperty:valueType>
+         <pdfaProperty:category>external</pdfaProperty:category>
+         <pdfaProperty:description>{}</pdfaProperty:description>
+        </rdf:li>"#,
+            name, description
+        ))
+        .collect::<Vec<_>>()
+        .join("\n"),
+    )
+}
+
+/// Creates a Factur-X PDF from the rendered text of the invoice
+///
+/// Lines of the text are placed to A4 pages, the first line is used as the document title.
+pub fn export(invoice: &Invoice, text: &str, font: &[u8]) -> Result<Vec<u8>> {
+    let mut layout = Layout::new(font)?;
+    let pages: Vec<Vec<Vec<u8>>> = {
+        let lines: Vec<Vec<u8>> = text.lines().flat_map(|line| layout.lines(line)).collect();
+        let per_page = ((PAGE_HEIGHT - 2.0 * MARGIN) / LINE_HEIGHT) as usize;
+        lines.chunks(per_page).map(<[_]>::to_vec).collect()
+    };
+    let title = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    let xml = cii::export(invoice)?;
+    let uuid = document_uuid(invoice);
+
+    let mut next_id = 1;
+    let mut alloc = || {
+        let id = Ref::new(next_id);
+        next_id += 1;
+        id
+    };
+    let catalog_id = alloc();
+    let page_tree_id = alloc();
+    let font_id = alloc();
+    let cid_font_id = alloc();
+    let descriptor_id = alloc();
+    let font_file_id = alloc();
+    let cmap_id = alloc();
+    let icc_id = alloc();
+    let metadata_id = alloc();
+    let file_spec_id = alloc();
+    let embedded_file_id = alloc();
+    let page_ids: Vec<(Ref, Ref)> = pages.iter().map(|_| (alloc(), alloc())).collect();
+
+    let mut pdf = Pdf::new();
+    pdf.set_version(1, 7);
+    pdf.set_file_id((uuid.as_bytes().to_vec(), uuid.as_bytes().to_vec()));
+
+    // Document structure
+    let mut catalog = pdf.catalog(catalog_id);
+    catalog.pages(page_tree_id).metadata(metadata_id);
+    catalog
+        .names()
+        .embedded_files()
+        .names()
+        .insert(Str(ATTACHMENT_NAME.as_bytes()), file_spec_id);
+    catalog.insert(Name(b"AF")).array().item(file_spec_id);
+    catalog
+        .output_intents()
+        .push()
+        .pair(Name(b"Type"), Name(b"OutputIntent"))
+        .pair(Name(b"S"), Name(b"GTS_PDFA1"))
+        .pair(
+            Name(b"OutputConditionIdentifier"),
+            TextStr("ucelofka gray gamma 2.2"),
+        )
+        .pair(Name(b"DestOutputProfile"), icc_id);
+    catalog.finish();
+
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().map(|(page_id, _)| *page_id))
+        .count(page_ids.len() as i32);
+
+    for (lines, (page_id, content_id)) in pages.iter().zip(&page_ids) {
+        let mut page = pdf.page(*page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+            .parent(page_tree_id)
+            .contents(*content_id);
+        page.resources().fonts().pair(FONT_NAME, font_id);
+        page.finish();
+
+        let mut content = Content::new();
+        content.set_fill_gray(0.0);
+        content.begin_text();
+        content.set_font(FONT_NAME, FONT_SIZE);
+        content.next_line(MARGIN, PAGE_HEIGHT - MARGIN - FONT_SIZE);
+        for (idx, line) in lines.iter().enumerate() {
+            if idx > 0 {
+                content.next_line(0.0, -LINE_HEIGHT);
+            }
+            content.show(Str(line));
+        }
+        content.end_text();
+        let content = compress_to_vec_zlib(&content.finish(), COMPRESSION_LEVEL);
+        pdf.stream(*content_id, &content)
+            .filter(Filter::FlateDecode);
+    }
+
+    // Font
+    let base_font = format!("UCELOF+{}", layout.postscript_name());
+    let face = &layout.face;
+    pdf.type0_font(font_id)
+        .base_font(Name(base_font.as_bytes()))
+        .encoding_predefined(Name(b"Identity-H"))
+        .descendant_font(cid_font_id)
+        .to_unicode(cmap_id);
+
+    let mut cid_font = pdf.cid_font(cid_font_id);
+    cid_font
+        .subtype(CidFontType::Type2)
+        .base_font(Name(base_font.as_bytes()))
+        .system_info(SYSTEM_INFO)
+        .font_descriptor(descriptor_id)
+        .cid_to_gid_map_predefined(Name(b"Identity"));
+    let mut widths = cid_font.widths();
+    for (glyph, (_, advance)) in &layout.glyphs {
+        widths.consecutive(*glyph, [layout.scale(*advance as f32)]);
+    }
+    widths.finish();
+    cid_font.finish();
+
+    let bbox = face.global_bounding_box();
+    let mut flags = FontFlags::NON_SYMBOLIC;
+    if face.is_monospaced() {
+        flags |= FontFlags::FIXED_PITCH;
+    }
+    pdf.font_descriptor(descriptor_id)
+        .name(Name(base_font.as_bytes()))
+        .flags(flags)
+        .bbox(Rect::new(
+            layout.scale(bbox.x_min as f32),
+            layout.scale(bbox.y_min as f32),
+            layout.scale(bbox.x_max as f32),
+            layout.scale(bbox.y_max as f32),
+        ))
+        .italic_angle(face.italic_angle().unwrap_or(0.0))
+        .ascent(layout.scale(face.ascender() as f32))
+        .descent(layout.scale(face.descender() as f32))
+        .cap_height(layout.scale(face.capital_height().unwrap_or(face.ascender()) as f32))
+        .stem_v(80.0)
+        .font_file2(font_file_id);
+
+    let glyph_ids: Vec<u16> = layout.glyphs.keys().copied().collect();
+    let subset =
+        subsetter::subset(font, 0, subsetter::Profile::pdf(&glyph_ids)).map_err(|err| {
+            anyhow!(get_message(
+                "font-subset-failed",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })?;
+    let subset = compress_to_vec_zlib(&subset, COMPRESSION_LEVEL);
+    pdf.stream(font_file_id, &subset)
+        .filter(Filter::FlateDecode);
+
+    let mut cmap = UnicodeCmap::new(Name(b"Custom"), SYSTEM_INFO);
+    for (glyph, (c, _)) in &layout.glyphs {
+        cmap.pair(*glyph, *c);
+    }
+    pdf.cmap(cmap_id, &cmap.finish());
+
+    // PDF/A
+    let icc = gray_icc_profile();
+    pdf.icc_profile(icc_id, &icc).n(1);
+
+    let metadata = xmp_metadata(title, &invoice.issue_date, &uuid.hyphenated().to_string());
+    pdf.metadata(metadata_id, metadata.as_bytes());
+
+    // Factur-X attachment
+    let mut file_spec = pdf.file_spec(file_spec_id);
+    file_spec
+        .path(Str(ATTACHMENT_NAME.as_bytes()))
+        .unic_file(TextStr(ATTACHMENT_NAME))
+        .description(TextStr("Factur-X invoice"));
+    file_spec.pair(Name(b"AFRelationship"), Name(b"Alternative"));
+    file_spec
+        .insert(Name(b"EF"))
+        .dict()
+        .pair(Name(b"F"), embedded_file_id)
+        .pair(Name(b"UF"), embedded_file_id);
+    file_spec.finish();
+
+    let date = pdf_date(&invoice.issue_date)?;
+    let mut embedded = pdf.embedded_file(embedded_file_id, xml.as_bytes());
+    embedded.subtype(Name(b"text/xml"));
+    embedded
+        .params()
+        .size(xml.len() as i32)
+        .modification_date(date);
+    embedded.finish();
+
+    Ok(pdf.finish())
+}
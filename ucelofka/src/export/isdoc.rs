@@ -0,0 +1,232 @@
+//! ISDOC 6.0.2 - the czech e-invoicing standard
+//!
+//! Entries without VAT rate are exported with zero VAT.
+//! The currency of the invoice is used as the local currency so invoices
+//! of czech issuers have to be in CZK (the exchange rate is not known).
+
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+
+use crate::{
+    data::{identification::Identification, invoice::Invoice},
+    tax::Summary,
+    translations::get_message,
+};
+
+use super::{
+    amount, document_uuid, element, identification, text_element, xml_finish, xml_writer, Address,
+    XmlWriter, HOME_CURRENCY,
+};
+
+const NAMESPACE: &str = "http://isdoc.cz/namespace/2013";
+const VERSION: &str = "6.0.2";
+
+/// Splits `123-456789/0100` to account number and bank code
+fn split_account_number(account_number: &str) -> (&str, &str) {
+    account_number
+        .rsplit_once('/')
+        .unwrap_or((account_number, ""))
+}
+
+fn party(
+    writer: &mut XmlWriter,
+    name: &str,
+    address: &[String],
+    identifications: &[Identification],
+    phone: Option<&str>,
+    email: Option<&str>,
+) -> Result<()> {
+    let address = Address::parse(address, identifications);
+    element(writer, "Party", |writer| {
+        element(writer, "PartyIdentification", |writer| {
+            text_element(
+                writer,
+                "ID",
+                identification(identifications, "registration").unwrap_or_default(),
+            )
+        })?;
+        element(writer, "PartyName", |writer| {
+            text_element(writer, "Name", name)
+        })?;
+        element(writer, "PostalAddress", |writer| {
+            text_element(writer, "StreetName", &address.street)?;
+            text_element(writer, "BuildingNumber", &address.building_number)?;
+            text_element(writer, "CityName", &address.city)?;
+            text_element(writer, "PostalZone", &address.postal_zone)?;
+            element(writer, "Country", |writer| {
+                text_element(writer, "IdentificationCode", address.country_code())?;
+                text_element(writer, "Name", &address.country)
+            })
+        })?;
+        if let Some(tax) = identification(identifications, "tax") {
+            element(writer, "PartyTaxScheme", |writer| {
+                text_element(writer, "CompanyID", tax)?;
+                text_element(writer, "TaxScheme", "VAT")
+            })?;
+        }
+        if phone.is_some() || email.is_some() {
+            element(writer, "Contact", |writer| {
+                if let Some(phone) = phone {
+                    text_element(writer, "Telephone", phone)?;
+                }
+                if let Some(email) = email {
+                    text_element(writer, "ElectronicMail", email)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    })
+}
+
+pub fn export(invoice: &Invoice) -> Result<String> {
+    let issuer_address = Address::parse(&invoice.issuer.address, &invoice.issuer.identifications);
+    if issuer_address.country_code() == "CZ" && invoice.billing.currency != HOME_CURRENCY {
+        return Err(anyhow!(get_message(
+            "isdoc-foreign-currency",
+            Some(fluent_args![
+                "invoice" => invoice.id,
+                "currency" => invoice.billing.currency.as_str()
+            ])
+        )));
+    }
+
+    let mut writer = xml_writer();
+    let vat = Summary::new(invoice);
+    let base = amount(vat.base);
+    let total = amount(vat.total);
+    let zero = amount(0.0);
+
+    writer
+        .create_element("Invoice")
+        .with_attribute(("xmlns", NAMESPACE))
+        .with_attribute(("version", VERSION))
+        .write_inner_content(|writer| {
+            text_element(writer, "DocumentType", "1")?;
+            text_element(writer, "ID", &invoice.id.to_string())?;
+            text_element(
+                writer,
+                "UUID",
+                &document_uuid(invoice)
+                    .hyphenated()
+                    .to_string()
+                    .to_uppercase(),
+            )?;
+            text_element(writer, "IssuingSystem", "ucelofka")?;
+            text_element(writer, "IssueDate", &invoice.issue_date)?;
+            text_element(writer, "TaxPointDate", &invoice.issue_date)?;
+            text_element(writer, "VATApplicable", &vat.applicable.to_string())?;
+            text_element(writer, "ElectronicPossibilityAgreementReference", "")?;
+            text_element(writer, "LocalCurrencyCode", &invoice.billing.currency)?;
+            text_element(writer, "CurrRate", "1")?;
+            text_element(writer, "RefCurrRate", "1")?;
+
+            element(writer, "AccountingSupplierParty", |writer| {
+                party(
+                    writer,
+                    &invoice.issuer.name,
+                    &invoice.issuer.address,
+                    &invoice.issuer.identifications,
+                    invoice.issuer.phone.first().map(String::as_str),
+                    invoice.issuer.email.first().map(String::as_str),
+                )
+            })?;
+            element(writer, "AccountingCustomerParty", |writer| {
+                party(
+                    writer,
+                    &invoice.customer.name,
+                    &invoice.customer.address,
+                    &invoice.customer.identifications,
+                    None,
+                    invoice.customer.email.first().map(String::as_str),
+                )
+            })?;
+
+            element(writer, "InvoiceLines", |writer| {
+                for (idx, entry) in invoice.entries.iter().enumerate() {
+                    let price = amount(entry.price);
+                    let price_with_tax = amount(entry.price + entry.tax());
+                    element(writer, "InvoiceLine", |writer| {
+                        text_element(writer, "ID", &(idx + 1).to_string())?;
+                        text_element(writer, "InvoicedQuantity", "1")?;
+                        text_element(writer, "LineExtensionAmount", &price)?;
+                        text_element(writer, "LineExtensionAmountTaxInclusive", &price_with_tax)?;
+                        text_element(writer, "LineExtensionTaxAmount", &amount(entry.tax()))?;
+                        text_element(writer, "UnitPrice", &price)?;
+                        text_element(writer, "UnitPriceTaxInclusive", &price_with_tax)?;
+                        element(writer, "ClassifiedTaxCategory", |writer| {
+                            text_element(
+                                writer,
+                                "Percent",
+                                &entry.vat_rate.unwrap_or(0.0).to_string(),
+                            )?;
+                            text_element(writer, "VATCalculationMethod", "0")
+                        })?;
+                        if !entry.details.is_empty() {
+                            text_element(writer, "Note", &entry.details.join("; "))?;
+                        }
+                        element(writer, "Item", |writer| {
+                            text_element(writer, "Description", &entry.name)
+                        })
+                    })?;
+                }
+                Ok(())
+            })?;
+
+            element(writer, "TaxTotal", |writer| {
+                for subtotal in &vat.subtotals {
+                    let base = amount(subtotal.base);
+                    let tax = amount(subtotal.tax);
+                    let total = amount(subtotal.total);
+                    element(writer, "TaxSubTotal", |writer| {
+                        text_element(writer, "TaxableAmount", &base)?;
+                        text_element(writer, "TaxAmount", &tax)?;
+                        text_element(writer, "TaxInclusiveAmount", &total)?;
+                        text_element(writer, "AlreadyClaimedTaxableAmount", &zero)?;
+                        text_element(writer, "AlreadyClaimedTaxAmount", &zero)?;
+                        text_element(writer, "AlreadyClaimedTaxInclusiveAmount", &zero)?;
+                        text_element(writer, "DifferenceTaxableAmount", &base)?;
+                        text_element(writer, "DifferenceTaxAmount", &tax)?;
+                        text_element(writer, "DifferenceTaxInclusiveAmount", &total)?;
+                        element(writer, "TaxCategory", |writer| {
+                            text_element(writer, "Percent", &subtotal.rate.to_string())
+                        })
+                    })?;
+                }
+                text_element(writer, "TaxAmount", &amount(vat.tax))
+            })?;
+
+            element(writer, "LegalMonetaryTotal", |writer| {
+                text_element(writer, "TaxExclusiveAmount", &base)?;
+                text_element(writer, "TaxInclusiveAmount", &total)?;
+                text_element(writer, "AlreadyClaimedTaxExclusiveAmount", &zero)?;
+                text_element(writer, "AlreadyClaimedTaxInclusiveAmount", &zero)?;
+                text_element(writer, "DifferenceTaxExclusiveAmount", &base)?;
+                text_element(writer, "DifferenceTaxInclusiveAmount", &total)?;
+                text_element(writer, "PayableRoundingAmount", &zero)?;
+                text_element(writer, "PaidDepositsAmount", &zero)?;
+                text_element(writer, "PayableAmount", &total)
+            })?;
+
+            element(writer, "PaymentMeans", |writer| {
+                element(writer, "Payment", |writer| {
+                    text_element(writer, "PaidAmount", &total)?;
+                    // 42 - bank transfer
+                    text_element(writer, "PaymentMeansCode", "42")?;
+                    element(writer, "Details", |writer| {
+                        let (account_number, bank_code) =
+                            split_account_number(&invoice.billing.account_number);
+                        text_element(writer, "PaymentDueDate", &invoice.due_date)?;
+                        text_element(writer, "ID", account_number)?;
+                        text_element(writer, "BankCode", bank_code)?;
+                        text_element(writer, "Name", &invoice.billing.account_name)?;
+                        text_element(writer, "IBAN", &invoice.billing.IBAN.replace(' ', ""))?;
+                        text_element(writer, "BIC", &invoice.billing.BIC)?;
+                        text_element(writer, "VariableSymbol", &invoice.billing.variable_symbol)
+                    })
+                })
+            })
+        })?;
+
+    xml_finish(writer)
+}
@@ -0,0 +1,151 @@
+//! Money S3 XML with issued invoices (`SeznamFaktVyd`)
+//!
+//! Prices of the items are exported without VAT, the VAT is summarized in the reduced (`5`)
+//! and basic (`22`) rate levels of Money S3 (the second reduced rate is not supported).
+//! Amounts of invoices in other currency than CZK are kept in the invoice currency
+//! and the exchange rate is filled by Money S3.
+
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+
+use crate::{
+    data::invoice::Invoice,
+    tax::{Rate, Summary},
+    translations::get_message,
+};
+
+use super::{
+    amount, element, identification, text_element, xml_finish, xml_writer, Address, XmlWriter,
+    HOME_CURRENCY,
+};
+
+/// Price type of the items - without VAT
+const PRICE_TYPE: &str = "0";
+/// Payment condition - bank transfer
+const PAYMENT: &str = "převodem";
+
+fn partner(writer: &mut XmlWriter, invoice: &Invoice) -> Result<()> {
+    let customer = &invoice.customer;
+    let address = Address::parse(&customer.address, &customer.identifications);
+    element(writer, "DodOdb", |writer| {
+        text_element(writer, "ObchNazev", &customer.name)?;
+        element(writer, "ObchAdresa", |writer| {
+            text_element(writer, "Ulice", &address.street_line())?;
+            text_element(writer, "Misto", &address.city)?;
+            text_element(writer, "PSC", &address.postal_zone)?;
+            text_element(writer, "Stat", &address.country)?;
+            text_element(writer, "KodStatu", address.country_code())
+        })?;
+        text_element(writer, "FaktNazev", &customer.name)?;
+        if let Some(registration) = identification(&customer.identifications, "registration") {
+            text_element(writer, "ICO", registration)?;
+        }
+        if let Some(tax) = identification(&customer.identifications, "tax") {
+            text_element(writer, "DIC", tax)?;
+        }
+        if let Some(email) = customer.email.first() {
+            text_element(writer, "EMail", email)?;
+        }
+        Ok(())
+    })
+}
+
+fn invoice(writer: &mut XmlWriter, invoice: &Invoice) -> Result<()> {
+    let vat = Summary::new(invoice);
+    vat.check_rates(invoice)?;
+    if let Some(subtotal) = vat
+        .subtotals
+        .iter()
+        .find(|subtotal| Rate::classify(subtotal.rate) == Some(Rate::SecondReduced))
+    {
+        return Err(anyhow!(get_message(
+            "vat-rate-unsupported",
+            Some(fluent_args!["invoice" => invoice.id, "rate" => subtotal.rate])
+        )));
+    }
+    let (reduced, reduced_tax) = vat.level(Rate::Reduced);
+    let (basic, basic_tax) = vat.level(Rate::Basic);
+    let total = amount(vat.total);
+    let home = invoice.billing.currency == HOME_CURRENCY;
+
+    element(writer, "FaktVyd", |writer| {
+        text_element(writer, "Doklad", &invoice.id.to_string())?;
+        let description = invoice
+            .entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        text_element(writer, "Popis", &description)?;
+        text_element(writer, "DatUcPr", &invoice.issue_date)?;
+        text_element(writer, "DatVyst", &invoice.issue_date)?;
+        text_element(writer, "DatZdPln", &invoice.issue_date)?;
+        text_element(writer, "DatSplat", &invoice.due_date)?;
+        text_element(writer, "VarSymbol", &invoice.billing.variable_symbol)?;
+        text_element(writer, "PlatPodm", PAYMENT)?;
+        text_element(writer, "Vystavil", &invoice.issuer.name)?;
+        element(writer, "SouhrnDPH", |writer| {
+            text_element(writer, "Zaklad0", &amount(vat.exempt()))?;
+            text_element(writer, "Zaklad5", &amount(reduced))?;
+            text_element(writer, "Zaklad22", &amount(basic))?;
+            text_element(writer, "DPH5", &amount(reduced_tax))?;
+            text_element(writer, "DPH22", &amount(basic_tax))
+        })?;
+        text_element(writer, "Celkem", &total)?;
+        if !home {
+            element(writer, "Valuty", |writer| {
+                element(writer, "Mena", |writer| {
+                    text_element(writer, "Kod", &invoice.billing.currency)
+                })?;
+                text_element(writer, "Celkem", &total)
+            })?;
+        }
+        partner(writer, invoice)?;
+        element(writer, "Ucet", |writer| {
+            text_element(writer, "Ucet", &invoice.billing.account_number)?;
+            text_element(writer, "IBAN", &invoice.billing.IBAN.replace(' ', ""))?;
+            text_element(writer, "SWIFT", &invoice.billing.BIC)
+        })?;
+        element(writer, "SeznamPolozek", |writer| {
+            for entry in &invoice.entries {
+                element(writer, "Polozka", |writer| {
+                    text_element(writer, "Popis", &entry.name)?;
+                    text_element(writer, "PocetMJ", "1")?;
+                    text_element(writer, "Cena", &amount(entry.price))?;
+                    text_element(
+                        writer,
+                        "SazbaDPH",
+                        &entry.vat_rate.unwrap_or(0.0).to_string(),
+                    )?;
+                    text_element(writer, "TypCeny", PRICE_TYPE)?;
+                    if !entry.details.is_empty() {
+                        text_element(writer, "Poznamka", &entry.details.join("; "))?;
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })
+    })
+}
+
+/// `ico` is the registration number of the agenda the invoices are imported into
+pub fn export(invoices: &[Invoice], ico: &str) -> Result<String> {
+    let mut writer = xml_writer();
+
+    writer
+        .create_element("MoneyData")
+        .with_attribute(("ICAgendy", ico))
+        .with_attribute(("JazykVerze", "CZ"))
+        .with_attribute(("description", "Issued invoices"))
+        .write_inner_content(|writer| {
+            element(writer, "SeznamFaktVyd", |writer| {
+                for record in invoices {
+                    invoice(writer, record)?;
+                }
+                Ok(())
+            })
+        })?;
+
+    xml_finish(writer)
+}
@@ -0,0 +1,187 @@
+//! Pohoda XML data pack with issued invoices
+//!
+//! Prices of the items are exported without VAT, the VAT rates are mapped to the rate levels
+//! of Pohoda (`none`, `low`, `high` and `third`). Invoices in other currency than CZK
+//! are exported in the foreign currency and the exchange rate is filled by Pohoda.
+
+use anyhow::Result;
+
+use crate::{
+    data::invoice::Invoice,
+    tax::{Rate, Summary},
+};
+
+use super::{
+    amount, element, identification, text_element, xml_finish, xml_writer, Address, XmlWriter,
+    HOME_CURRENCY,
+};
+
+const NAMESPACE_DAT: &str = "http://www.stormware.cz/schema/version_2/data.xsd";
+const NAMESPACE_INV: &str = "http://www.stormware.cz/schema/version_2/invoice.xsd";
+const NAMESPACE_TYP: &str = "http://www.stormware.cz/schema/version_2/type.xsd";
+const VERSION: &str = "2.0";
+
+fn rate_vat(rate: Option<f32>) -> &'static str {
+    match rate.and_then(Rate::classify) {
+        Some(Rate::Basic) => "high",
+        Some(Rate::Reduced) => "low",
+        Some(Rate::SecondReduced) => "third",
+        None => "none",
+    }
+}
+
+fn partner(writer: &mut XmlWriter, invoice: &Invoice) -> Result<()> {
+    let customer = &invoice.customer;
+    let address = Address::parse(&customer.address, &customer.identifications);
+    element(writer, "inv:partnerIdentity", |writer| {
+        element(writer, "typ:address", |writer| {
+            text_element(writer, "typ:company", &customer.name)?;
+            text_element(writer, "typ:city", &address.city)?;
+            text_element(writer, "typ:street", &address.street_line())?;
+            text_element(writer, "typ:zip", &address.postal_zone)?;
+            if let Some(registration) = identification(&customer.identifications, "registration") {
+                text_element(writer, "typ:ico", registration)?;
+            }
+            if let Some(tax) = identification(&customer.identifications, "tax") {
+                text_element(writer, "typ:dic", tax)?;
+            }
+            element(writer, "typ:country", |writer| {
+                text_element(writer, "typ:ids", address.country_code())
+            })?;
+            if let Some(email) = customer.email.first() {
+                text_element(writer, "typ:email", email)?;
+            }
+            Ok(())
+        })
+    })
+}
+
+fn invoice(writer: &mut XmlWriter, invoice: &Invoice) -> Result<()> {
+    let id = invoice.id.to_string();
+    let home = invoice.billing.currency == HOME_CURRENCY;
+    let vat = Summary::new(invoice);
+    vat.check_rates(invoice)?;
+    let (account_number, bank_code) = invoice
+        .billing
+        .account_number
+        .rsplit_once('/')
+        .unwrap_or((&invoice.billing.account_number, ""));
+
+    writer
+        .create_element("inv:invoice")
+        .with_attribute(("version", VERSION))
+        .write_inner_content(|writer| {
+            element(writer, "inv:invoiceHeader", |writer| {
+                text_element(writer, "inv:invoiceType", "issuedInvoice")?;
+                element(writer, "inv:number", |writer| {
+                    text_element(writer, "typ:numberRequested", &id)
+                })?;
+                text_element(writer, "inv:symVar", &invoice.billing.variable_symbol)?;
+                text_element(writer, "inv:date", &invoice.issue_date)?;
+                text_element(writer, "inv:dateTax", &invoice.issue_date)?;
+                text_element(writer, "inv:dateAccounting", &invoice.issue_date)?;
+                text_element(writer, "inv:dateDue", &invoice.due_date)?;
+                let text = invoice
+                    .entries
+                    .iter()
+                    .map(|entry| entry.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                text_element(writer, "inv:text", &text)?;
+                partner(writer, invoice)?;
+                element(writer, "inv:paymentType", |writer| {
+                    text_element(writer, "typ:paymentType", "draft")
+                })?;
+                element(writer, "inv:account", |writer| {
+                    text_element(writer, "typ:accountNo", account_number)?;
+                    text_element(writer, "typ:bankCode", bank_code)
+                })
+            })?;
+
+            element(writer, "inv:invoiceDetail", |writer| {
+                for entry in &invoice.entries {
+                    element(writer, "inv:invoiceItem", |writer| {
+                        text_element(writer, "inv:text", &entry.name)?;
+                        text_element(writer, "inv:quantity", "1")?;
+                        text_element(writer, "inv:payVAT", "false")?;
+                        text_element(writer, "inv:rateVAT", rate_vat(entry.vat_rate))?;
+                        let currency = if home {
+                            "inv:homeCurrency"
+                        } else {
+                            "inv:foreignCurrency"
+                        };
+                        element(writer, currency, |writer| {
+                            text_element(writer, "typ:unitPrice", &amount(entry.price))
+                        })?;
+                        if !entry.details.is_empty() {
+                            text_element(writer, "inv:note", &entry.details.join("; "))?;
+                        }
+                        Ok(())
+                    })?;
+                }
+                Ok(())
+            })?;
+
+            element(writer, "inv:invoiceSummary", |writer| {
+                text_element(writer, "inv:roundingDocument", "none")?;
+                if home {
+                    element(writer, "inv:homeCurrency", |writer| {
+                        text_element(writer, "typ:priceNone", &amount(vat.exempt()))?;
+                        for (level, name) in [
+                            (Rate::Reduced, "priceLow"),
+                            (Rate::Basic, "priceHigh"),
+                            (Rate::SecondReduced, "price3"),
+                        ] {
+                            let (base, tax) = vat.level(level);
+                            if base != 0.0 {
+                                text_element(writer, &format!("typ:{}", name), &amount(base))?;
+                                text_element(writer, &format!("typ:{}VAT", name), &amount(tax))?;
+                                text_element(
+                                    writer,
+                                    &format!("typ:{}Sum", name),
+                                    &amount(base + tax),
+                                )?;
+                            }
+                        }
+                        Ok(())
+                    })
+                } else {
+                    element(writer, "inv:foreignCurrency", |writer| {
+                        element(writer, "typ:currency", |writer| {
+                            text_element(writer, "typ:ids", &invoice.billing.currency)
+                        })?;
+                        text_element(writer, "typ:priceSum", &amount(vat.total))
+                    })
+                }
+            })
+        })?;
+    Ok(())
+}
+
+/// `ico` is the registration number of the accounting unit the data pack is imported into
+pub fn export(invoices: &[Invoice], ico: &str) -> Result<String> {
+    let mut writer = xml_writer();
+
+    writer
+        .create_element("dat:dataPack")
+        .with_attribute(("xmlns:dat", NAMESPACE_DAT))
+        .with_attribute(("xmlns:inv", NAMESPACE_INV))
+        .with_attribute(("xmlns:typ", NAMESPACE_TYP))
+        .with_attribute(("id", "ucelofka"))
+        .with_attribute(("ico", ico))
+        .with_attribute(("application", "ucelofka"))
+        .with_attribute(("version", VERSION))
+        .with_attribute(("note", "Issued invoices"))
+        .write_inner_content(|writer| {
+            for record in invoices {
+                writer
+                    .create_element("dat:dataPackItem")
+                    .with_attribute(("id", record.id.to_string().as_str()))
+                    .with_attribute(("version", VERSION))
+                    .write_inner_content(|writer| invoice(writer, record))?;
+            }
+            Ok::<_, anyhow::Error>(())
+        })?;
+
+    xml_finish(writer)
+}
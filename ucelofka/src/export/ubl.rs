@@ -0,0 +1,246 @@
+//! UBL 2.1 invoice following the Peppol BIS Billing 3.0 (EN 16931) rules
+//!
+//! Entries with VAT rate are exported in the standard rated (`S`) category, the other ones
+//! in the exempt (`E`) category when the issuer has a VAT id or in the not subject
+//! to VAT (`O`) category otherwise. Credit notes are not modelled in ucelofka
+//! so only invoices are exported.
+
+use anyhow::Result;
+
+use crate::{
+    data::{identification::Identification, invoice::Invoice},
+    tax::Summary,
+};
+
+use super::{
+    amount, element, identification, text_element, text_element_with_attributes, xml_finish,
+    xml_writer, Address, Untaxed, XmlWriter,
+};
+
+const NAMESPACE: &str = "urn:oasis:names:specification:ubl:schema:xsd:Invoice-2";
+const NAMESPACE_CAC: &str =
+    "urn:oasis:names:specification:ubl:schema:xsd:CommonAggregateComponents-2";
+const NAMESPACE_CBC: &str = "urn:oasis:names:specification:ubl:schema:xsd:CommonBasicComponents-2";
+const CUSTOMIZATION_ID: &str =
+    "urn:cen.eu:en16931:2017#compliant#urn:fdc:peppol.eu:2017:poacc:billing:3.0";
+const PROFILE_ID: &str = "urn:fdc:peppol.eu:2017:poacc:billing:01:1.0";
+/// Commercial invoice
+const INVOICE_TYPE_CODE: &str = "380";
+/// Credit transfer
+const PAYMENT_MEANS_CODE: &str = "30";
+/// Standard rated
+const TAX_CATEGORY_STANDARD: &str = "S";
+/// Unit code for "one" (piece)
+const UNIT_CODE: &str = "C62";
+/// Electronic address scheme for emails
+const EMAIL_SCHEME: &str = "EM";
+
+fn money(writer: &mut XmlWriter, name: &str, currency: &str, value: f32) -> Result<()> {
+    text_element_with_attributes(writer, name, &[("currencyID", currency)], &amount(value))
+}
+
+fn tax_scheme(writer: &mut XmlWriter) -> Result<()> {
+    element(writer, "cac:TaxScheme", |writer| {
+        text_element(writer, "cbc:ID", "VAT")
+    })
+}
+
+/// The exemption reason is written only in the VAT breakdown
+fn tax_category(
+    writer: &mut XmlWriter,
+    name: &str,
+    rate: f32,
+    untaxed: Untaxed,
+    reason: bool,
+) -> Result<()> {
+    element(writer, name, |writer| {
+        if rate == 0.0 {
+            text_element(writer, "cbc:ID", untaxed.code())?;
+            if let Some(percent) = untaxed.percent() {
+                text_element(writer, "cbc:Percent", percent)?;
+            }
+            if reason {
+                text_element(writer, "cbc:TaxExemptionReason", untaxed.reason())?;
+            }
+        } else {
+            text_element(writer, "cbc:ID", TAX_CATEGORY_STANDARD)?;
+            text_element(writer, "cbc:Percent", &rate.to_string())?;
+        }
+        tax_scheme(writer)
+    })
+}
+
+fn party(
+    writer: &mut XmlWriter,
+    name: &str,
+    address: &[String],
+    identifications: &[Identification],
+    phone: Option<&str>,
+    email: Option<&str>,
+) -> Result<()> {
+    let address = Address::parse(address, identifications);
+    let registration = identification(identifications, "registration");
+    element(writer, "cac:Party", |writer| {
+        if let Some(email) = email {
+            text_element_with_attributes(
+                writer,
+                "cbc:EndpointID",
+                &[("schemeID", EMAIL_SCHEME)],
+                email,
+            )?;
+        }
+        if let Some(registration) = registration {
+            element(writer, "cac:PartyIdentification", |writer| {
+                text_element(writer, "cbc:ID", registration)
+            })?;
+        }
+        element(writer, "cac:PartyName", |writer| {
+            text_element(writer, "cbc:Name", name)
+        })?;
+        element(writer, "cac:PostalAddress", |writer| {
+            text_element(writer, "cbc:StreetName", &address.street_line())?;
+            text_element(writer, "cbc:CityName", &address.city)?;
+            text_element(writer, "cbc:PostalZone", &address.postal_zone)?;
+            element(writer, "cac:Country", |writer| {
+                text_element(writer, "cbc:IdentificationCode", address.country_code())
+            })
+        })?;
+        if let Some(tax) = identification(identifications, "tax") {
+            element(writer, "cac:PartyTaxScheme", |writer| {
+                text_element(writer, "cbc:CompanyID", tax)?;
+                tax_scheme(writer)
+            })?;
+        }
+        element(writer, "cac:PartyLegalEntity", |writer| {
+            text_element(writer, "cbc:RegistrationName", name)?;
+            if let Some(registration) = registration {
+                text_element(writer, "cbc:CompanyID", registration)?;
+            }
+            Ok(())
+        })?;
+        if phone.is_some() || email.is_some() {
+            element(writer, "cac:Contact", |writer| {
+                if let Some(phone) = phone {
+                    text_element(writer, "cbc:Telephone", phone)?;
+                }
+                if let Some(email) = email {
+                    text_element(writer, "cbc:ElectronicMail", email)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    })
+}
+
+pub fn export(invoice: &Invoice) -> Result<String> {
+    let mut writer = xml_writer();
+    let currency = invoice.billing.currency.as_str();
+    let vat = Summary::new(invoice);
+    let untaxed = Untaxed::new(&invoice.issuer.identifications);
+
+    writer
+        .create_element("Invoice")
+        .with_attribute(("xmlns", NAMESPACE))
+        .with_attribute(("xmlns:cac", NAMESPACE_CAC))
+        .with_attribute(("xmlns:cbc", NAMESPACE_CBC))
+        .write_inner_content(|writer| {
+            text_element(writer, "cbc:CustomizationID", CUSTOMIZATION_ID)?;
+            text_element(writer, "cbc:ProfileID", PROFILE_ID)?;
+            text_element(writer, "cbc:ID", &invoice.id.to_string())?;
+            text_element(writer, "cbc:IssueDate", &invoice.issue_date)?;
+            text_element(writer, "cbc:DueDate", &invoice.due_date)?;
+            text_element(writer, "cbc:InvoiceTypeCode", INVOICE_TYPE_CODE)?;
+            text_element(writer, "cbc:DocumentCurrencyCode", currency)?;
+            // reference assigned by the buyer (e.g. Leitweg-ID), required by Peppol
+            // so the invoice id is used when the customer doesn't have any
+            let invoice_id = invoice.id.to_string();
+            let reference = identification(&invoice.customer.identifications, "buyer_reference")
+                .unwrap_or(&invoice_id);
+            text_element(writer, "cbc:BuyerReference", reference)?;
+
+            element(writer, "cac:AccountingSupplierParty", |writer| {
+                party(
+                    writer,
+                    &invoice.issuer.name,
+                    &invoice.issuer.address,
+                    &invoice.issuer.identifications,
+                    invoice.issuer.phone.first().map(String::as_str),
+                    invoice.issuer.email.first().map(String::as_str),
+                )
+            })?;
+            element(writer, "cac:AccountingCustomerParty", |writer| {
+                party(
+                    writer,
+                    &invoice.customer.name,
+                    &invoice.customer.address,
+                    &invoice.customer.identifications,
+                    None,
+                    invoice.customer.email.first().map(String::as_str),
+                )
+            })?;
+
+            element(writer, "cac:PaymentMeans", |writer| {
+                text_element(writer, "cbc:PaymentMeansCode", PAYMENT_MEANS_CODE)?;
+                text_element(writer, "cbc:PaymentID", &invoice.billing.variable_symbol)?;
+                element(writer, "cac:PayeeFinancialAccount", |writer| {
+                    text_element(writer, "cbc:ID", &invoice.billing.IBAN.replace(' ', ""))?;
+                    text_element(writer, "cbc:Name", &invoice.billing.account_name)?;
+                    element(writer, "cac:FinancialInstitutionBranch", |writer| {
+                        text_element(writer, "cbc:ID", &invoice.billing.BIC)
+                    })
+                })
+            })?;
+
+            element(writer, "cac:TaxTotal", |writer| {
+                money(writer, "cbc:TaxAmount", currency, vat.tax)?;
+                for subtotal in &vat.subtotals {
+                    element(writer, "cac:TaxSubtotal", |writer| {
+                        money(writer, "cbc:TaxableAmount", currency, subtotal.base)?;
+                        money(writer, "cbc:TaxAmount", currency, subtotal.tax)?;
+                        tax_category(writer, "cac:TaxCategory", subtotal.rate, untaxed, true)
+                    })?;
+                }
+                Ok(())
+            })?;
+
+            element(writer, "cac:LegalMonetaryTotal", |writer| {
+                money(writer, "cbc:LineExtensionAmount", currency, vat.base)?;
+                money(writer, "cbc:TaxExclusiveAmount", currency, vat.base)?;
+                money(writer, "cbc:TaxInclusiveAmount", currency, vat.total)?;
+                money(writer, "cbc:PayableAmount", currency, vat.total)
+            })?;
+
+            for (idx, entry) in invoice.entries.iter().enumerate() {
+                element(writer, "cac:InvoiceLine", |writer| {
+                    text_element(writer, "cbc:ID", &(idx + 1).to_string())?;
+                    text_element_with_attributes(
+                        writer,
+                        "cbc:InvoicedQuantity",
+                        &[("unitCode", UNIT_CODE)],
+                        "1",
+                    )?;
+                    money(writer, "cbc:LineExtensionAmount", currency, entry.price)?;
+                    element(writer, "cac:Item", |writer| {
+                        if !entry.details.is_empty() {
+                            text_element(writer, "cbc:Description", &entry.details.join("; "))?;
+                        }
+                        text_element(writer, "cbc:Name", &entry.name)?;
+                        tax_category(
+                            writer,
+                            "cac:ClassifiedTaxCategory",
+                            entry.vat_rate.unwrap_or(0.0),
+                            untaxed,
+                            false,
+                        )
+                    })?;
+                    element(writer, "cac:Price", |writer| {
+                        money(writer, "cbc:PriceAmount", currency, entry.price)
+                    })
+                })?;
+            }
+            Ok::<_, anyhow::Error>(())
+        })?;
+
+    xml_finish(writer)
+}
@@ -0,0 +1,367 @@
+//! Composing and delivering emails
+//!
+//! Messages are delivered either to an SMTP server (encrypted by STARTTLS or TLS unless
+//! disabled for a local relay) or to a `sendmail` compatible binary (e.g. `msmtp`)
+//! which takes care of the rest.
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::Local;
+use fluent::fluent_args;
+use lettre::{
+    address::Envelope,
+    transport::smtp::{
+        authentication::Credentials,
+        client::{Tls, TlsParameters},
+        SmtpTransport,
+    },
+    Address, Transport as _,
+};
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::translations::get_message;
+
+const LINE_LENGTH: usize = 76;
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub filename: String,
+    pub content_type: &'static str,
+    pub data: Vec<u8>,
+}
+
+impl Attachment {
+    pub fn new(filename: String, data: Vec<u8>) -> Self {
+        let extension = filename
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_lowercase())
+            .unwrap_or_default();
+        let content_type = match extension.as_str() {
+            "html" | "htm" => "text/html; charset=utf-8",
+            "txt" => "text/plain; charset=utf-8",
+            "pdf" => "application/pdf",
+            "xml" | "isdoc" => "application/xml",
+            _ => "application/octet-stream",
+        };
+        Self {
+            filename,
+            content_type,
+            data,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Message {
+    /// Sender in `Name <address>` or `address` format
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: String,
+    pub body: String,
+    pub attachments: Vec<Attachment>,
+}
+
+/// Email address without the display name
+pub fn address(value: &str) -> &str {
+    match (value.rfind('<'), value.rfind('>')) {
+        (Some(start), Some(end)) if start < end => value[start + 1..end].trim(),
+        _ => value.trim(),
+    }
+}
+
+/// Encodes a header value which contains non-ASCII characters (RFC 2047)
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(value))
+    }
+}
+
+/// Encodes the display name of an address
+fn encode_address(value: &str) -> String {
+    match value.rfind('<') {
+        Some(idx) if !value[..idx].trim().is_empty() => format!(
+            "{} <{}>",
+            encode_header(value[..idx].trim()),
+            address(value)
+        ),
+        _ => address(value).to_string(),
+    }
+}
+
+fn base64_lines(data: &[u8]) -> String {
+    let encoded = STANDARD.encode(data);
+    encoded
+        .as_bytes()
+        .chunks(LINE_LENGTH)
+        .map(|chunk| String::from_utf8_lossy(chunk).to_string())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+impl Message {
+    /// Message in the internet message format (`.eml`) with CRLF line endings
+    pub fn to_eml(&self) -> Vec<u8> {
+        let now = Local::now();
+        let domain = address(&self.from)
+            .rsplit_once('@')
+            .map(|(_, domain)| domain)
+            .unwrap_or("localhost");
+        let boundary = format!(
+            "=_ucelofka_{}",
+            now.timestamp_nanos_opt().unwrap_or_default()
+        );
+
+        let mut lines = vec![
+            format!("From: {}", encode_address(&self.from)),
+            format!(
+                "To: {}",
+                self.to
+                    .iter()
+                    .map(|to| encode_address(to))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            format!("Subject: {}", encode_header(&self.subject)),
+            format!("Date: {}", now.to_rfc2822()),
+            format!(
+                "Message-ID: <{}@{}>",
+                now.timestamp_nanos_opt().unwrap_or_default(),
+                domain
+            ),
+            "MIME-Version: 1.0".to_string(),
+            format!("Content-Type: multipart/mixed; boundary=\"{}\"", boundary),
+            String::new(),
+            format!("--{}", boundary),
+            "Content-Type: text/plain; charset=utf-8".to_string(),
+            "Content-Transfer-Encoding: base64".to_string(),
+            String::new(),
+            base64_lines(self.body.as_bytes()),
+        ];
+        for attachment in &self.attachments {
+            lines.extend([
+                format!("--{}", boundary),
+                format!("Content-Type: {}", attachment.content_type),
+                "Content-Transfer-Encoding: base64".to_string(),
+                format!(
+                    "Content-Disposition: attachment; filename=\"{}\"",
+                    encode_header(&attachment.filename)
+                ),
+                String::new(),
+                base64_lines(&attachment.data),
+            ]);
+        }
+        lines.push(format!("--{}--", boundary));
+        lines.push(String::new());
+        lines.join("\r\n").into_bytes()
+    }
+}
+
+/// Encryption of the SMTP connection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Security {
+    /// Plain connection upgraded by STARTTLS (submission)
+    StartTls,
+    /// TLS from the start (submissions)
+    Tls,
+    /// Unencrypted connection, meant for a local relay only
+    None,
+}
+
+impl Security {
+    pub const NAMES: &'static [&'static str] = &["starttls", "tls", "none"];
+
+    fn default_port(&self) -> u16 {
+        match self {
+            Self::StartTls => 587,
+            Self::Tls => 465,
+            Self::None => 25,
+        }
+    }
+}
+
+impl FromStr for Security {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "starttls" => Ok(Self::StartTls),
+            "tls" => Ok(Self::Tls),
+            "none" => Ok(Self::None),
+            _ => Err(anyhow!(get_message(
+                "smtp-unknown-security",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Security {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StartTls => write!(f, "starttls"),
+            Self::Tls => write!(f, "tls"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+/// How the messages are delivered
+#[derive(Debug, Clone)]
+pub enum Transport {
+    Smtp {
+        host: String,
+        port: u16,
+        security: Security,
+        credentials: Option<(String, String)>,
+    },
+    Sendmail(PathBuf),
+}
+
+impl Transport {
+    /// SMTP server in `host[:port]` format
+    ///
+    /// The credentials are never sent over an unencrypted connection.
+    pub fn smtp(
+        server: &str,
+        security: Security,
+        credentials: Option<(String, String)>,
+    ) -> Result<Self> {
+        if security == Security::None && credentials.is_some() {
+            return Err(anyhow!(get_message("smtp-credentials-unencrypted", None)));
+        }
+        let (host, port) = match server.rsplit_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse::<u16>().map_err(|err| {
+                    anyhow!(get_message(
+                        "smtp-invalid-port",
+                        Some(fluent_args!["port" => port, "error" => err.to_string()])
+                    ))
+                })?,
+            ),
+            None => (server, security.default_port()),
+        };
+        Ok(Self::Smtp {
+            host: host.to_string(),
+            port,
+            security,
+            credentials,
+        })
+    }
+
+    pub fn send(&self, message: &Message) -> Result<()> {
+        match self {
+            Self::Smtp {
+                host,
+                port,
+                security,
+                credentials,
+            } => smtp_send(host, *port, *security, credentials.as_ref(), message),
+            Self::Sendmail(path) => sendmail_send(path, message),
+        }
+    }
+}
+
+fn sendmail_send(path: &Path, message: &Message) -> Result<()> {
+    let mut child = Command::new(path)
+        .arg("-i")
+        .arg("-f")
+        .arg(address(&message.from))
+        .arg("--")
+        .args(message.to.iter().map(|to| address(to)))
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|err| {
+            anyhow!(get_message(
+                "command-run-failed",
+                Some(fluent_args!["path" => path.to_string_lossy(), "error" => err.to_string()])
+            ))
+        })?;
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| {
+            anyhow!(get_message(
+                "command-write-failed",
+                Some(fluent_args!["path" => path.to_string_lossy()])
+            ))
+        })?
+        .write_all(&message.to_eml())?;
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(anyhow!(get_message(
+            "command-failed",
+            Some(fluent_args!["path" => path.to_string_lossy(), "status" => status.to_string()])
+        )));
+    }
+    Ok(())
+}
+
+fn parse_address(value: &str) -> Result<Address> {
+    address(value)
+        .parse()
+        .map_err(|err: lettre::address::AddressError| {
+            anyhow!(get_message(
+                "invalid-email-address",
+                Some(fluent_args!["address" => value, "error" => err.to_string()])
+            ))
+        })
+}
+
+fn smtp_send(
+    host: &str,
+    port: u16,
+    security: Security,
+    credentials: Option<&(String, String)>,
+    message: &Message,
+) -> Result<()> {
+    let failed = |err: String| {
+        anyhow!(get_message(
+            "smtp-send-failed",
+            Some(fluent_args!["host" => host, "port" => port, "error" => err])
+        ))
+    };
+    let tls = match security {
+        Security::None => Tls::None,
+        Security::StartTls | Security::Tls => {
+            let parameters =
+                TlsParameters::new(host.to_string()).map_err(|err| failed(err.to_string()))?;
+            if security == Security::Tls {
+                Tls::Wrapper(parameters)
+            } else {
+                Tls::Required(parameters)
+            }
+        }
+    };
+    let mut builder = SmtpTransport::builder_dangerous(host)
+        .port(port)
+        .tls(tls)
+        .timeout(Some(TIMEOUT));
+    if let Some((user, password)) = credentials {
+        builder = builder.credentials(Credentials::new(user.clone(), password.clone()));
+    }
+    let envelope = Envelope::new(
+        Some(parse_address(&message.from)?),
+        message
+            .to
+            .iter()
+            .map(|to| parse_address(to))
+            .collect::<Result<_>>()?,
+    )
+    .map_err(|err| failed(err.to_string()))?;
+    builder
+        .build()
+        .send_raw(&envelope, &message.to_eml())
+        .map_err(|err| failed(err.to_string()))?;
+    Ok(())
+}
@@ -1,27 +1,47 @@
 pub mod actions;
+pub mod config;
+pub mod export;
+pub mod mail;
+pub mod report;
+pub mod statement;
 pub mod storage;
+pub mod tabular;
+pub mod tax;
+pub mod templating;
 pub mod translations;
 pub mod tui;
+pub mod watch;
 pub mod web;
 
 use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
 use clap::{
-    crate_authors, crate_description, crate_name, crate_version, value_parser, Arg, ArgAction,
-    ArgMatches, Command,
+    crate_authors, crate_name, crate_version, value_parser, Arg, ArgAction, ArgMatches, Command,
 };
 use clap_complete::{
     generate,
     shells::{Bash, Elvish, Fish, PowerShell, Zsh},
     Generator,
 };
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1250};
 use fluent::fluent_args;
-use std::io;
 use std::path::{Path, PathBuf};
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    io::{self, Read},
+};
 use ucelofka_data as data;
 
 use crate::{
-    actions::{account, customer, entry, identity, ids, invoice, project, template},
-    translations::{get_message, texts},
+    actions::{
+        account, customer, entry, identity, ids, invoice, payment, project,
+        records::{Conflict, ImportSummary},
+        template,
+    },
+    mail::Transport,
+    translations::get_message,
 };
 
 pub fn check_data_dir(root_dir: &Path) -> Result<PathBuf> {
@@ -70,11 +90,21 @@ fn prepare_data_dir() -> Arg {
         .num_args(1)
         .required(false)
         .value_parser(value_parser!(PathBuf))
-        .help(texts::DATA_DIRECTORY_PATH.to_string())
+        .help(get_message("data-directory-path", None))
         .default_value(".")
 }
 
-fn prepare_get_subcommand(help: &'static str) -> Command {
+fn prepare_output_pattern() -> Arg {
+    Arg::new("pattern")
+        .env("UCELOFKA_OUTPUT_PATTERN")
+        .help(get_message("help-output-pattern", None))
+        .long("pattern")
+        .num_args(1)
+        .required(false)
+        .default_value(invoice::DEFAULT_OUTPUT_PATTERN)
+}
+
+fn prepare_get_subcommand(help: String) -> Command {
     Command::new("get")
         .arg(
             Arg::new("id")
@@ -86,16 +116,80 @@ fn prepare_get_subcommand(help: &'static str) -> Command {
         .about(help)
 }
 
+fn prepare_table_export_subcommand(help: String) -> Command {
+    Command::new("export")
+        .arg(
+            Arg::new("format")
+                .help(get_message("help-export-format", None))
+                .short('F')
+                .long("format")
+                .num_args(1)
+                .required(true)
+                .value_parser(tabular::Format::NAMES.to_vec()),
+        )
+        .arg(
+            Arg::new("output")
+                .help(get_message("help-output-or-stdout", None))
+                .short('O')
+                .long("output")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .about(help)
+}
+
+fn prepare_import_subcommand(help: String) -> Command {
+    Command::new("import")
+        .arg(
+            Arg::new("input")
+                .help(get_message("help-import-input", None))
+                .short('i')
+                .long("input")
+                .num_args(1)
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("format")
+                .help(get_message("help-import-format", None))
+                .short('F')
+                .long("format")
+                .num_args(1)
+                .required(false)
+                .value_parser(tabular::Format::NAMES.to_vec()),
+        )
+        .arg(
+            Arg::new("conflict")
+                .help(get_message("help-import-conflict", None))
+                .long("conflict")
+                .num_args(1)
+                .required(false)
+                .value_parser(Conflict::NAMES.to_vec())
+                .default_value("fail"),
+        )
+        .arg(
+            Arg::new("git")
+                .env("UCELOFKA_GIT")
+                .help(get_message("help-import-git", None))
+                .short('G')
+                .long("git")
+                .num_args(0)
+                .required(false),
+        )
+        .about(help)
+}
+
 fn prepare_invoice_subcommand() -> Command {
     Command::new("invoice")
         .arg(prepare_data_dir())
-        .about("Invoice management")
+        .about(get_message("about-invoice", None))
         .subcommand(
             Command::new("create")
-                .about("Creates a new invoice")
+                .about(get_message("about-invoice-create", None))
                 .arg(
                     Arg::new("customer")
-                        .help("Customer id")
+                        .help(get_message("help-customer-id", None))
                         .long("customer")
                         .short('C')
                         .num_args(1)
@@ -103,7 +197,7 @@ fn prepare_invoice_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("identity")
-                        .help("Identity id")
+                        .help(get_message("help-identity-id", None))
                         .short('I')
                         .long("identity")
                         .num_args(1)
@@ -111,7 +205,7 @@ fn prepare_invoice_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("account")
-                        .help("Account id")
+                        .help(get_message("help-account-id", None))
                         .long("account")
                         .short('A')
                         .num_args(1)
@@ -119,7 +213,7 @@ fn prepare_invoice_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("entry")
-                        .help("Entry id")
+                        .help(get_message("help-entry-id", None))
                         .short('E')
                         .long("entry")
                         .num_args(1)
@@ -129,7 +223,7 @@ fn prepare_invoice_subcommand() -> Command {
                 .arg(
                     Arg::new("git")
                         .env("UCELOFKA_GIT")
-                        .help("Add newly created invoice to git")
+                        .help(get_message("help-invoice-create-git", None))
                         .short('G')
                         .long("git")
                         .num_args(0)
@@ -137,7 +231,7 @@ fn prepare_invoice_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("due")
-                        .help("Due time (in days)")
+                        .help(get_message("help-invoice-due", None))
                         .short('D')
                         .long("due")
                         .num_args(1)
@@ -145,14 +239,14 @@ fn prepare_invoice_subcommand() -> Command {
                         .value_parser(value_parser!(usize)),
                 ),
         )
-        .subcommand(Command::new("list").about("Lists invoices"))
-        .subcommand(Command::new("ids").about("List invoice ids"))
+        .subcommand(Command::new("list").about(get_message("about-invoice-list", None)))
+        .subcommand(Command::new("ids").about(get_message("about-invoice-ids", None)))
         .subcommand(
             Command::new("render")
-                .about("Renders invoice")
+                .about(get_message("about-invoice-render", None))
                 .arg(
                     Arg::new("template")
-                        .help("Template id")
+                        .help(get_message("help-template-id", None))
                         .short('T')
                         .long("template")
                         .num_args(1)
@@ -160,34 +254,404 @@ fn prepare_invoice_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("invoice")
-                        .help("Invoice id")
+                        .help(get_message("help-invoice-id", None))
                         .short('I')
                         .long("invoice")
                         .num_args(1)
                         .required(true),
                 )
+                .arg(
+                    Arg::new("locale")
+                        .help(get_message("help-render-locale", None))
+                        .short('L')
+                        .long("locale")
+                        .num_args(1)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("facturx")
+                        .help(get_message("help-render-facturx", None))
+                        .long("facturx")
+                        .num_args(0)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("stdout")
+                        .help(get_message("help-render-stdout", None))
+                        .long("stdout")
+                        .num_args(0)
+                        .required(false)
+                        .conflicts_with_all(["output", "facturx"]),
+                )
+                .arg(
+                    Arg::new("output")
+                        .help(get_message("help-output", None))
+                        .short('O')
+                        .long("output")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(prepare_output_pattern())
+                .arg(
+                    Arg::new("git")
+                        .env("UCELOFKA_GIT")
+                        .help(get_message("help-output-git", None))
+                        .short('G')
+                        .long("git")
+                        .num_args(0)
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("build")
+                .about(get_message("about-invoice-build", None))
+                .arg(
+                    Arg::new("template")
+                        .help(get_message("help-template-id", None))
+                        .short('T')
+                        .long("template")
+                        .num_args(1)
+                        .action(ArgAction::Append)
+                        .default_value("default.html"),
+                )
+                .arg(
+                    Arg::new("invoice")
+                        .help(get_message("help-build-invoice", None))
+                        .short('I')
+                        .long("invoice")
+                        .num_args(1)
+                        .action(ArgAction::Append)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("locale")
+                        .help(get_message("help-build-locale", None))
+                        .short('L')
+                        .long("locale")
+                        .num_args(1)
+                        .required(false),
+                )
+                .arg(prepare_output_pattern())
+                .arg(
+                    Arg::new("force")
+                        .help(get_message("help-build-force", None))
+                        .short('F')
+                        .long("force")
+                        .num_args(0)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("jobs")
+                        .help(get_message("help-build-jobs", None))
+                        .short('j')
+                        .long("jobs")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(usize)),
+                )
                 .arg(
                     Arg::new("git")
                         .env("UCELOFKA_GIT")
-                        .help("Add newly created file to git")
+                        .help(get_message("help-build-git", None))
                         .short('G')
                         .long("git")
                         .num_args(0)
                         .required(false),
                 ),
         )
-        .subcommand(prepare_get_subcommand("Get invoice"))
+        .subcommand(
+            Command::new("export")
+                .about(get_message("about-invoice-export", None))
+                .arg(
+                    Arg::new("invoice")
+                        .help(get_message("help-export-invoice", None))
+                        .short('I')
+                        .long("invoice")
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("format")
+                        .help(get_message("help-export-format", None))
+                        .short('F')
+                        .long("format")
+                        .num_args(1)
+                        .required(true)
+                        .value_parser(
+                            [
+                                export::Format::NAMES,
+                                export::Accounting::NAMES,
+                                tabular::Format::NAMES,
+                            ]
+                            .concat(),
+                        ),
+                )
+                .arg(
+                    Arg::new("from")
+                        .help(get_message("help-export-from", None))
+                        .long("from")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(NaiveDate))
+                        .conflicts_with("invoice"),
+                )
+                .arg(
+                    Arg::new("to")
+                        .help(get_message("help-export-to", None))
+                        .long("to")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(NaiveDate))
+                        .conflicts_with("invoice"),
+                )
+                .arg(
+                    Arg::new("stdout")
+                        .help(get_message("help-export-stdout", None))
+                        .long("stdout")
+                        .num_args(0)
+                        .required(false)
+                        .conflicts_with("output"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .help(get_message("help-output", None))
+                        .short('O')
+                        .long("output")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("git")
+                        .env("UCELOFKA_GIT")
+                        .help(get_message("help-output-git", None))
+                        .short('G')
+                        .long("git")
+                        .num_args(0)
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("send")
+                .about(get_message("about-invoice-send", None))
+                .arg(
+                    Arg::new("invoice")
+                        .help(get_message("help-invoice-id", None))
+                        .short('I')
+                        .long("invoice")
+                        .num_args(1)
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("template")
+                        .help(get_message("help-send-template", None))
+                        .short('T')
+                        .long("template")
+                        .num_args(1)
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("email_template")
+                        .help(get_message("help-send-email-template", None))
+                        .long("email-template")
+                        .num_args(1)
+                        .required(false)
+                        .default_value(invoice::DEFAULT_EMAIL_TEMPLATE),
+                )
+                .arg(
+                    Arg::new("locale")
+                        .help(get_message("help-send-locale", None))
+                        .short('L')
+                        .long("locale")
+                        .num_args(1)
+                        .required(false),
+                )
+                .arg(prepare_output_pattern())
+                .arg(
+                    Arg::new("from")
+                        .env("UCELOFKA_MAIL_FROM")
+                        .help(get_message("help-send-from", None))
+                        .long("from")
+                        .num_args(1)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("to")
+                        .help(get_message("help-send-to", None))
+                        .long("to")
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("smtp")
+                        .env("UCELOFKA_SMTP")
+                        .help(get_message("help-send-smtp", None))
+                        .long("smtp")
+                        .num_args(1)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("smtp_security")
+                        .env("UCELOFKA_SMTP_SECURITY")
+                        .help(get_message("help-send-smtp-security", None))
+                        .long("smtp-security")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(mail::Security::NAMES.to_vec())
+                        .default_value("starttls"),
+                )
+                .arg(
+                    Arg::new("smtp_user")
+                        .env("UCELOFKA_SMTP_USER")
+                        .help(get_message("help-send-smtp-user", None))
+                        .long("smtp-user")
+                        .num_args(1)
+                        .required(false)
+                        .requires("smtp_password"),
+                )
+                .arg(
+                    Arg::new("smtp_password")
+                        .env("UCELOFKA_SMTP_PASSWORD")
+                        .help(get_message("help-send-smtp-password", None))
+                        .long("smtp-password")
+                        .num_args(1)
+                        .required(false)
+                        .requires("smtp_user")
+                        .hide_env_values(true),
+                )
+                .arg(
+                    Arg::new("sendmail")
+                        .env("UCELOFKA_SENDMAIL")
+                        .help(get_message("help-send-sendmail", None))
+                        .long("sendmail")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(PathBuf))
+                        .default_value("sendmail"),
+                )
+                .arg(
+                    Arg::new("dry_run")
+                        .help(get_message("help-send-dry-run", None))
+                        .long("dry-run")
+                        .num_args(0)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("git")
+                        .env("UCELOFKA_GIT")
+                        .help(get_message("help-send-git", None))
+                        .short('G')
+                        .long("git")
+                        .num_args(0)
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("paid")
+                .about(get_message("about-invoice-paid", None))
+                .arg(
+                    Arg::new("invoice")
+                        .help(get_message("help-invoice-id", None))
+                        .short('I')
+                        .long("invoice")
+                        .num_args(1)
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("date")
+                        .help(get_message("help-paid-date", None))
+                        .long("date")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(NaiveDate)),
+                )
+                .arg(
+                    Arg::new("git")
+                        .env("UCELOFKA_GIT")
+                        .help(get_message("help-paid-git", None))
+                        .short('G')
+                        .long("git")
+                        .num_args(0)
+                        .required(false),
+                ),
+        )
+        .subcommand(prepare_get_subcommand(get_message(
+            "about-invoice-get",
+            None,
+        )))
+        .subcommand(prepare_import_subcommand(get_message(
+            "about-invoice-import",
+            None,
+        )))
+}
+
+fn prepare_remind_subcommand() -> Command {
+    Command::new("remind")
+        .arg(prepare_data_dir())
+        .about(get_message("about-remind", None))
+        .arg(
+            Arg::new("template")
+                .help(get_message("help-remind-template", None))
+                .short('T')
+                .long("template")
+                .num_args(1)
+                .required(false)
+                .default_value(actions::reminder::DEFAULT_TEMPLATE),
+        )
+        .arg(
+            Arg::new("date")
+                .help(get_message("help-remind-date", None))
+                .long("date")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(NaiveDate)),
+        )
+        .arg(
+            Arg::new("interest")
+                .help(get_message("help-remind-interest", None))
+                .long("interest")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("interval")
+                .help(get_message("help-remind-interval", None))
+                .long("interval")
+                .num_args(1)
+                .required(false)
+                .value_parser(value_parser!(i64))
+                .default_value("14"),
+        )
+        .arg(
+            Arg::new("locale")
+                .help(get_message("help-remind-locale", None))
+                .short('L')
+                .long("locale")
+                .num_args(1)
+                .required(false),
+        )
+        .arg(
+            Arg::new("git")
+                .env("UCELOFKA_GIT")
+                .help(get_message("help-remind-git", None))
+                .short('G')
+                .long("git")
+                .num_args(0)
+                .required(false),
+        )
 }
 
 fn prepare_project_subcommand() -> Command {
     Command::new("project")
-        .about("Manages data project")
+        .about(get_message("about-project", None))
         .subcommand(
             Command::new("make")
-                .about("Creates new data dir")
+                .about(get_message("about-project-make", None))
                 .arg(
                     Arg::new("target")
-                        .help("Where is should be placed")
+                        .help(get_message("help-project-target", None))
                         .short('T')
                         .long("target")
                         .value_parser(value_parser!(PathBuf))
@@ -197,7 +661,7 @@ fn prepare_project_subcommand() -> Command {
                 .arg(
                     Arg::new("git")
                         .env("UCELOFKA_GIT")
-                        .help("Initialize with a git repository")
+                        .help(get_message("help-project-git", None))
                         .short('G')
                         .long("git")
                         .num_args(0)
@@ -209,34 +673,64 @@ fn prepare_project_subcommand() -> Command {
 fn prepare_account_subcommand() -> Command {
     Command::new("account")
         .arg(prepare_data_dir())
-        .about("Account management")
-        .subcommand(Command::new("list").about("Lists accounts"))
-        .subcommand(Command::new("ids").about("Lists accounts ids"))
-        .subcommand(prepare_get_subcommand("Get account"))
+        .about(get_message("about-account", None))
+        .subcommand(Command::new("list").about(get_message("about-account-list", None)))
+        .subcommand(Command::new("ids").about(get_message("about-account-ids", None)))
+        .subcommand(prepare_get_subcommand(get_message(
+            "about-account-get",
+            None,
+        )))
+        .subcommand(prepare_table_export_subcommand(get_message(
+            "about-account-export",
+            None,
+        )))
+        .subcommand(prepare_import_subcommand(get_message(
+            "about-account-import",
+            None,
+        )))
 }
 
 fn prepare_customer_subcommand() -> Command {
     Command::new("customer")
         .arg(prepare_data_dir())
-        .about("Customer management")
-        .subcommand(Command::new("list").about("Lists customers"))
-        .subcommand(Command::new("ids").about("Lists customers ids"))
-        .subcommand(prepare_get_subcommand("Get customer"))
+        .about(get_message("about-customer", None))
+        .subcommand(Command::new("list").about(get_message("about-customer-list", None)))
+        .subcommand(Command::new("ids").about(get_message("about-customer-ids", None)))
+        .subcommand(prepare_get_subcommand(get_message(
+            "about-customer-get",
+            None,
+        )))
+        .subcommand(prepare_table_export_subcommand(get_message(
+            "about-customer-export",
+            None,
+        )))
+        .subcommand(prepare_import_subcommand(get_message(
+            "about-customer-import",
+            None,
+        )))
 }
 
 fn prepare_entry_subcommand() -> Command {
     Command::new("entry")
         .arg(prepare_data_dir())
-        .about("Entry management")
-        .subcommand(Command::new("list").about("Lists entries"))
-        .subcommand(Command::new("ids").about("Lists entries ids"))
-        .subcommand(prepare_get_subcommand("Get entry"))
+        .about(get_message("about-entry", None))
+        .subcommand(Command::new("list").about(get_message("about-entry-list", None)))
+        .subcommand(Command::new("ids").about(get_message("about-entry-ids", None)))
+        .subcommand(prepare_get_subcommand(get_message("about-entry-get", None)))
+        .subcommand(prepare_table_export_subcommand(get_message(
+            "about-entry-export",
+            None,
+        )))
+        .subcommand(prepare_import_subcommand(get_message(
+            "about-entry-import",
+            None,
+        )))
         .subcommand(
             Command::new("create")
-                .about("Create an entry")
+                .about(get_message("about-entry-create", None))
                 .arg(
                     Arg::new("id")
-                        .help("New entry ID")
+                        .help(get_message("help-entry-create-id", None))
                         .short('I')
                         .long("id")
                         .num_args(1)
@@ -244,7 +738,7 @@ fn prepare_entry_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("name")
-                        .help("New entry name")
+                        .help(get_message("help-entry-create-name", None))
                         .short('N')
                         .long("name")
                         .num_args(1)
@@ -252,7 +746,7 @@ fn prepare_entry_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("price")
-                        .help("New entry price")
+                        .help(get_message("help-entry-create-price", None))
                         .short('P')
                         .long("price")
                         .num_args(1)
@@ -260,7 +754,7 @@ fn prepare_entry_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("currency")
-                        .help("New entry currency")
+                        .help(get_message("help-entry-create-currency", None))
                         .short('C')
                         .long("currency")
                         .num_args(1)
@@ -268,16 +762,24 @@ fn prepare_entry_subcommand() -> Command {
                 )
                 .arg(
                     Arg::new("details")
-                        .help("New entry detail")
+                        .help(get_message("help-entry-create-details", None))
                         .short('D')
                         .long("detail")
                         .num_args(1..)
                         .required(false),
                 )
+                .arg(
+                    Arg::new("vat")
+                        .help(get_message("help-entry-create-vat", None))
+                        .long("vat")
+                        .value_parser(value_parser!(f32))
+                        .num_args(1)
+                        .required(false),
+                )
                 .arg(
                     Arg::new("git")
                         .env("UCELOFKA_GIT")
-                        .help("Add newly created entry to git")
+                        .help(get_message("help-entry-create-git", None))
                         .short('G')
                         .long("git")
                         .num_args(0)
@@ -289,10 +791,203 @@ fn prepare_entry_subcommand() -> Command {
 fn prepare_identity_subcommand() -> Command {
     Command::new("identity")
         .arg(prepare_data_dir())
-        .about("Identity management")
-        .subcommand(Command::new("list").about("Lists identities"))
-        .subcommand(Command::new("ids").about("Lists identities ids"))
-        .subcommand(prepare_get_subcommand("Get identity"))
+        .about(get_message("about-identity", None))
+        .subcommand(Command::new("list").about(get_message("about-identity-list", None)))
+        .subcommand(Command::new("ids").about(get_message("about-identity-ids", None)))
+        .subcommand(prepare_get_subcommand(get_message(
+            "about-identity-get",
+            None,
+        )))
+        .subcommand(prepare_table_export_subcommand(get_message(
+            "about-identity-export",
+            None,
+        )))
+        .subcommand(prepare_import_subcommand(get_message(
+            "about-identity-import",
+            None,
+        )))
+}
+
+fn prepare_payment_subcommand() -> Command {
+    Command::new("payment")
+        .visible_alias("payments")
+        .arg(prepare_data_dir())
+        .about(get_message("about-payment", None))
+        .subcommand(Command::new("list").about(get_message("about-payment-list", None)))
+        .subcommand(Command::new("ids").about(get_message("about-payment-ids", None)))
+        .subcommand(prepare_get_subcommand(get_message(
+            "about-payment-get",
+            None,
+        )))
+        .subcommand(
+            Command::new("import")
+                .about(get_message("about-payment-import", None))
+                .arg(
+                    Arg::new("input")
+                        .help(get_message("help-payment-import-input", None))
+                        .short('i')
+                        .long("input")
+                        .num_args(1)
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("format")
+                        .help(get_message("help-payment-import-format", None))
+                        .short('F')
+                        .long("format")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(statement::Format::NAMES.to_vec()),
+                )
+                .arg(
+                    Arg::new("encoding")
+                        .help(get_message("help-payment-import-encoding", None))
+                        .long("encoding")
+                        .num_args(1)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("dry_run")
+                        .help(get_message("help-payment-import-dry-run", None))
+                        .long("dry-run")
+                        .num_args(0)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("git")
+                        .env("UCELOFKA_GIT")
+                        .help(get_message("help-payment-import-git", None))
+                        .short('G')
+                        .long("git")
+                        .num_args(0)
+                        .required(false),
+                ),
+        )
+}
+
+fn prepare_report_format() -> Arg {
+    Arg::new("format")
+        .help(get_message("help-report-format", None))
+        .short('F')
+        .long("format")
+        .num_args(1)
+        .required(false)
+        .value_parser(report::Format::NAMES.to_vec())
+        .default_value("table")
+}
+
+fn prepare_report_subcommand() -> Command {
+    Command::new("report")
+        .arg(prepare_data_dir())
+        .about(get_message("about-report", None))
+        .subcommand(
+            Command::new("revenue")
+                .about(get_message("about-report-revenue", None))
+                .arg(
+                    Arg::new("period")
+                        .help(get_message("help-report-period", None))
+                        .long("period")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(actions::report::Period::NAMES.to_vec())
+                        .default_value("month"),
+                )
+                .arg(
+                    Arg::new("by_customer")
+                        .help(get_message("help-report-by-customer", None))
+                        .long("by-customer")
+                        .num_args(0)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("from")
+                        .help(get_message("help-report-from", None))
+                        .long("from")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(NaiveDate)),
+                )
+                .arg(
+                    Arg::new("to")
+                        .help(get_message("help-report-to", None))
+                        .long("to")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(NaiveDate)),
+                )
+                .arg(prepare_report_format()),
+        )
+        .subcommand(
+            Command::new("aging")
+                .about(get_message("about-report-aging", None))
+                .arg(
+                    Arg::new("date")
+                        .help(get_message("help-report-date", None))
+                        .long("date")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(NaiveDate)),
+                )
+                .arg(prepare_report_format()),
+        )
+}
+
+fn prepare_tax_period() -> Arg {
+    Arg::new("period")
+        .help(get_message("help-tax-period", None))
+        .long("period")
+        .num_args(1)
+        .required(true)
+        .value_parser(|value: &str| value.parse::<tax::Period>().map_err(|err| err.to_string()))
+}
+
+fn prepare_tax_subcommand() -> Command {
+    Command::new("tax")
+        .arg(prepare_data_dir())
+        .about(get_message("about-tax", None))
+        .subcommand(
+            Command::new("report")
+                .about(get_message("about-tax-report", None))
+                .arg(prepare_tax_period())
+                .arg(prepare_report_format()),
+        )
+        .subcommand(
+            Command::new("control-statement")
+                .about(get_message("about-tax-control-statement", None))
+                .arg(prepare_tax_period())
+                .arg(
+                    Arg::new("legal_person")
+                        .help(get_message("help-tax-legal-person", None))
+                        .long("legal-person")
+                        .num_args(0)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("tax_office")
+                        .help(get_message("help-tax-office", None))
+                        .long("tax-office")
+                        .num_args(1)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("date")
+                        .help(get_message("help-tax-date", None))
+                        .long("date")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(NaiveDate)),
+                )
+                .arg(
+                    Arg::new("output")
+                        .help(get_message("help-output-or-stdout", None))
+                        .short('O')
+                        .long("output")
+                        .num_args(1)
+                        .required(false)
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
 }
 
 fn prepare_web() -> Command {
@@ -301,24 +996,24 @@ fn prepare_web() -> Command {
         .arg(
             Arg::new("port")
                 .env("UCELOFKA_PORT")
-                .help("Port which will be used for the web server")
+                .help(get_message("help-web-port", None))
                 .long("port")
                 .num_args(1)
                 .value_parser(value_parser!(u16))
                 .required(false)
                 .default_value("8080"),
         )
-        .about("start webserver frontend for ucelofka")
+        .about(get_message("about-web", None))
 }
 
 fn prepare_completions() -> Command {
     Command::new("completions")
-        .about("completions generator")
+        .about(get_message("about-completions", None))
         .arg(
             Arg::new("shell")
                 .short('s')
                 .long("shell")
-                .help("For which shell the completion is supposed to be generated")
+                .help(get_message("help-completions-shell", None))
                 .value_parser(["bash", "fish", "elvish", "powershell", "zsh"])
                 .required(true),
         )
@@ -327,34 +1022,58 @@ fn prepare_completions() -> Command {
 fn prepare_template_subcommand() -> Command {
     Command::new("template")
         .arg(prepare_data_dir())
-        .about("Template management")
-        .subcommand(Command::new("list").about("Lists templates"))
-        .subcommand(prepare_get_subcommand("Get template"))
+        .about(get_message("about-template", None))
+        .subcommand(Command::new("list").about(get_message("about-template-list", None)))
+        .subcommand(prepare_get_subcommand(get_message(
+            "about-template-get",
+            None,
+        )))
 }
 
 fn prepare_ids_subcommand() -> Command {
     Command::new("ids")
         .arg(prepare_data_dir())
-        .about("Print ids of all entities")
+        .about(get_message("about-ids", None))
 }
 
 fn prepare_tui_subcommand() -> Command {
     Command::new("tui")
         .arg(prepare_data_dir())
-        .about("Start ucelofka's tui")
+        .arg(
+            Arg::new("git")
+                .env("UCELOFKA_GIT")
+                .help(get_message("help-tui-git", None))
+                .short('G')
+                .long("git")
+                .num_args(0)
+                .required(false),
+        )
+        .about(get_message("about-tui", None))
 }
 
 fn prepare_cmd() -> Command {
     Command::new(crate_name!())
         .author(crate_authors!())
         .version(crate_version!())
-        .about(crate_description!())
+        .about(get_message("about-ucelofka", None))
+        .arg(
+            Arg::new("lang")
+                .long("lang")
+                .value_name("LANG")
+                .num_args(1)
+                .global(true)
+                .help(get_message("help-lang", None)),
+        )
         .subcommand(prepare_invoice_subcommand())
         .subcommand(prepare_project_subcommand())
         .subcommand(prepare_account_subcommand())
         .subcommand(prepare_customer_subcommand())
         .subcommand(prepare_entry_subcommand())
         .subcommand(prepare_identity_subcommand())
+        .subcommand(prepare_payment_subcommand())
+        .subcommand(prepare_remind_subcommand())
+        .subcommand(prepare_report_subcommand())
+        .subcommand(prepare_tax_subcommand())
         .subcommand(prepare_template_subcommand())
         .subcommand(prepare_web())
         .subcommand(prepare_completions())
@@ -373,6 +1092,85 @@ fn exit_on_parse_error(mut cmd: Command) {
     std::process::exit(1);
 }
 
+/// Writes the exported records to the output file or to stdout
+fn write_table_export(matches: &ArgMatches, exported: String) -> Result<()> {
+    match matches.get_one::<PathBuf>("output") {
+        Some(path) => fs::write(path, exported).map_err(|err| {
+            anyhow!(get_message(
+                "file-write-failed",
+                Some(fluent_args!["path" => path.to_string_lossy(), "error" => err.to_string()])
+            ))
+        }),
+        None => {
+            print!("{}", exported);
+            Ok(())
+        }
+    }
+}
+
+/// Reads the imported file (`-` stands for stdin)
+///
+/// Files which are not in UTF-8 are decoded as windows-1250 unless the encoding is set
+/// (statements of the czech banks usually use it).
+fn read_input(input: &Path, encoding: Option<&'static Encoding>) -> Result<String> {
+    let data = if input.as_os_str() == "-" {
+        let mut data = vec![];
+        io::stdin().read_to_end(&mut data)?;
+        data
+    } else {
+        fs::read(input).map_err(|err| {
+            anyhow!(get_message(
+                "file-read-failed",
+                Some(fluent_args!["path" => input.to_string_lossy(), "error" => err.to_string()])
+            ))
+        })?
+    };
+    let encoding = encoding.unwrap_or_else(|| {
+        if std::str::from_utf8(&data).is_ok() {
+            UTF_8
+        } else {
+            WINDOWS_1250
+        }
+    });
+    let (text, _, _) = encoding.decode(&data);
+    Ok(text.into_owned())
+}
+
+/// Encoding set by its label (e.g. `windows-1250`, `iso-8859-2`)
+fn parse_encoding(label: &str) -> Result<&'static Encoding> {
+    Encoding::for_label(label.as_bytes()).ok_or_else(|| {
+        anyhow!(get_message(
+            "unknown-encoding",
+            Some(fluent_args!["encoding" => label])
+        ))
+    })
+}
+
+type ImportFn = fn(&Path, &str, tabular::Format, Conflict, bool) -> Result<ImportSummary>;
+
+fn process_import(data_path: &Path, matches: &ArgMatches, import: ImportFn) -> Result<()> {
+    let input = matches.get_one::<PathBuf>("input").unwrap();
+    let format: tabular::Format = match matches.get_one::<String>("format") {
+        Some(format) => format.parse()?,
+        None => tabular::Format::from_path(input).ok_or_else(|| {
+            anyhow!(get_message(
+                "format-not-detected",
+                Some(fluent_args!["path" => input.to_string_lossy()])
+            ))
+        })?,
+    };
+    let data = read_input(input, None)?;
+    let summary = import(
+        data_path,
+        &data,
+        format,
+        matches.get_one::<String>("conflict").unwrap().parse()?,
+        matches.get_flag("git"),
+    )?;
+    println!("{}", summary);
+    Ok(())
+}
+
 fn process_invoice(cmd: Command, matches: &ArgMatches) -> Result<()> {
     let data_path = get_data_dir(matches)?;
     match matches.subcommand() {
@@ -393,17 +1191,41 @@ fn process_invoice(cmd: Command, matches: &ArgMatches) -> Result<()> {
                 create_matches.get_flag("git"),
                 due,
             )?;
-            println!("Created invoice {}", new_id);
+            println!(
+                "{}",
+                get_message("invoice-created", Some(fluent_args!["invoice" => new_id]))
+            );
         }
         Some(("render", render_matches)) => {
             let invoice_id = render_matches
                 .get_one::<String>("invoice")
                 .unwrap()
                 .to_string();
-            let filename = invoice::render(
+            let template = render_matches.get_one::<String>("template").unwrap();
+            let locale = render_matches
+                .get_one::<String>("locale")
+                .map(String::as_str);
+            if render_matches.get_flag("stdout") {
+                print!(
+                    "{}",
+                    invoice::render_string(data_path.as_ref(), &invoice_id, template, locale)?
+                );
+                return Ok(());
+            }
+            let render = if render_matches.get_flag("facturx") {
+                invoice::render_facturx
+            } else {
+                invoice::render
+            };
+            let filename = render(
                 data_path.as_ref(),
                 &invoice_id,
-                render_matches.get_one::<String>("template").unwrap(),
+                template,
+                locale,
+                render_matches
+                    .get_one::<PathBuf>("output")
+                    .map(PathBuf::as_path),
+                render_matches.get_one::<String>("pattern").unwrap(),
                 render_matches.get_flag("git"),
             )?;
             println!(
@@ -414,6 +1236,172 @@ fn process_invoice(cmd: Command, matches: &ArgMatches) -> Result<()> {
                 )
             );
         }
+        Some(("send", send_matches)) => {
+            let invoice_id = send_matches.get_one::<String>("invoice").unwrap();
+            let transport = if send_matches.get_flag("dry_run") {
+                None
+            } else if let Some(server) = send_matches.get_one::<String>("smtp") {
+                let credentials = send_matches
+                    .get_one::<String>("smtp_user")
+                    .zip(send_matches.get_one::<String>("smtp_password"))
+                    .map(|(user, password)| (user.to_string(), password.to_string()));
+                let security = send_matches
+                    .get_one::<String>("smtp_security")
+                    .unwrap()
+                    .parse()?;
+                Some(Transport::smtp(server, security, credentials)?)
+            } else {
+                Some(Transport::Sendmail(
+                    send_matches.get_one::<PathBuf>("sendmail").unwrap().clone(),
+                ))
+            };
+            let to: Vec<String> = send_matches
+                .get_many::<String>("to")
+                .unwrap_or_default()
+                .map(String::from)
+                .collect();
+            let sent = invoice::send(
+                &data_path,
+                invoice_id,
+                send_matches.get_one::<String>("template").unwrap(),
+                send_matches.get_one::<String>("email_template").unwrap(),
+                send_matches.get_one::<String>("locale").map(String::as_str),
+                send_matches.get_one::<String>("pattern").unwrap(),
+                send_matches.get_one::<String>("from").map(String::as_str),
+                &to,
+                transport.as_ref(),
+                send_matches.get_flag("git"),
+            )?;
+            match sent {
+                invoice::Sent::Delivered(recipients) => println!(
+                    "{}",
+                    get_message(
+                        "invoice-sent",
+                        Some(fluent_args![
+                            "invoice" => invoice_id.as_str(),
+                            "recipients" => recipients.join(", ")
+                        ])
+                    )
+                ),
+                invoice::Sent::Written(path) => println!(
+                    "{}",
+                    get_message(
+                        "invoice-eml-written",
+                        Some(fluent_args![
+                            "invoice" => invoice_id.as_str(),
+                            "filename" => path.to_string_lossy().to_string()
+                        ])
+                    )
+                ),
+            }
+        }
+        Some(("paid", paid_matches)) => {
+            invoice::mark_paid(
+                &data_path,
+                paid_matches.get_one::<String>("invoice").unwrap(),
+                paid_matches
+                    .get_one::<NaiveDate>("date")
+                    .copied()
+                    .unwrap_or_else(|| chrono::Local::now().date_naive()),
+                paid_matches.get_flag("git"),
+            )?;
+        }
+        Some(("export", export_matches)) => {
+            let format = export_matches.get_one::<String>("format").unwrap();
+            let invoices: Vec<String> = export_matches
+                .get_many::<String>("invoice")
+                .unwrap_or_default()
+                .map(String::from)
+                .collect();
+            let from = export_matches.get_one::<NaiveDate>("from").copied();
+            let to = export_matches.get_one::<NaiveDate>("to").copied();
+            // multiple invoices are printed to stdout unless output is set
+            if let Ok(format) = format.parse::<tabular::Format>() {
+                return write_table_export(
+                    export_matches,
+                    invoice::export_table(&data_path, &invoices, from, to, format)?,
+                );
+            }
+            if let Ok(format) = format.parse::<export::Accounting>() {
+                return write_table_export(
+                    export_matches,
+                    invoice::export_accounting(&data_path, &invoices, from, to, format)?,
+                );
+            }
+            let format: export::Format = format.parse()?;
+            let invoice_id = match invoices.as_slice() {
+                [invoice_id] => invoice_id,
+                _ => {
+                    return Err(anyhow!(get_message(
+                        "export-single-invoice",
+                        Some(fluent_args!["format" => format.to_string()])
+                    )))
+                }
+            };
+            if export_matches.get_flag("stdout") {
+                print!(
+                    "{}",
+                    invoice::export_string(&data_path, invoice_id, format)?
+                );
+                return Ok(());
+            }
+            let filename = invoice::export(
+                &data_path,
+                invoice_id,
+                format,
+                export_matches
+                    .get_one::<PathBuf>("output")
+                    .map(PathBuf::as_path),
+                export_matches.get_flag("git"),
+            )?;
+            println!(
+                "{}",
+                get_message(
+                    "invoice-exported",
+                    Some(fluent_args![
+                        "filename" => filename,
+                        "invoice" => invoice_id.as_str(),
+                        "format" => format.to_string()
+                    ])
+                )
+            );
+        }
+        Some(("build", build_matches)) => {
+            let templates: Vec<String> = build_matches
+                .get_many::<String>("template")
+                .unwrap()
+                .map(String::from)
+                .collect();
+            let invoices: Vec<String> = build_matches
+                .get_many::<String>("invoice")
+                .unwrap_or_default()
+                .map(String::from)
+                .collect();
+            let jobs = build_matches
+                .get_one::<usize>("jobs")
+                .copied()
+                .unwrap_or_else(|| {
+                    std::thread::available_parallelism()
+                        .map(usize::from)
+                        .unwrap_or(1)
+                });
+            let summary = invoice::build(
+                &data_path,
+                &invoices,
+                &templates,
+                build_matches
+                    .get_one::<String>("locale")
+                    .map(String::as_str),
+                build_matches.get_one::<String>("pattern").unwrap(),
+                build_matches.get_flag("force"),
+                jobs,
+                build_matches.get_flag("git"),
+            )?;
+            if !summary.failed.is_empty() {
+                return Err(anyhow!("{}", summary));
+            }
+            println!("{}", summary);
+        }
         Some(("list", _)) => {
             println!("{}", invoice::list(&data_path)?);
         }
@@ -425,6 +1413,9 @@ fn process_invoice(cmd: Command, matches: &ArgMatches) -> Result<()> {
             let invoice = invoice::get(&data_path, invoice_id)?;
             println!("{}", invoice);
         }
+        Some(("import", import_matches)) => {
+            process_import(&data_path, import_matches, invoice::import)?;
+        }
         _ => exit_on_parse_error(cmd),
     }
     Ok(())
@@ -457,6 +1448,16 @@ fn process_accounts(cmd: Command, matches: &ArgMatches) -> Result<()> {
             let account = account::get(&data_path, account_id)?;
             println!("{}", account);
         }
+        Some(("export", export_matches)) => {
+            let format = export_matches
+                .get_one::<String>("format")
+                .unwrap()
+                .parse()?;
+            write_table_export(export_matches, account::export(&data_path, format)?)?;
+        }
+        Some(("import", import_matches)) => {
+            process_import(&data_path, import_matches, account::import)?;
+        }
         _ => exit_on_parse_error(cmd),
     }
     Ok(())
@@ -476,6 +1477,16 @@ fn process_customer(cmd: Command, matches: &ArgMatches) -> Result<()> {
             let customer = customer::get(&data_path, customer_id)?;
             println!("{}", customer);
         }
+        Some(("export", export_matches)) => {
+            let format = export_matches
+                .get_one::<String>("format")
+                .unwrap()
+                .parse()?;
+            write_table_export(export_matches, customer::export(&data_path, format)?)?;
+        }
+        Some(("import", import_matches)) => {
+            process_import(&data_path, import_matches, customer::import)?;
+        }
         _ => exit_on_parse_error(cmd),
     }
     Ok(())
@@ -495,6 +1506,16 @@ fn process_entry(cmd: Command, matches: &ArgMatches) -> Result<()> {
             let entry = entry::get(&data_path, entry_id)?;
             println!("{}", entry);
         }
+        Some(("export", export_matches)) => {
+            let format = export_matches
+                .get_one::<String>("format")
+                .unwrap()
+                .parse()?;
+            write_table_export(export_matches, entry::export(&data_path, format)?)?;
+        }
+        Some(("import", import_matches)) => {
+            process_import(&data_path, import_matches, entry::import)?;
+        }
         Some(("create", create_matches)) => {
             let id: String = create_matches.get_one::<String>("id").unwrap().to_string();
             let name: String = create_matches
@@ -516,7 +1537,10 @@ fn process_entry(cmd: Command, matches: &ArgMatches) -> Result<()> {
                 .unwrap_or_default()
                 .map(String::from)
                 .collect();
-            entry::create(&data_path, id, name, price, currency, details, git)?;
+            let vat_rate = create_matches.get_one::<f32>("vat").copied();
+            entry::create(
+                &data_path, id, name, price, currency, details, vat_rate, git,
+            )?;
         }
         _ => exit_on_parse_error(cmd),
     }
@@ -537,6 +1561,138 @@ fn process_identity(cmd: Command, matches: &ArgMatches) -> Result<()> {
             let identity = identity::get(&data_path, identity_id)?;
             println!("{}", identity);
         }
+        Some(("export", export_matches)) => {
+            let format = export_matches
+                .get_one::<String>("format")
+                .unwrap()
+                .parse()?;
+            write_table_export(export_matches, identity::export(&data_path, format)?)?;
+        }
+        Some(("import", import_matches)) => {
+            process_import(&data_path, import_matches, identity::import)?;
+        }
+        _ => exit_on_parse_error(cmd),
+    }
+    Ok(())
+}
+
+fn process_payment(cmd: Command, matches: &ArgMatches) -> Result<()> {
+    let data_path = get_data_dir(matches)?;
+    match matches.subcommand() {
+        Some(("list", _)) => {
+            println!("{}", payment::list(&data_path)?);
+        }
+        Some(("ids", _)) => {
+            println!("{}", payment::ids(&data_path)?);
+        }
+        Some(("get", get_matches)) => {
+            let payment_id = get_matches.get_one::<String>("id").unwrap();
+            let payment = payment::get(&data_path, payment_id)?;
+            println!("{}", payment);
+        }
+        Some(("import", import_matches)) => {
+            let input = import_matches.get_one::<PathBuf>("input").unwrap();
+            let encoding = import_matches
+                .get_one::<String>("encoding")
+                .map(|label| parse_encoding(label))
+                .transpose()?;
+            let data = read_input(input, encoding)?;
+            let format = import_matches
+                .get_one::<String>("format")
+                .map(|format| format.parse())
+                .transpose()?;
+            let reconciliation = payment::import(
+                &data_path,
+                &data,
+                format,
+                import_matches.get_flag("dry_run"),
+                import_matches.get_flag("git"),
+            )?;
+            println!("{}", reconciliation);
+        }
+        _ => exit_on_parse_error(cmd),
+    }
+    Ok(())
+}
+
+fn process_remind(_cmd: Command, matches: &ArgMatches) -> Result<()> {
+    let data_path = get_data_dir(matches)?;
+    let reminders = actions::reminder::remind(
+        &data_path,
+        matches
+            .get_one::<NaiveDate>("date")
+            .copied()
+            .unwrap_or_else(|| chrono::Local::now().date_naive()),
+        matches.get_one::<String>("template").unwrap(),
+        matches.get_one::<f64>("interest").copied(),
+        *matches.get_one::<i64>("interval").unwrap(),
+        matches.get_one::<String>("locale").map(String::as_str),
+        matches.get_flag("git"),
+    )?;
+    print!("{}", reminders);
+    Ok(())
+}
+
+fn process_report(cmd: Command, matches: &ArgMatches) -> Result<()> {
+    let data_path = get_data_dir(matches)?;
+    let (table, format) = match matches.subcommand() {
+        Some(("revenue", revenue_matches)) => (
+            actions::report::revenue(
+                &data_path,
+                revenue_matches
+                    .get_one::<String>("period")
+                    .unwrap()
+                    .parse()?,
+                revenue_matches.get_flag("by_customer"),
+                revenue_matches.get_one::<NaiveDate>("from").copied(),
+                revenue_matches.get_one::<NaiveDate>("to").copied(),
+            )?,
+            revenue_matches.get_one::<String>("format").unwrap(),
+        ),
+        Some(("aging", aging_matches)) => (
+            actions::report::aging(
+                &data_path,
+                aging_matches
+                    .get_one::<NaiveDate>("date")
+                    .copied()
+                    .unwrap_or_else(|| chrono::Local::now().date_naive()),
+            )?,
+            aging_matches.get_one::<String>("format").unwrap(),
+        ),
+        _ => {
+            exit_on_parse_error(cmd);
+            return Ok(());
+        }
+    };
+    print!("{}", table.render(format.parse()?)?);
+    Ok(())
+}
+
+fn process_tax(cmd: Command, matches: &ArgMatches) -> Result<()> {
+    let data_path = get_data_dir(matches)?;
+    match matches.subcommand() {
+        Some(("report", report_matches)) => {
+            let table =
+                actions::tax::summary(&data_path, *report_matches.get_one("period").unwrap())?;
+            let format = report_matches.get_one::<String>("format").unwrap();
+            print!("{}", table.render(format.parse()?)?);
+        }
+        Some(("control-statement", statement_matches)) => {
+            let taxpayer = tax::control_statement::Taxpayer {
+                legal_person: statement_matches.get_flag("legal_person"),
+                tax_office: statement_matches.get_one::<String>("tax_office").cloned(),
+            };
+            let xml = actions::tax::control_statement(
+                &data_path,
+                *statement_matches.get_one("period").unwrap(),
+                &taxpayer,
+                statement_matches
+                    .get_one::<NaiveDate>("date")
+                    .copied()
+                    .unwrap_or_else(|| chrono::Local::now().date_naive()),
+            )?;
+            write_table_export(statement_matches, xml)?;
+        }
         _ => exit_on_parse_error(cmd),
     }
     Ok(())
@@ -609,14 +1765,54 @@ fn process_ids(_cmd: Command, matches: &ArgMatches) -> Result<()> {
 
 fn process_tui(_cmd: Command, matches: &ArgMatches) -> Result<()> {
     let path = get_data_dir(matches)?;
-    dioxus_tui::launch_cfg_with_props(tui::App, tui::AppProps { path }, dioxus_tui::Config::new());
+    let git = matches.get_flag("git");
+    let keys = config::Config::load(&path)?.tui.keys;
+    tui::restore_terminal_on_panic();
+    dioxus_tui::launch_cfg_with_props(
+        tui::App,
+        tui::AppProps { path, git, keys },
+        dioxus_tui::Config::new(),
+    );
     Ok(())
 }
 
+/// Language and data directory found before the arguments are parsed
+///
+/// The language has to be selected before the help is built so the arguments
+/// are parsed leniently first (the help and errors are shown by the second pass).
+fn pre_parse(args: &[OsString]) -> (Option<String>, PathBuf) {
+    let matches = prepare_cmd()
+        .ignore_errors(true)
+        .disable_help_flag(true)
+        .disable_help_subcommand(true)
+        .disable_version_flag(true)
+        .try_get_matches_from(args)
+        .ok();
+    let mut data_dir = None;
+    let mut current = matches.as_ref();
+    while let Some(matches) = current {
+        if let Ok(Some(path)) = matches.try_get_one::<PathBuf>("data_dir") {
+            data_dir = Some(path.clone());
+        }
+        current = matches.subcommand().map(|(_, matches)| matches);
+    }
+    let lang = matches
+        .as_ref()
+        .and_then(|matches| matches.try_get_one::<String>("lang").ok().flatten())
+        .cloned();
+    (lang, data_dir.unwrap_or_else(|| ".".into()))
+}
+
 fn main() -> Result<()> {
+    let args: Vec<OsString> = env::args_os().collect();
+    let (lang, data_path) = pre_parse(&args);
+    // invalid config is reported by the commands which use it
+    let config = config::Config::load(&data_path).unwrap_or_default();
+    translations::init(lang.or(config.lang).as_deref(), Some(&data_path))?;
+
     let cmd = prepare_cmd();
 
-    let matches = cmd.clone().get_matches();
+    let matches = cmd.clone().get_matches_from(args);
 
     match matches.subcommand() {
         Some(("invoice", invoice_matches)) => process_invoice(cmd.clone(), invoice_matches)?,
@@ -625,6 +1821,10 @@ fn main() -> Result<()> {
         Some(("customer", customer_matches)) => process_customer(cmd.clone(), customer_matches)?,
         Some(("entry", entry_matches)) => process_entry(cmd.clone(), entry_matches)?,
         Some(("identity", identity_matches)) => process_identity(cmd.clone(), identity_matches)?,
+        Some(("payment", payment_matches)) => process_payment(cmd.clone(), payment_matches)?,
+        Some(("remind", remind_matches)) => process_remind(cmd.clone(), remind_matches)?,
+        Some(("report", report_matches)) => process_report(cmd.clone(), report_matches)?,
+        Some(("tax", tax_matches)) => process_tax(cmd.clone(), tax_matches)?,
         Some(("template", identity_matches)) => process_template(cmd.clone(), identity_matches)?,
         Some(("web", web_matches)) => process_web(cmd.clone(), web_matches)?,
         Some(("completions", completions_matches)) => {
@@ -0,0 +1,184 @@
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use serde_json::{Map, Value};
+use std::{fmt, str::FromStr};
+
+use crate::translations::get_message;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl Format {
+    pub const NAMES: &'static [&'static str] = &["table", "csv", "json"];
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "table" => Ok(Self::Table),
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(anyhow!(get_message(
+                "unknown-report-format",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Table => write!(f, "table"),
+            Self::Csv => write!(f, "csv"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Text(String),
+    Count(usize),
+    Amount(f64),
+}
+
+impl Cell {
+    fn is_number(&self) -> bool {
+        !matches!(self, Self::Text(_))
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            Self::Text(text) => Value::from(text.as_str()),
+            Self::Count(count) => Value::from(*count),
+            // keep the amounts rounded to cents
+            Self::Amount(amount) => Value::from((amount * 100.0).round() / 100.0),
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Count(count) => write!(f, "{}", count),
+            Self::Amount(amount) => write!(f, "{:.2}", amount),
+        }
+    }
+}
+
+/// Report data which can be printed in several formats
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub header: Vec<&'static str>,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    pub fn new(header: Vec<&'static str>) -> Self {
+        Self {
+            header,
+            rows: vec![],
+        }
+    }
+
+    pub fn render(&self, format: Format) -> Result<String> {
+        match format {
+            Format::Table => Ok(self.to_string()),
+            Format::Csv => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+                writer.write_record(&self.header)?;
+                for row in &self.rows {
+                    writer.write_record(row.iter().map(Cell::to_string))?;
+                }
+                Ok(String::from_utf8(writer.into_inner()?)?)
+            }
+            Format::Json => {
+                let rows: Vec<Value> = self
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        Value::Object(
+                            self.header
+                                .iter()
+                                .zip(row)
+                                .map(|(name, cell)| (name.to_string(), cell.to_json()))
+                                .collect::<Map<_, _>>(),
+                        )
+                    })
+                    .collect();
+                Ok(serde_json::to_string_pretty(&rows)? + "\n")
+            }
+        }
+    }
+}
+
+/// Columns are aligned, numbers to the right
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(Cell::to_string).collect())
+            .collect();
+        let widths: Vec<usize> = self
+            .header
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                rows.iter()
+                    .filter_map(|row| row.get(idx))
+                    .map(|cell| cell.chars().count())
+                    .chain([name.chars().count()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+        let numeric: Vec<bool> = (0..self.header.len())
+            .map(|idx| {
+                self.rows
+                    .iter()
+                    .any(|row| row.get(idx).is_some_and(Cell::is_number))
+            })
+            .collect();
+
+        let line = |cells: Vec<&str>| -> String {
+            cells
+                .iter()
+                .enumerate()
+                .map(|(idx, cell)| {
+                    if numeric[idx] {
+                        format!("{:>width$}", cell, width = widths[idx])
+                    } else {
+                        format!("{:<width$}", cell, width = widths[idx])
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        writeln!(f, "{}", line(self.header.clone()))?;
+        writeln!(
+            f,
+            "{}",
+            widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>()
+                .join("  ")
+        )?;
+        for row in &rows {
+            writeln!(f, "{}", line(row.iter().map(String::as_str).collect()))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+pub mod camt;
+pub mod gpc;
+pub mod mt940;
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use fluent::fluent_args;
+
+use crate::translations::get_message;
+use std::{fmt, str::FromStr};
+
+/// Bank statement formats which can be imported
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Camt,
+    Gpc,
+    Mt940,
+}
+
+impl Format {
+    pub const NAMES: &'static [&'static str] = &["camt", "gpc", "mt940"];
+
+    /// Detects the format from the content of the statement
+    pub fn detect(data: &str) -> Option<Self> {
+        let data = data.trim_start_matches('\u{feff}').trim_start();
+        if data.starts_with('<') {
+            Some(Self::Camt)
+        } else if data.starts_with("074") {
+            Some(Self::Gpc)
+        } else if data.contains(":20:") && data.contains(":61:") {
+            Some(Self::Mt940)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "camt" => Ok(Self::Camt),
+            "gpc" => Ok(Self::Gpc),
+            "mt940" => Ok(Self::Mt940),
+            _ => Err(anyhow!(get_message(
+                "unknown-statement-format",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Camt => write!(f, "camt"),
+            Self::Gpc => write!(f, "gpc"),
+            Self::Mt940 => write!(f, "mt940"),
+        }
+    }
+}
+
+/// Single transaction from the bank statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transaction {
+    /// Reference of the transaction assigned by the bank
+    pub reference: String,
+    pub date: NaiveDate,
+    pub amount: f32,
+    /// Incoming payment
+    pub credit: bool,
+    pub currency: String,
+    pub variable_symbol: Option<String>,
+    /// Account or name of the other party
+    pub counterparty: String,
+}
+
+pub fn parse(data: &str, format: Format) -> Result<Vec<Transaction>> {
+    let data = data.trim_start_matches('\u{feff}');
+    match format {
+        Format::Camt => camt::parse(data),
+        Format::Gpc => gpc::parse(data),
+        Format::Mt940 => mt940::parse(data),
+    }
+}
+
+/// Variable symbols are compared without leading zeros
+pub fn normalize_variable_symbol(value: &str) -> Option<String> {
+    let value = value.trim().trim_start_matches('0');
+    if value.is_empty() || value.len() > 10 || !value.chars().all(|c| c.is_ascii_digit()) {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+/// Finds the variable symbol within a payment note
+///
+/// Either the note is just a number or it contains `VS` followed by the number
+/// (e.g. `VS:202400001`, `/VS/202400001` or `VS 202400001`).
+pub(crate) fn variable_symbol(text: &str) -> Option<String> {
+    if let Some(symbol) = normalize_variable_symbol(text) {
+        return Some(symbol);
+    }
+    let upper = text.to_uppercase();
+    upper.match_indices("VS").find_map(|(idx, _)| {
+        let rest = upper[idx + 2..].trim_start_matches([':', '/', ' ', '=', '.']);
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        normalize_variable_symbol(&digits)
+    })
+}
+
+pub(crate) fn parse_amount(value: &str) -> Result<f32> {
+    value
+        .trim()
+        .replace(',', ".")
+        .parse::<f32>()
+        .map_err(|err| {
+            anyhow!(get_message(
+                "invalid-amount",
+                Some(fluent_args!["amount" => value, "error" => err.to_string()])
+            ))
+        })
+}
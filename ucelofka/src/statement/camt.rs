@@ -0,0 +1,161 @@
+//! ISO 20022 bank to customer statement (CAMT.053)
+//!
+//! The variable symbol is searched in the structured creditor reference,
+//! end to end id and the unstructured remittance information (in this order).
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use fluent::fluent_args;
+use quick_xml::{events::Event, Reader};
+
+use super::{parse_amount, variable_symbol, Transaction};
+
+use crate::translations::get_message;
+
+/// Data collected within a single `Ntry` element
+#[derive(Debug, Default)]
+struct Entry {
+    amount: Option<String>,
+    currency: String,
+    indicator: String,
+    booking_date: Option<String>,
+    value_date: Option<String>,
+    reference: Option<String>,
+    entry_reference: Option<String>,
+    creditor_reference: Option<String>,
+    end_to_end_id: Option<String>,
+    remittance: Vec<String>,
+    name: Option<String>,
+    account: Option<String>,
+}
+
+impl Entry {
+    fn into_transaction(self) -> Result<Transaction> {
+        let amount = self
+            .amount
+            .ok_or_else(|| anyhow!(get_message("statement-entry-without-amount", None)))?;
+        let date = self
+            .booking_date
+            .or(self.value_date)
+            .ok_or_else(|| anyhow!(get_message("statement-entry-without-date", None)))?;
+        // dates can be also in YYYY-MM-DDThh:mm:ss format
+        let date = NaiveDate::parse_from_str(date.get(..10).unwrap_or(&date), "%Y-%m-%d").map_err(
+            |err| {
+                anyhow!(get_message(
+                    "invalid-date",
+                    Some(fluent_args!["date" => date, "error" => err.to_string()])
+                ))
+            },
+        )?;
+        let variable_symbol = self
+            .creditor_reference
+            .iter()
+            .chain(self.end_to_end_id.iter())
+            .chain(self.remittance.iter())
+            .find_map(|text| variable_symbol(text));
+
+        Ok(Transaction {
+            reference: self.reference.or(self.entry_reference).unwrap_or_default(),
+            date,
+            amount: parse_amount(&amount)?,
+            credit: self.indicator == "CRDT",
+            currency: self.currency,
+            variable_symbol,
+            counterparty: self.account.or(self.name).unwrap_or_default(),
+        })
+    }
+}
+
+fn ends_with(path: &[String], suffix: &[&str]) -> bool {
+    path.len() >= suffix.len()
+        && path[path.len() - suffix.len()..]
+            .iter()
+            .zip(suffix)
+            .all(|(item, name)| item == name)
+}
+
+/// Sets the value only when it wasn't set before (the first occurrence wins)
+fn set(field: &mut Option<String>, value: &str) {
+    if field.is_none() {
+        *field = Some(value.to_string());
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Transaction>> {
+    let mut reader = Reader::from_str(data);
+    reader.trim_text(true);
+
+    let mut res = vec![];
+    let mut path: Vec<String> = vec![];
+    let mut entry: Option<Entry> = None;
+
+    loop {
+        match reader.read_event().map_err(|err| {
+            anyhow!(get_message(
+                "statement-invalid-camt",
+                Some(fluent_args!["error" => err.to_string()])
+            ))
+        })? {
+            Event::Start(element) => {
+                let name = String::from_utf8_lossy(element.local_name().as_ref()).to_string();
+                if name == "Ntry" {
+                    entry = Some(Entry::default());
+                }
+                if let Some(entry) = entry.as_mut() {
+                    if name == "Amt" && path.last().is_some_and(|last| last == "Ntry") {
+                        if let Some(currency) = element.try_get_attribute("Ccy")? {
+                            entry.currency = currency.unescape_value()?.to_string();
+                        }
+                    }
+                }
+                path.push(name);
+            }
+            Event::End(_) => {
+                if path.pop().as_deref() == Some("Ntry") {
+                    if let Some(entry) = entry.take() {
+                        res.push(entry.into_transaction()?);
+                    }
+                }
+            }
+            Event::Text(text) => {
+                let Some(entry) = entry.as_mut() else {
+                    continue;
+                };
+                let text = text.unescape()?;
+                let text = text.as_ref();
+                if ends_with(&path, &["Ntry", "Amt"]) {
+                    set(&mut entry.amount, text);
+                } else if ends_with(&path, &["Ntry", "CdtDbtInd"]) {
+                    entry.indicator = text.to_string();
+                } else if ends_with(&path, &["BookgDt", "Dt"])
+                    || ends_with(&path, &["BookgDt", "DtTm"])
+                {
+                    set(&mut entry.booking_date, text);
+                } else if ends_with(&path, &["ValDt", "Dt"]) || ends_with(&path, &["ValDt", "DtTm"])
+                {
+                    set(&mut entry.value_date, text);
+                } else if ends_with(&path, &["AcctSvcrRef"]) {
+                    set(&mut entry.reference, text);
+                } else if ends_with(&path, &["Ntry", "NtryRef"]) {
+                    set(&mut entry.entry_reference, text);
+                } else if ends_with(&path, &["CdtrRefInf", "Ref"]) {
+                    set(&mut entry.creditor_reference, text);
+                } else if ends_with(&path, &["Refs", "EndToEndId"]) {
+                    set(&mut entry.end_to_end_id, text);
+                } else if ends_with(&path, &["RmtInf", "Ustrd"]) {
+                    entry.remittance.push(text.to_string());
+                } else if path.iter().any(|name| name == "Dbtr") && ends_with(&path, &["Nm"]) {
+                    set(&mut entry.name, text);
+                } else if path.iter().any(|name| name == "DbtrAcct")
+                    && (ends_with(&path, &["IBAN"]) || ends_with(&path, &["Othr", "Id"]))
+                {
+                    set(&mut entry.account, text);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(res)
+}
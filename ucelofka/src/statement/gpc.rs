@@ -0,0 +1,81 @@
+//! ABO / GPC - fixed width statement format used by the czech banks
+//!
+//! Only the transaction records (`075`) are read.
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use fluent::fluent_args;
+
+use super::{normalize_variable_symbol, Transaction};
+
+use crate::translations::get_message;
+
+const RECORD_LENGTH: usize = 128;
+/// Accounting code of a credit (incoming payment)
+const CREDIT: &str = "2";
+
+/// Converts ISO 4217 numeric code to the currency code
+fn currency(code: &str) -> &str {
+    match code.trim_start_matches('0') {
+        "" | "203" => "CZK",
+        "978" => "EUR",
+        "840" => "USD",
+        "826" => "GBP",
+        "756" => "CHF",
+        "985" => "PLN",
+        "348" => "HUF",
+        _ => code,
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Transaction>> {
+    let mut res = vec![];
+
+    for (idx, line) in data.lines().enumerate() {
+        if !line.starts_with("075") {
+            continue;
+        }
+        // some banks strip the trailing spaces
+        let mut chars: Vec<char> = line.chars().collect();
+        chars.resize(chars.len().max(RECORD_LENGTH), ' ');
+        // positions are 1-based in the specification
+        let field = |from: usize, to: usize| -> String {
+            chars[from - 1..to]
+                .iter()
+                .collect::<String>()
+                .trim()
+                .to_string()
+        };
+
+        let amount: u64 = field(49, 60).parse::<u64>().map_err(|err| {
+            anyhow!(get_message(
+                "statement-invalid-amount-on-line",
+                Some(fluent_args!["line" => idx + 1, "error" => err.to_string()])
+            ))
+        })?;
+        let date = NaiveDate::parse_from_str(&field(92, 97), "%d%m%y").map_err(|err| {
+            anyhow!(get_message(
+                "statement-invalid-date-on-line",
+                Some(fluent_args!["line" => idx + 1, "error" => err.to_string()])
+            ))
+        })?;
+        let counter_account = field(20, 35).trim_start_matches('0').to_string();
+        let counterparty = if counter_account.is_empty() {
+            field(98, 117)
+        } else {
+            format!("{}/{}", counter_account, field(74, 77))
+        };
+
+        res.push(Transaction {
+            reference: field(36, 48),
+            date,
+            amount: amount as f32 / 100.0,
+            credit: field(61, 61) == CREDIT,
+            currency: currency(&field(119, 122)).to_string(),
+            variable_symbol: normalize_variable_symbol(&field(62, 71)),
+            counterparty,
+        });
+    }
+
+    Ok(res)
+}
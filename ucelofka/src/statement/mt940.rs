@@ -0,0 +1,136 @@
+//! SWIFT MT940 customer statement
+//!
+//! Transactions are read from `:61:` statement lines. The variable symbol is searched
+//! in the customer reference and in the information to the account owner (`:86:`).
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use fluent::fluent_args;
+
+use super::{parse_amount, variable_symbol, Transaction};
+
+use crate::translations::get_message;
+
+/// Splits the statement into `(tag, value)` fields, continuation lines are kept in the value
+fn fields(data: &str) -> Vec<(String, String)> {
+    let mut res: Vec<(String, String)> = vec![];
+    for line in data.lines() {
+        let line = line.trim_end();
+        let tag = line
+            .strip_prefix(':')
+            .and_then(|rest| rest.split_once(':'))
+            .filter(|(tag, _)| {
+                !tag.is_empty() && tag.len() <= 3 && tag.chars().all(|c| c.is_ascii_alphanumeric())
+            });
+        match (tag, res.last_mut()) {
+            (Some((tag, value)), _) => res.push((tag.to_string(), value.to_string())),
+            (None, Some((_, value))) => {
+                value.push('\n');
+                value.push_str(line);
+            }
+            (None, None) => {}
+        }
+    }
+    res
+}
+
+/// Subfield of structured `:86:` information (e.g. `?32` - name of the other party)
+fn subfield(information: &str, code: &str) -> Option<String> {
+    let information = information.replace('\n', "");
+    let start = information.find(&format!("?{}", code))? + 3;
+    let value = information[start..]
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .trim();
+    Some(value.to_string()).filter(|value| !value.is_empty())
+}
+
+fn statement_line(value: &str, currency: &str) -> Result<Transaction> {
+    let invalid = || {
+        anyhow!(get_message(
+            "statement-invalid-line",
+            Some(fluent_args!["line" => value])
+        ))
+    };
+    let (first, details) = value.split_once('\n').unwrap_or((value, ""));
+
+    let date = NaiveDate::parse_from_str(first.get(..6).ok_or_else(invalid)?, "%y%m%d")
+        .map_err(|_| invalid())?;
+    let mut rest = &first[6..];
+    // optional entry date (MMDD)
+    if rest
+        .get(..4)
+        .is_some_and(|date| date.chars().all(|c| c.is_ascii_digit()))
+    {
+        rest = &rest[4..];
+    }
+    let credit = if let Some(stripped) = rest.strip_prefix("RC").or(rest.strip_prefix("RD")) {
+        // reversals are not considered to be payments
+        rest = stripped;
+        false
+    } else if let Some(stripped) = rest.strip_prefix('C') {
+        rest = stripped;
+        true
+    } else if let Some(stripped) = rest.strip_prefix('D') {
+        rest = stripped;
+        false
+    } else {
+        return Err(invalid());
+    };
+    // optional funds code (third character of the currency)
+    if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        rest = &rest[1..];
+    }
+    let amount_len = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == ','))
+        .unwrap_or(rest.len());
+    let amount = parse_amount(&rest[..amount_len])?;
+    // transaction type identification code (e.g. NTRF)
+    let rest = rest.get(amount_len + 4..).unwrap_or_default();
+    let (customer_reference, bank_reference) = rest.split_once("//").unwrap_or((rest, ""));
+    let customer_reference = Some(customer_reference.trim()).filter(|r| *r != "NONREF");
+
+    Ok(Transaction {
+        reference: Some(bank_reference.trim())
+            .filter(|r| !r.is_empty())
+            .or(customer_reference)
+            .unwrap_or_default()
+            .to_string(),
+        date,
+        amount,
+        credit,
+        currency: currency.to_string(),
+        variable_symbol: customer_reference.and_then(variable_symbol),
+        counterparty: details.trim().to_string(),
+    })
+}
+
+pub fn parse(data: &str) -> Result<Vec<Transaction>> {
+    let mut res: Vec<Transaction> = vec![];
+    let mut currency = String::new();
+
+    for (tag, value) in fields(data) {
+        match tag.as_str() {
+            // opening balance contains the currency of the statement
+            "60F" | "60M" => currency = value.get(7..10).unwrap_or_default().to_string(),
+            "61" => res.push(statement_line(&value, &currency)?),
+            "86" => {
+                let Some(transaction) = res.last_mut() else {
+                    continue;
+                };
+                if transaction.variable_symbol.is_none() {
+                    transaction.variable_symbol = variable_symbol(&value.replace('\n', " "));
+                }
+                if let Some(account) = subfield(&value, "31") {
+                    transaction.counterparty = account;
+                } else if let Some(name) = subfield(&value, "32") {
+                    transaction.counterparty = name;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(res)
+}
@@ -4,12 +4,16 @@ pub use ucelofka_data::{
     entry::{Entries, Entry},
     identity::{Identities, Identity},
     invoice::{Invoice, Invoices},
+    payment::{Payment, Payments},
     template::{Template, Templates},
     Versioned,
 };
 
 use anyhow::{anyhow, Result};
+use fluent::fluent_args;
 use serde::Serialize;
+
+use crate::translations::get_message;
 use std::{
     convert::TryFrom,
     fmt::Debug,
@@ -41,10 +45,36 @@ where
     ITEM: Versioned,
 {
     fn new(records: Vec<ITEM>) -> Self;
+    fn records(&self) -> &[ITEM];
+
     fn load(dir: &Path) -> Result<Self>
     where
-        Self: Sized;
-    fn records(&self) -> &[ITEM];
+        Self: Sized,
+    {
+        let paths = Self::paths(dir)?;
+        Ok(Self::new(Self::load_records(paths)?))
+    }
+
+    /// Loads the records which can be parsed, the errors of the other files are returned as well
+    fn load_valid(dir: &Path) -> Result<(Self, Vec<anyhow::Error>)>
+    where
+        Self: Sized,
+    {
+        let mut records = Vec::new();
+        let mut errors = Vec::new();
+        for path in Self::paths(dir)? {
+            match Self::load_record(&path) {
+                Ok(record) => records.push(record),
+                Err(err) => errors.push(err),
+            }
+        }
+        Ok((Self::new(records), errors))
+    }
+
+    /// Files containing the records
+    fn paths(dir: &Path) -> Result<Vec<PathBuf>> {
+        Self::list_directory(dir)
+    }
 
     fn get(&self, id: &str) -> Option<ITEM> {
         for record in self.records() {
@@ -72,19 +102,28 @@ where
     }
 
     fn load_records(paths: Vec<PathBuf>) -> Result<Vec<ITEM>> {
-        let mut res: Vec<ITEM> = Vec::new();
-
-        for path in paths {
-            let path_str = path
-                .to_str()
-                .ok_or_else(|| anyhow!("Invalid path {}", path.to_string_lossy()))?
-                .to_string();
-            let data = std::fs::read_to_string(path)?;
-            let parsed = ITEM::latest(&data)
-                .map_err(|err| anyhow!("failed to convert {} - {:?}", path_str, err))?;
-            res.push(parsed);
-        }
-        Ok(res)
+        paths.iter().map(|path| Self::load_record(path)).collect()
+    }
+
+    fn load_record(path: &Path) -> Result<ITEM> {
+        let path_str = path.to_str().ok_or_else(|| {
+            anyhow!(get_message(
+                "invalid-path",
+                Some(fluent_args!["path" => path.to_string_lossy()])
+            ))
+        })?;
+        let data = std::fs::read_to_string(path).map_err(|err| {
+            anyhow!(get_message(
+                "file-read-failed",
+                Some(fluent_args!["path" => path_str, "error" => err.to_string()])
+            ))
+        })?;
+        ITEM::latest(&data).map_err(|err| {
+            anyhow!(get_message(
+                "record-convert-failed",
+                Some(fluent_args!["path" => path_str, "error" => format!("{:?}", err)])
+            ))
+        })
     }
 }
 
@@ -99,11 +138,6 @@ impl Records<Account> for Accounts {
         Self { accounts }
     }
 
-    fn load(dir: &Path) -> Result<Self> {
-        let paths = Self::list_directory(dir)?;
-        Ok(Self::new(Self::load_records(paths)?))
-    }
-
     fn records(&self) -> &[Account] {
         &self.accounts
     }
@@ -120,11 +154,6 @@ impl Records<Customer> for Customers {
         Self { customers }
     }
 
-    fn load(dir: &Path) -> Result<Self> {
-        let paths = Self::list_directory(dir)?;
-        Ok(Self::new(Self::load_records(paths)?))
-    }
-
     fn records(&self) -> &[Customer] {
         &self.customers
     }
@@ -141,11 +170,6 @@ impl Records<Entry> for Entries {
         Self { entries }
     }
 
-    fn load(dir: &Path) -> Result<Self> {
-        let paths = Self::list_directory(dir)?;
-        Ok(Self::new(Self::load_records(paths)?))
-    }
-
     fn records(&self) -> &[Entry] {
         &self.entries
     }
@@ -162,11 +186,6 @@ impl Records<Identity> for Identities {
         Self { identities }
     }
 
-    fn load(dir: &Path) -> Result<Self> {
-        let paths = Self::list_directory(dir)?;
-        Ok(Self::new(Self::load_records(paths)?))
-    }
-
     fn records(&self) -> &[Identity] {
         &self.identities
     }
@@ -183,12 +202,35 @@ impl Records<Invoice> for Invoices {
         Self { invoices }
     }
 
-    fn load(dir: &Path) -> Result<Self> {
-        let paths = Self::list_directory(dir)?;
-        Ok(Self::new(Self::load_records(paths)?))
-    }
-
     fn records(&self) -> &[Invoice] {
         &self.invoices
     }
 }
+
+impl Record for Payment {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl Records<Payment> for Payments {
+    fn new(payments: Vec<Payment>) -> Self {
+        Self { payments }
+    }
+
+    fn records(&self) -> &[Payment] {
+        &self.payments
+    }
+
+    fn paths(dir: &Path) -> Result<Vec<PathBuf>> {
+        // projects created by older versions don't have payments dir
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+        // the dir is empty in a new project (contains only .gitkeep)
+        Ok(Self::list_directory(dir)?
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "yml"))
+            .collect())
+    }
+}
@@ -0,0 +1,610 @@
+//! CSV and JSON representation of the records
+//!
+//! JSON contains the same structure as the YAML files. In CSV lists (e.g. address lines)
+//! are stored in a single cell separated by newlines and each identification has its own
+//! column (e.g. `identification.tax`). Invoices are flattened to a row per invoice entry.
+
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use std::{fmt, path::Path, str::FromStr};
+
+use crate::{
+    data::{
+        account::{self, Account},
+        customer::{self, Customer},
+        entry::{self, Entry},
+        identification::Identification,
+        identity::{self, Identity},
+        invoice::{self, Invoice},
+    },
+    storage::{Record, Versioned},
+    translations::get_message,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    pub const NAMES: &'static [&'static str] = &["csv", "json"];
+
+    /// Detects the format from the file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .and_then(|e| e.to_lowercase().parse().ok())
+    }
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(anyhow!(get_message(
+                "unknown-format",
+                Some(fluent_args!["value" => value])
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Csv => write!(f, "csv"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Cells of a single CSV row in the column order
+#[derive(Debug, Default, Clone)]
+pub struct Row(Vec<(String, String)>);
+
+impl Row {
+    /// Row made of `(column, value)` cells, list values are separated by newlines
+    pub fn new(cells: Vec<(String, String)>) -> Self {
+        Self(cells)
+    }
+
+    fn push<V: Into<String>>(&mut self, column: &str, value: V) {
+        self.0.push((column.to_string(), value.into()));
+    }
+
+    fn push_lines(&mut self, column: &str, values: &[String]) {
+        self.push(column, values.join("\n"));
+    }
+
+    fn push_identifications(&mut self, prefix: &str, identifications: &[Identification]) {
+        for identification in identifications {
+            self.push(
+                &format!("{}identification.{}", prefix, identification.name),
+                identification.value.as_str(),
+            );
+        }
+    }
+
+    pub fn get(&self, column: &str) -> &str {
+        self.0
+            .iter()
+            .find(|(name, _)| name == column)
+            .map(|(_, value)| value.trim())
+            .unwrap_or_default()
+    }
+
+    fn optional(&self, column: &str) -> Option<String> {
+        Some(self.get(column).to_string()).filter(|value| !value.is_empty())
+    }
+
+    fn required(&self, column: &str) -> Result<String> {
+        self.optional(column).ok_or_else(|| {
+            anyhow!(get_message(
+                "value-missing",
+                Some(fluent_args!["column" => column])
+            ))
+        })
+    }
+
+    fn parse<T>(&self, column: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.required(column)?.parse::<T>().map_err(|err| {
+            anyhow!(get_message(
+                "value-invalid",
+                Some(fluent_args!["column" => column, "error" => err.to_string()])
+            ))
+        })
+    }
+
+    fn parse_optional<T>(&self, column: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.optional(column)
+            .map_or(Ok(None), |_| self.parse(column).map(Some))
+    }
+
+    fn lines(&self, column: &str) -> Vec<String> {
+        self.get(column)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    fn identifications(&self, prefix: &str) -> Vec<Identification> {
+        let prefix = format!("{}identification.", prefix);
+        self.0
+            .iter()
+            .filter_map(|(column, value)| {
+                let name = column.strip_prefix(&prefix)?;
+                Some(Identification {
+                    name: name.to_string(),
+                    value: value.trim().to_string(),
+                })
+            })
+            .filter(|identification| !identification.value.is_empty())
+            .collect()
+    }
+}
+
+/// Record which can be converted to and from CSV rows
+pub trait Tabular: Record + Versioned + Sized {
+    fn to_rows(&self) -> Vec<Row>;
+
+    /// Creates the record from all the rows with the same id
+    fn from_rows(rows: &[Row]) -> Result<Self>;
+
+    /// Checks the record before it is stored
+    fn validate(&self) -> Result<()> {
+        validate_id(&self.id())
+    }
+}
+
+/// Ids are used as filenames
+fn validate_id(id: &str) -> Result<()> {
+    if id.is_empty()
+        || id.starts_with('.')
+        || id.contains(|c: char| c == '/' || c == '\\' || c.is_control())
+    {
+        return Err(anyhow!(get_message(
+            "id-invalid",
+            Some(fluent_args!["id" => id])
+        )));
+    }
+    Ok(())
+}
+
+/// Records which are stored in a single row
+fn single_row(rows: &[Row]) -> Result<&Row> {
+    match rows {
+        [row] => Ok(row),
+        _ => Err(anyhow!(get_message("id-not-unique", None))),
+    }
+}
+
+impl Tabular for Account {
+    fn to_rows(&self) -> Vec<Row> {
+        let mut row = Row::default();
+        row.push("id", self.id.as_str());
+        row.push("name", self.name.as_str());
+        row.push("bank_name", self.bank_name.as_str());
+        row.push("account_name", self.account_name.as_str());
+        row.push("account_number", self.account_number.as_str());
+        row.push("IBAN", self.IBAN.as_str());
+        row.push("BIC", self.BIC.as_str());
+        row.push("currency", self.currency.as_str());
+        vec![row]
+    }
+
+    fn from_rows(rows: &[Row]) -> Result<Self> {
+        let row = single_row(rows)?;
+        Ok(Self {
+            _version: account::CURRENT_VERSION,
+            id: row.required("id")?,
+            name: row.required("name")?,
+            bank_name: row.get("bank_name").to_string(),
+            account_name: row.required("account_name")?,
+            account_number: row.required("account_number")?,
+            IBAN: row.get("IBAN").to_string(),
+            BIC: row.get("BIC").to_string(),
+            currency: row.required("currency")?,
+        })
+    }
+}
+
+impl Tabular for Customer {
+    fn to_rows(&self) -> Vec<Row> {
+        let mut row = Row::default();
+        row.push("id", self.id.as_str());
+        row.push("name", self.name.as_str());
+        row.push_lines("address", &self.address);
+        row.push_lines("email", &self.email);
+        row.push("locale", self.locale.clone().unwrap_or_default());
+        row.push_identifications("", &self.identifications);
+        vec![row]
+    }
+
+    fn from_rows(rows: &[Row]) -> Result<Self> {
+        let row = single_row(rows)?;
+        Ok(Self {
+            _version: customer::CURRENT_VERSION,
+            id: row.required("id")?,
+            name: row.required("name")?,
+            address: row.lines("address"),
+            email: row.lines("email"),
+            locale: row.optional("locale"),
+            identifications: row.identifications(""),
+        })
+    }
+}
+
+impl Tabular for Entry {
+    fn to_rows(&self) -> Vec<Row> {
+        let mut row = Row::default();
+        row.push("id", self.id.as_str());
+        row.push("name", self.name.as_str());
+        row.push("price", self.price.to_string());
+        row.push("currency", self.currency.as_str());
+        row.push_lines("details", &self.details);
+        if let Some(vat_rate) = self.vat_rate {
+            row.push("vat_rate", vat_rate.to_string());
+        }
+        vec![row]
+    }
+
+    fn from_rows(rows: &[Row]) -> Result<Self> {
+        let row = single_row(rows)?;
+        Ok(Self {
+            _version: entry::CURRENT_VERSION,
+            id: row.required("id")?,
+            name: row.required("name")?,
+            price: row.parse("price")?,
+            currency: row.required("currency")?,
+            details: row.lines("details"),
+            vat_rate: row.parse_optional("vat_rate")?,
+        })
+    }
+
+    fn validate(&self) -> Result<()> {
+        validate_id(&self.id)?;
+        if !self.price.is_finite() {
+            return Err(anyhow!(get_message("price-invalid", None)));
+        }
+        if self.currency.is_empty() {
+            return Err(anyhow!(get_message("currency-missing", None)));
+        }
+        if self
+            .vat_rate
+            .is_some_and(|rate| !rate.is_finite() || rate < 0.0)
+        {
+            return Err(anyhow!(get_message("vat-rate-invalid", None)));
+        }
+        Ok(())
+    }
+}
+
+impl Tabular for Identity {
+    fn to_rows(&self) -> Vec<Row> {
+        let mut row = Row::default();
+        row.push("id", self.id.as_str());
+        row.push("name", self.name.as_str());
+        row.push_lines("address", &self.address);
+        row.push_lines("phone", &self.phone);
+        row.push_lines("email", &self.email);
+        row.push_lines("www", &self.www);
+        row.push_identifications("", &self.identifications);
+        vec![row]
+    }
+
+    fn from_rows(rows: &[Row]) -> Result<Self> {
+        let row = single_row(rows)?;
+        Ok(Self {
+            _version: identity::CURRENT_VERSION,
+            id: row.required("id")?,
+            name: row.required("name")?,
+            address: row.lines("address"),
+            phone: row.lines("phone"),
+            email: row.lines("email"),
+            www: row.lines("www"),
+            identifications: row.identifications(""),
+        })
+    }
+}
+
+impl Tabular for Invoice {
+    fn to_rows(&self) -> Vec<Row> {
+        let mut invoice_row = Row::default();
+        invoice_row.push("id", self.id.to_string());
+        invoice_row.push("issue_date", self.issue_date.as_str());
+        invoice_row.push("due_date", self.due_date.as_str());
+        invoice_row.push("issuer.name", self.issuer.name.as_str());
+        invoice_row.push_lines("issuer.address", &self.issuer.address);
+        invoice_row.push_lines("issuer.phone", &self.issuer.phone);
+        invoice_row.push_lines("issuer.email", &self.issuer.email);
+        invoice_row.push_lines("issuer.www", &self.issuer.www);
+        invoice_row.push_identifications("issuer.", &self.issuer.identifications);
+        invoice_row.push("customer.name", self.customer.name.as_str());
+        invoice_row.push_lines("customer.address", &self.customer.address);
+        invoice_row.push_lines("customer.email", &self.customer.email);
+        invoice_row.push(
+            "customer.locale",
+            self.customer.locale.clone().unwrap_or_default(),
+        );
+        invoice_row.push_identifications("customer.", &self.customer.identifications);
+        invoice_row.push("billing.account_name", self.billing.account_name.as_str());
+        invoice_row.push(
+            "billing.account_number",
+            self.billing.account_number.as_str(),
+        );
+        invoice_row.push("billing.IBAN", self.billing.IBAN.as_str());
+        invoice_row.push("billing.BIC", self.billing.BIC.as_str());
+        invoice_row.push("billing.total", self.billing.total.to_string());
+        invoice_row.push("billing.currency", self.billing.currency.as_str());
+        invoice_row.push(
+            "billing.variable_symbol",
+            self.billing.variable_symbol.as_str(),
+        );
+        if let Some(paid) = &self.paid {
+            invoice_row.push("paid", paid.as_str());
+        }
+        if !self.reminders.is_empty() {
+            let reminders: Vec<String> = self
+                .reminders
+                .iter()
+                .map(|reminder| format!("{} {}", reminder.level, reminder.date))
+                .collect();
+            invoice_row.push_lines("reminders", &reminders);
+        }
+        if !self.sent.is_empty() {
+            let sent: Vec<String> = self
+                .sent
+                .iter()
+                .map(|delivery| format!("{} {}", delivery.date, delivery.recipients.join(",")))
+                .collect();
+            invoice_row.push_lines("sent", &sent);
+        }
+
+        self.entries
+            .iter()
+            .map(|entry| {
+                let mut row = invoice_row.clone();
+                row.push("entry.name", entry.name.as_str());
+                row.push("entry.price", entry.price.to_string());
+                row.push("entry.currency", entry.currency.as_str());
+                row.push_lines("entry.details", &entry.details);
+                if let Some(vat_rate) = entry.vat_rate {
+                    row.push("entry.vat_rate", vat_rate.to_string());
+                }
+                row
+            })
+            .collect()
+    }
+
+    fn from_rows(rows: &[Row]) -> Result<Self> {
+        let row = rows
+            .first()
+            .ok_or_else(|| anyhow!(get_message("invoice-without-rows", None)))?;
+        let entries = rows
+            .iter()
+            .map(|row| {
+                Ok(invoice::latest::Entry {
+                    name: row.required("entry.name")?,
+                    price: row.parse("entry.price")?,
+                    currency: row.required("entry.currency")?,
+                    details: row.lines("entry.details"),
+                    vat_rate: row.parse_optional("entry.vat_rate")?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // reminders are stored as `<level> <date>` lines
+        let reminders = row
+            .lines("reminders")
+            .iter()
+            .map(|line| {
+                let (level, date) = line.split_once(' ').ok_or_else(|| {
+                    anyhow!(get_message(
+                        "reminder-invalid",
+                        Some(fluent_args!["reminder" => line.as_str()])
+                    ))
+                })?;
+                Ok(invoice::latest::Reminder {
+                    level: level.parse::<u32>().map_err(|err| {
+                        anyhow!(get_message(
+                            "reminder-level-invalid",
+                            Some(fluent_args!["reminder" => line.as_str(), "error" => err.to_string()])
+                        ))
+                    })?,
+                    date: date.trim().to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // deliveries are stored as `<date> <recipient>,<recipient>` lines
+        let sent = row
+            .lines("sent")
+            .iter()
+            .map(|line| {
+                let (date, recipients) = line.split_once(' ').ok_or_else(|| {
+                    anyhow!(get_message(
+                        "delivery-invalid",
+                        Some(fluent_args!["delivery" => line.as_str()])
+                    ))
+                })?;
+                Ok(invoice::latest::Delivery {
+                    date: date.to_string(),
+                    recipients: recipients
+                        .split(',')
+                        .map(|recipient| recipient.trim().to_string())
+                        .collect(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            _version: invoice::CURRENT_VERSION,
+            id: row.parse("id")?,
+            issue_date: row.required("issue_date")?,
+            due_date: row.required("due_date")?,
+            issuer: invoice::latest::Issuer {
+                name: row.required("issuer.name")?,
+                address: row.lines("issuer.address"),
+                phone: row.lines("issuer.phone"),
+                email: row.lines("issuer.email"),
+                www: row.lines("issuer.www"),
+                identifications: row.identifications("issuer."),
+            },
+            customer: invoice::latest::Customer {
+                name: row.required("customer.name")?,
+                address: row.lines("customer.address"),
+                email: row.lines("customer.email"),
+                locale: row.optional("customer.locale"),
+                identifications: row.identifications("customer."),
+            },
+            billing: invoice::latest::Billing {
+                account_name: row.required("billing.account_name")?,
+                account_number: row.required("billing.account_number")?,
+                IBAN: row.get("billing.IBAN").to_string(),
+                BIC: row.get("billing.BIC").to_string(),
+                total: row.parse("billing.total")?,
+                currency: row.required("billing.currency")?,
+                variable_symbol: row.required("billing.variable_symbol")?,
+            },
+            entries,
+            paid: row.optional("paid"),
+            reminders,
+            sent,
+        })
+    }
+
+    fn validate(&self) -> Result<()> {
+        let dates = vec![&self.issue_date, &self.due_date]
+            .into_iter()
+            .chain(self.paid.iter())
+            .chain(self.reminders.iter().map(|reminder| &reminder.date))
+            .chain(self.sent.iter().map(|delivery| &delivery.date));
+        for date in dates {
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|err| {
+                anyhow!(get_message(
+                    "invalid-date",
+                    Some(fluent_args!["date" => date.as_str(), "error" => err.to_string()])
+                ))
+            })?;
+        }
+        if self.entries.is_empty() {
+            return Err(anyhow!(get_message("invoice-without-entries", None)));
+        }
+        if let Some(entry) = self
+            .entries
+            .iter()
+            .find(|entry| entry.currency != self.billing.currency)
+        {
+            return Err(anyhow!(get_message(
+                "entry-different-currency",
+                Some(fluent_args!["entry" => entry.name.as_str()])
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the records
+pub fn to_string<T: Tabular>(records: &[T], format: Format) -> Result<String> {
+    match format {
+        Format::Json => Ok(serde_json::to_string_pretty(records)? + "\n"),
+        Format::Csv => {
+            let rows: Vec<Row> = records.iter().flat_map(Tabular::to_rows).collect();
+            let mut header: Vec<&str> = vec![];
+            for (column, _) in rows.iter().flat_map(|row| row.0.iter()) {
+                if !header.contains(&column.as_str()) {
+                    header.push(column);
+                }
+            }
+
+            let mut writer = csv::Writer::from_writer(vec![]);
+            writer.write_record(&header)?;
+            for row in &rows {
+                writer.write_record(header.iter().map(|column| row.get(column)))?;
+            }
+            Ok(String::from_utf8(writer.into_inner()?)?)
+        }
+    }
+}
+
+/// Deserializes and validates the records
+///
+/// JSON records are read the same way as the YAML files so older versions
+/// of the records are upgraded.
+pub fn from_str<T: Tabular>(data: &str, format: Format) -> Result<Vec<T>> {
+    let records = match format {
+        Format::Json => {
+            let values: Vec<serde_json::Value> = serde_json::from_str(data).map_err(|err| {
+                anyhow!(get_message(
+                    "json-invalid",
+                    Some(fluent_args!["error" => err.to_string()])
+                ))
+            })?;
+            values
+                .iter()
+                .enumerate()
+                .map(|(idx, value)| {
+                    T::latest(&serde_yaml::to_string(value)?).map_err(|err| {
+                        anyhow!(get_message(
+                            "record-index-error",
+                            Some(fluent_args!["index" => idx + 1, "error" => err.to_string()])
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<T>>>()?
+        }
+        Format::Csv => {
+            let mut reader = csv::Reader::from_reader(data.as_bytes());
+            let header = reader.headers()?.clone();
+
+            // rows with the same id belong to the same record
+            let mut groups: Vec<(String, Vec<Row>)> = vec![];
+            for record in reader.records() {
+                let record = record?;
+                let row = Row(header
+                    .iter()
+                    .map(String::from)
+                    .zip(record.iter().map(String::from))
+                    .collect());
+                let id = row.get("id").to_string();
+                match groups.iter_mut().find(|(group_id, _)| *group_id == id) {
+                    Some((_, rows)) => rows.push(row),
+                    None => groups.push((id, vec![row])),
+                }
+            }
+            groups
+                .iter()
+                .map(|(id, rows)| {
+                    T::from_rows(rows).map_err(|err| {
+                        anyhow!(get_message(
+                            "record-id-error",
+                            Some(fluent_args!["id" => id.as_str(), "error" => err.to_string()])
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<T>>>()?
+        }
+    };
+
+    for record in &records {
+        record.validate().map_err(|err| {
+            anyhow!(get_message(
+                "record-id-error",
+                Some(fluent_args!["id" => record.id(), "error" => err.to_string()])
+            ))
+        })?;
+    }
+
+    Ok(records)
+}
@@ -0,0 +1,213 @@
+//! Czech VAT - tax periods, rates, summaries of invoices and the control statement
+//! (kontrolní hlášení)
+
+pub mod control_statement;
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use fluent::fluent_args;
+use serde::Serialize;
+
+use crate::{data::invoice::Invoice, translations::get_message};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+/// Tax period - a calendar month or quarter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Period {
+    Month { year: i32, month: u32 },
+    Quarter { year: i32, quarter: u32 },
+}
+
+impl Period {
+    pub fn first_day(&self) -> NaiveDate {
+        let (year, month) = match *self {
+            Self::Month { year, month } => (year, month),
+            Self::Quarter { year, quarter } => (year, (quarter - 1) * 3 + 1),
+        };
+        NaiveDate::from_ymd_opt(year, month, 1).unwrap()
+    }
+
+    pub fn last_day(&self) -> NaiveDate {
+        let (year, month) = match *self {
+            Self::Month { year, month } => (year, month),
+            Self::Quarter { year, quarter } => (year, quarter * 3),
+        };
+        let (year, month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        NaiveDate::from_ymd_opt(year, month, 1)
+            .unwrap()
+            .pred_opt()
+            .unwrap()
+    }
+}
+
+/// Accepts `2026Q3`, `2026-Q3` or `2026-07`
+impl FromStr for Period {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let invalid = || {
+            anyhow!(get_message(
+                "invalid-tax-period",
+                Some(fluent_args!["period" => value])
+            ))
+        };
+        let upper = value.trim().to_uppercase();
+        let (year, rest) = upper.split_at_checked(4).ok_or_else(invalid)?;
+        let year: i32 = year.parse().map_err(|_| invalid())?;
+        let rest = rest.strip_prefix('-').unwrap_or(rest);
+        if let Some(quarter) = rest.strip_prefix('Q') {
+            match quarter.parse() {
+                Ok(quarter @ 1..=4) => Ok(Self::Quarter { year, quarter }),
+                _ => Err(invalid()),
+            }
+        } else {
+            match rest.parse() {
+                Ok(month @ 1..=12) if rest.len() == 2 => Ok(Self::Month { year, month }),
+                _ => Err(invalid()),
+            }
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Month { year, month } => write!(f, "{}-{:02}", year, month),
+            Self::Quarter { year, quarter } => write!(f, "{}Q{}", year, quarter),
+        }
+    }
+}
+
+/// VAT rate levels as reported in the tax forms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rate {
+    Basic,
+    Reduced,
+    SecondReduced,
+}
+
+impl Rate {
+    /// Maps the rate in percent to its level (including the rates valid in the past)
+    pub fn classify(rate: f32) -> Option<Self> {
+        match (rate * 100.0).round() as i64 {
+            2000 | 2100 | 2200 => Some(Self::Basic),
+            1200 | 1400 | 1500 => Some(Self::Reduced),
+            1000 => Some(Self::SecondReduced),
+            _ => None,
+        }
+    }
+
+    /// Index used in the names of the form fields (`zakl_dane1`, `dan1`, ...)
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Basic => 1,
+            Self::Reduced => 2,
+            Self::SecondReduced => 3,
+        }
+    }
+}
+
+/// Label of the VAT rate used in the reports (`-` for entries not subject to VAT)
+pub fn rate_label(rate: Option<f32>) -> String {
+    rate.map_or_else(|| "-".to_string(), |rate| rate.to_string())
+}
+
+/// Rounds the amount to two decimal places
+fn round(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+/// Taxable base and VAT of the entries with the same rate
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Subtotal {
+    /// Rate in percent, zero for the entries without VAT
+    pub rate: f32,
+    pub base: f32,
+    pub tax: f32,
+    pub total: f32,
+}
+
+/// VAT of the invoice per rate (the highest rate first), amounts are rounded to two decimal places
+///
+/// It is also available in the templates as `vat`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Summary {
+    pub subtotals: Vec<Subtotal>,
+    pub base: f32,
+    pub tax: f32,
+    pub total: f32,
+    /// Some of the entries are subject to VAT
+    pub applicable: bool,
+}
+
+impl Summary {
+    pub fn new(invoice: &Invoice) -> Self {
+        let mut groups: BTreeMap<i64, (f32, f32, f32)> = BTreeMap::new();
+        for entry in &invoice.entries {
+            let rate = entry.vat_rate.unwrap_or(0.0);
+            let group = groups.entry(-(rate * 100.0).round() as i64).or_default();
+            group.0 = rate;
+            group.1 += entry.price;
+            group.2 += entry.tax();
+        }
+
+        let subtotals: Vec<_> = groups
+            .into_values()
+            .map(|(rate, base, tax)| Subtotal {
+                rate,
+                base: round(base),
+                tax: round(tax),
+                total: round(base) + round(tax),
+            })
+            .collect();
+        // empty float sums are negative zero
+        let base = subtotals
+            .iter()
+            .fold(0.0, |sum, subtotal| sum + subtotal.base);
+        let tax = subtotals
+            .iter()
+            .fold(0.0, |sum, subtotal| sum + subtotal.tax);
+        Self {
+            applicable: subtotals.iter().any(|subtotal| subtotal.rate != 0.0),
+            subtotals,
+            base,
+            tax,
+            total: base + tax,
+        }
+    }
+
+    /// Base and tax of the rate level, entries without VAT are not included
+    pub fn level(&self, level: Rate) -> (f32, f32) {
+        self.subtotals
+            .iter()
+            .filter(|subtotal| Rate::classify(subtotal.rate) == Some(level))
+            .fold((0.0, 0.0), |(base, tax), subtotal| {
+                (base + subtotal.base, tax + subtotal.tax)
+            })
+    }
+
+    /// Base of the entries without VAT
+    pub fn exempt(&self) -> f32 {
+        self.subtotals
+            .iter()
+            .filter(|subtotal| subtotal.rate == 0.0)
+            .fold(0.0, |sum, subtotal| sum + subtotal.base)
+    }
+
+    /// Fails when a rate can't be reported in the czech accounting software
+    pub fn check_rates(&self, invoice: &Invoice) -> Result<()> {
+        for subtotal in &self.subtotals {
+            if subtotal.rate != 0.0 && Rate::classify(subtotal.rate).is_none() {
+                return Err(anyhow!(get_message(
+                    "vat-rate-unsupported",
+                    Some(fluent_args!["invoice" => invoice.id, "rate" => subtotal.rate])
+                )));
+            }
+        }
+        Ok(())
+    }
+}
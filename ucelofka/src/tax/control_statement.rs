@@ -0,0 +1,276 @@
+//! Control statement (kontrolní hlášení, `DPHKH1`) in the XML format of the EPO tax portal
+//!
+//! Only the issued invoices are included. Invoices to VAT payers over 10 000 CZK
+//! including VAT are listed in the section A.4, the rest is summed in A.5.
+//! Invoices to foreign customers are not part of the control statement.
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use fluent::fluent_args;
+
+use crate::{
+    data::invoice::Invoice,
+    export::{identification, xml_finish, xml_writer, Address, XmlWriter, HOME_CURRENCY},
+    translations::get_message,
+};
+
+use super::{Period, Rate};
+
+const FORM_VERSION: &str = "03.01";
+/// Invoices to VAT payers above this amount (including VAT) are listed separately
+const LIMIT: f64 = 10_000.0;
+const DATE_FORMAT: &str = "%d.%m.%Y";
+
+/// Details of the taxpayer which are not present in the invoices
+#[derive(Debug, Clone, Default)]
+pub struct Taxpayer {
+    /// Legal person (company), natural person otherwise
+    pub legal_person: bool,
+    /// Code of the tax office (`c_ufo`)
+    pub tax_office: Option<String>,
+}
+
+/// Taxable bases and taxes per rate level
+#[derive(Debug, Default, Clone, Copy)]
+struct Amounts {
+    base: [f64; 3],
+    tax: [f64; 3],
+}
+
+impl Amounts {
+    fn from_invoice(invoice: &Invoice) -> Result<Self> {
+        let mut res = Self::default();
+        for entry in &invoice.entries {
+            let Some(rate) = entry.vat_rate.filter(|rate| *rate != 0.0) else {
+                continue;
+            };
+            let level = Rate::classify(rate).ok_or_else(|| {
+                anyhow!(get_message(
+                    "vat-rate-unsupported",
+                    Some(fluent_args!["invoice" => invoice.id, "rate" => rate])
+                ))
+            })?;
+            res.base[level.index() - 1] += f64::from(entry.price);
+            res.tax[level.index() - 1] += f64::from(entry.tax());
+        }
+        for value in res.base.iter_mut().chain(res.tax.iter_mut()) {
+            *value = (*value * 100.0).round() / 100.0;
+        }
+        Ok(res)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.base
+            .iter()
+            .chain(self.tax.iter())
+            .all(|value| *value == 0.0)
+    }
+
+    fn total(&self) -> f64 {
+        self.base.iter().chain(self.tax.iter()).sum()
+    }
+
+    fn add(&mut self, other: &Self) {
+        for idx in 0..3 {
+            self.base[idx] += other.base[idx];
+            self.tax[idx] += other.tax[idx];
+        }
+    }
+
+    fn attributes(&self) -> Vec<(String, String)> {
+        (0..3)
+            .filter(|idx| self.base[*idx] != 0.0 || self.tax[*idx] != 0.0)
+            .flat_map(|idx| {
+                [
+                    (format!("zakl_dane{}", idx + 1), amount(self.base[idx])),
+                    (format!("dan{}", idx + 1), amount(self.tax[idx])),
+                ]
+            })
+            .collect()
+    }
+}
+
+fn amount(value: f64) -> String {
+    format!("{:.2}", value)
+}
+
+/// Czech tax id without the country prefix
+fn czech_tax_id(tax: &str) -> Option<&str> {
+    tax.trim().strip_prefix("CZ")
+}
+
+fn empty_element(
+    writer: &mut XmlWriter,
+    name: &str,
+    attributes: &[(String, String)],
+) -> Result<()> {
+    writer
+        .create_element(name)
+        .with_attributes(
+            attributes
+                .iter()
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        )
+        .write_empty()?;
+    Ok(())
+}
+
+fn attribute<V: Into<String>>(name: &str, value: V) -> (String, String) {
+    (name.to_string(), value.into())
+}
+
+fn taxpayer_attributes(invoice: &Invoice, taxpayer: &Taxpayer) -> Result<Vec<(String, String)>> {
+    let issuer = &invoice.issuer;
+    let tax = identification(&issuer.identifications, "tax")
+        .and_then(czech_tax_id)
+        .ok_or_else(|| {
+            anyhow!(get_message(
+                "issuer-without-czech-tax-id",
+                Some(fluent_args!["issuer" => issuer.name.as_str()])
+            ))
+        })?;
+    let address = Address::parse(&issuer.address, &issuer.identifications);
+
+    let mut res = vec![
+        attribute("c_ufo", taxpayer.tax_office.clone().unwrap_or_default()),
+        attribute("dic", tax),
+    ];
+    if taxpayer.legal_person {
+        res.extend([
+            attribute("typ_ds", "P"),
+            attribute("zkrobchjm", issuer.name.as_str()),
+        ]);
+    } else {
+        let (name, surname) = issuer
+            .name
+            .trim()
+            .rsplit_once(' ')
+            .unwrap_or(("", issuer.name.trim()));
+        res.extend([
+            attribute("typ_ds", "F"),
+            attribute("jmeno", name.trim()),
+            attribute("prijmeni", surname),
+        ]);
+    }
+    res.extend([
+        attribute("ulice", address.street.as_str()),
+        attribute("c_pop", address.building_number.as_str()),
+        attribute("naz_obce", address.city.as_str()),
+        attribute("psc", address.postal_zone.replace(' ', "")),
+        attribute("stat", address.country.as_str()),
+        attribute("email", issuer.email.first().cloned().unwrap_or_default()),
+        attribute("c_telef", issuer.phone.first().cloned().unwrap_or_default()),
+    ]);
+    Ok(res)
+}
+
+/// Creates the control statement from the invoices issued in the period
+///
+/// `filed` is the date of filing the statement.
+pub fn export(
+    invoices: &[Invoice],
+    period: Period,
+    taxpayer: &Taxpayer,
+    filed: NaiveDate,
+) -> Result<String> {
+    let last = invoices.last().ok_or_else(|| {
+        anyhow!(get_message(
+            "no-invoices-in-period",
+            Some(fluent_args!["period" => period.to_string()])
+        ))
+    })?;
+
+    let mut listed = vec![];
+    let mut summed = Amounts::default();
+    for invoice in invoices {
+        let amounts = Amounts::from_invoice(invoice)?;
+        if amounts.is_empty() {
+            continue;
+        }
+        if invoice.billing.currency != HOME_CURRENCY {
+            return Err(anyhow!(get_message(
+                "invoice-vat-not-in-currency",
+                Some(fluent_args!["invoice" => invoice.id, "currency" => HOME_CURRENCY])
+            )));
+        }
+        match identification(&invoice.customer.identifications, "tax") {
+            Some(tax) => match czech_tax_id(tax) {
+                Some(tax) if amounts.total() > LIMIT => listed.push((invoice, tax, amounts)),
+                Some(_) => summed.add(&amounts),
+                // foreign customers
+                None => continue,
+            },
+            None => summed.add(&amounts),
+        }
+    }
+
+    let mut turnover = summed;
+    for (_, _, amounts) in &listed {
+        turnover.add(amounts);
+    }
+
+    let mut header = vec![
+        attribute("dokument", "KH1"),
+        attribute("k_uladis", "DPH"),
+        attribute("khdph_forma", "B"),
+    ];
+    match period {
+        Period::Month { year, month } => header.extend([
+            attribute("rok", year.to_string()),
+            attribute("mesic", month.to_string()),
+        ]),
+        Period::Quarter { year, quarter } => header.extend([
+            attribute("rok", year.to_string()),
+            attribute("ctvrt", quarter.to_string()),
+        ]),
+    }
+    header.push(attribute("d_poddp", filed.format(DATE_FORMAT).to_string()));
+    let taxpayer = taxpayer_attributes(last, taxpayer)?;
+
+    let mut writer = xml_writer();
+    writer
+        .create_element("Pisemnost")
+        .with_attribute(("nazevSW", "ucelofka"))
+        .with_attribute(("verzeSW", env!("CARGO_PKG_VERSION")))
+        .write_inner_content(|writer| {
+            writer
+                .create_element("DPHKH1")
+                .with_attribute(("verzePis", FORM_VERSION))
+                .write_inner_content(|writer| {
+                    empty_element(writer, "VetaD", &header)?;
+                    empty_element(writer, "VetaP", &taxpayer)?;
+                    for (idx, (invoice, tax, amounts)) in listed.iter().enumerate() {
+                        let mut attributes = vec![
+                            attribute("c_radku", (idx + 1).to_string()),
+                            attribute("dic_odb", *tax),
+                            attribute("c_evid_dd", invoice.id.to_string()),
+                            attribute(
+                                "dppd",
+                                NaiveDate::parse_from_str(&invoice.issue_date, "%Y-%m-%d")?
+                                    .format(DATE_FORMAT)
+                                    .to_string(),
+                            ),
+                        ];
+                        attributes.extend(amounts.attributes());
+                        attributes
+                            .extend([attribute("kod_rezim_pl", "0"), attribute("zdph_44", "N")]);
+                        empty_element(writer, "VetaA4", &attributes)?;
+                    }
+                    if !summed.is_empty() {
+                        empty_element(writer, "VetaA5", &summed.attributes())?;
+                    }
+                    empty_element(
+                        writer,
+                        "VetaC",
+                        &[
+                            attribute("obrat23", amount(turnover.base[0])),
+                            attribute("obrat5", amount(turnover.base[1] + turnover.base[2])),
+                        ],
+                    )
+                })?;
+            Ok::<_, anyhow::Error>(())
+        })?;
+
+    xml_finish(writer)
+}
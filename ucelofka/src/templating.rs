@@ -0,0 +1,26 @@
+mod filters;
+mod functions;
+mod words;
+
+use minijinja::{path_loader, Environment};
+use std::{path::Path, sync::Arc};
+
+use crate::translations::ProjectTranslations;
+
+/// Creates a jinja environment with ucelofka's custom filters and functions registered
+///
+/// Templates are loaded from the `templates` dir so they can `include` and `extend` each other.
+pub fn environment<'source>(data_path: &Path) -> Environment<'source> {
+    let templates_dir = data_path.join(Path::new("templates"));
+    let translations = Arc::new(ProjectTranslations::new(data_path));
+
+    let mut env = Environment::new();
+    env.set_loader(path_loader(&templates_dir));
+    env.add_filter("money", filters::money);
+    env.add_filter("date", filters::date);
+    env.add_filter("percent", filters::percent);
+    env.add_filter("amount_in_words", filters::amount_in_words);
+    env.add_function("data_uri", functions::data_uri(&templates_dir));
+    env.add_function("t", functions::translate(translations));
+    env
+}
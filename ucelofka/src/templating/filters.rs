@@ -0,0 +1,190 @@
+use chrono::{Datelike, NaiveDate};
+use fluent::fluent_args;
+use minijinja::{Error, ErrorKind, State};
+use std::fmt::Write;
+use unic_langid::LanguageIdentifier;
+
+use crate::translations::{get_locale_message, get_message, resolve_language};
+
+use super::words;
+
+pub(super) fn to_error(err: anyhow::Error) -> Error {
+    Error::new(ErrorKind::InvalidOperation, err.to_string())
+}
+
+/// Picks the locale passed to the filter, the `locale` from the context
+/// or the detected language in this order
+pub(super) fn get_language(
+    state: &State,
+    locale: Option<String>,
+) -> Result<LanguageIdentifier, Error> {
+    let locale = locale.or_else(|| {
+        state
+            .lookup("locale")
+            .and_then(|value| value.as_str().map(String::from))
+    });
+    resolve_language(locale.as_deref()).map_err(to_error)
+}
+
+fn message(lang: &LanguageIdentifier, msgid: &str) -> Result<String, Error> {
+    get_locale_message(lang, msgid, None).map_err(to_error)
+}
+
+fn format_number(value: f64, lang: &LanguageIdentifier) -> Result<String, Error> {
+    let decimal_separator = message(lang, "number-decimal-separator")?;
+    let group_separator = message(lang, "number-group-separator")?;
+
+    let formatted = format!("{:.2}", value.abs());
+    let (integral, fractional) = formatted.split_once('.').unwrap_or((&formatted, "00"));
+
+    let mut grouped = String::new();
+    for (idx, digit) in integral.chars().enumerate() {
+        if idx > 0 && (integral.len() - idx) % 3 == 0 {
+            grouped.push_str(&group_separator);
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if value < 0.0 && formatted != "0.00" {
+        "-"
+    } else {
+        ""
+    };
+
+    Ok(format!(
+        "{}{}{}{}",
+        sign, grouped, decimal_separator, fractional
+    ))
+}
+
+/// Formats the amount with the currency
+///
+/// `{{ billing.total|money(currency, "cs") }}` -> `999,99 US$`
+pub fn money(
+    state: &State,
+    value: f64,
+    currency: Option<String>,
+    locale: Option<String>,
+) -> Result<String, Error> {
+    let lang = get_language(state, locale)?;
+    let currency = currency
+        .or_else(|| {
+            state
+                .lookup("currency")
+                .and_then(|value| value.as_str().map(String::from))
+        })
+        .unwrap_or_default();
+    let amount = format_number(value, &lang)?;
+
+    get_locale_message(
+        &lang,
+        "money",
+        Some(fluent_args!["amount" => amount, "currency" => currency]),
+    )
+    .map_err(to_error)
+}
+
+/// Formats the rate in percent, the decimal places are written only when needed
+///
+/// `{{ item.vat_rate|percent("cs") }}` -> `21 %`
+pub fn percent(state: &State, value: f64, locale: Option<String>) -> Result<String, Error> {
+    let lang = get_language(state, locale)?;
+    let formatted = format!("{:.2}", value);
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    let value = formatted.replace('.', &message(&lang, "number-decimal-separator")?);
+
+    get_locale_message(&lang, "percent", Some(fluent_args!["value" => value])).map_err(to_error)
+}
+
+/// Formats the `YYYY-MM-DD` date using a strftime-like format
+///
+/// Month and weekday names (`%B`, `%b`, `%A`, `%a`) are translated.
+///
+/// `{{ issue_date|date("%-d. %B %Y", "cs") }}` -> `19. ledna 2024`
+pub fn date(
+    state: &State,
+    value: String,
+    format: Option<String>,
+    locale: Option<String>,
+) -> Result<String, Error> {
+    let lang = get_language(state, locale)?;
+    let parsed = NaiveDate::parse_from_str(&value, "%Y-%m-%d").map_err(|err| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            get_message(
+                "invalid-date",
+                Some(fluent_args!["date" => value.as_str(), "error" => err.to_string()]),
+            ),
+        )
+    })?;
+    let format = match format {
+        Some(format) => format,
+        None => message(&lang, "date-format")?,
+    };
+
+    let mut result = String::new();
+    let mut chars = format.chars();
+    while let Some(chr) = chars.next() {
+        if chr != '%' {
+            result.push(chr);
+            continue;
+        }
+        let mut spec = String::from("%");
+        for next in chars.by_ref() {
+            spec.push(next);
+            if !"-_0".contains(next) {
+                break;
+            }
+        }
+        match spec.as_str() {
+            "%B" => result += &message(&lang, &format!("date-month-{}", parsed.month()))?,
+            "%b" => result += &message(&lang, &format!("date-month-short-{}", parsed.month()))?,
+            "%A" => {
+                result += &message(
+                    &lang,
+                    &format!("date-weekday-{}", parsed.weekday().number_from_monday()),
+                )?
+            }
+            "%a" => {
+                result += &message(
+                    &lang,
+                    &format!(
+                        "date-weekday-short-{}",
+                        parsed.weekday().number_from_monday()
+                    ),
+                )?
+            }
+            "%%" => result.push('%'),
+            spec => {
+                write!(result, "{}", parsed.format(spec)).map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidOperation,
+                        get_message(
+                            "template-date-format-invalid",
+                            Some(fluent_args!["format" => spec]),
+                        ),
+                    )
+                })?;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Writes the amount in words
+///
+/// `{{ billing.total|amount_in_words("en") }}` -> `nine hundred ninety-nine and 99/100`
+pub fn amount_in_words(state: &State, value: f64, locale: Option<String>) -> Result<String, Error> {
+    let lang = get_language(state, locale)?;
+    let cents_total = (value.abs() * 100.0).round() as u64;
+    let words = words::number_to_words(cents_total / 100, &lang);
+    let cents = format!("{:02}", cents_total % 100);
+
+    get_locale_message(
+        &lang,
+        "amount-in-words",
+        Some(fluent_args!["words" => words, "cents" => cents]),
+    )
+    .map_err(to_error)
+}
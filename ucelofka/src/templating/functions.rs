@@ -0,0 +1,103 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use fluent::fluent_args;
+use fluent_bundle::{FluentArgs, FluentValue};
+use minijinja::{value::Kwargs, Error, ErrorKind, State, Value};
+use std::{
+    convert::TryFrom,
+    fs,
+    path::{Component, Path},
+    sync::Arc,
+};
+
+use crate::translations::{get_message, ProjectTranslations};
+
+use super::filters::{get_language, to_error};
+
+fn mime_type(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .as_deref()
+    {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        Some("webp") => "image/webp",
+        Some("css") => "text/css",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Inlines a file from the templates directory as a data URI
+///
+/// `<img src="{{ data_uri("assets/logo.png") }}">`
+pub fn data_uri(
+    templates_dir: &Path,
+) -> impl Fn(String) -> Result<Value, Error> + Send + Sync + 'static {
+    let templates_dir = templates_dir.to_path_buf();
+    move |name: String| {
+        let relative = Path::new(&name);
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                get_message(
+                    "template-path-outside",
+                    Some(fluent_args!["path" => name.as_str()]),
+                ),
+            ));
+        }
+        let path = templates_dir.join(relative);
+        let data = fs::read(&path).map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                get_message(
+                    "file-read-failed",
+                    Some(fluent_args![
+                        "path" => path.to_string_lossy(),
+                        "error" => err.to_string()
+                    ]),
+                ),
+            )
+        })?;
+        // the uri is safe to be used as an attribute value
+        Ok(Value::from_safe_string(format!(
+            "data:{};base64,{}",
+            mime_type(&path),
+            STANDARD.encode(data)
+        )))
+    }
+}
+
+/// Translates a message using the project's translations (`locales/<lang>.ftl`)
+///
+/// Keyword arguments are passed to the message, when the message is not found
+/// its id is returned.
+///
+/// `{{ t("due-in", days=15) }}`
+pub fn translate(
+    translations: Arc<ProjectTranslations>,
+) -> impl Fn(&State, String, Kwargs) -> Result<String, Error> + Send + Sync + 'static {
+    move |state: &State, msgid: String, kwargs: Kwargs| {
+        let lang = get_language(state, None)?;
+
+        let mut args = FluentArgs::new();
+        for key in kwargs.args() {
+            let value: Value = kwargs.get(key)?;
+            if value.is_number() {
+                args.set(key.to_string(), FluentValue::from(f64::try_from(value)?));
+            } else {
+                args.set(key.to_string(), FluentValue::from(value.to_string()));
+            }
+        }
+
+        Ok(translations
+            .get_message(&lang, &msgid, Some(args))
+            .map_err(to_error)?
+            .unwrap_or(msgid))
+    }
+}
@@ -0,0 +1,201 @@
+use unic_langid::LanguageIdentifier;
+
+const EN_ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const EN_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const EN_SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+const CS_ONES: [&str; 20] = [
+    "nula",
+    "jedna",
+    "dva",
+    "tři",
+    "čtyři",
+    "pět",
+    "šest",
+    "sedm",
+    "osm",
+    "devět",
+    "deset",
+    "jedenáct",
+    "dvanáct",
+    "třináct",
+    "čtrnáct",
+    "patnáct",
+    "šestnáct",
+    "sedmnáct",
+    "osmnáct",
+    "devatenáct",
+];
+const CS_TENS: [&str; 10] = [
+    "",
+    "",
+    "dvacet",
+    "třicet",
+    "čtyřicet",
+    "padesát",
+    "šedesát",
+    "sedmdesát",
+    "osmdesát",
+    "devadesát",
+];
+const CS_HUNDREDS: [&str; 10] = [
+    "",
+    "sto",
+    "dvě stě",
+    "tři sta",
+    "čtyři sta",
+    "pět set",
+    "šest set",
+    "sedm set",
+    "osm set",
+    "devět set",
+];
+// (one, two to four, five and more)
+const CS_SCALES: [(&str, &str, &str); 7] = [
+    ("", "", ""),
+    ("tisíc", "tisíce", "tisíc"),
+    ("milion", "miliony", "milionů"),
+    ("miliarda", "miliardy", "miliard"),
+    ("bilion", "biliony", "bilionů"),
+    ("biliarda", "biliardy", "biliard"),
+    ("trilion", "triliony", "trilionů"),
+];
+
+fn en_below_thousand(number: u64) -> Vec<String> {
+    let mut words = vec![];
+    let hundreds = number / 100;
+    let rest = number % 100;
+    if hundreds > 0 {
+        words.push(format!("{} hundred", EN_ONES[hundreds as usize]));
+    }
+    if rest >= 20 {
+        let tens = EN_TENS[(rest / 10) as usize];
+        if rest % 10 > 0 {
+            words.push(format!("{}-{}", tens, EN_ONES[(rest % 10) as usize]));
+        } else {
+            words.push(tens.to_string());
+        }
+    } else if rest > 0 {
+        words.push(EN_ONES[rest as usize].to_string());
+    }
+    words
+}
+
+fn cs_below_thousand(number: u64) -> Vec<String> {
+    let mut words = vec![];
+    let hundreds = number / 100;
+    let rest = number % 100;
+    if hundreds > 0 {
+        words.push(CS_HUNDREDS[hundreds as usize].to_string());
+    }
+    if rest >= 20 {
+        words.push(CS_TENS[(rest / 10) as usize].to_string());
+        if rest % 10 > 0 {
+            words.push(CS_ONES[(rest % 10) as usize].to_string());
+        }
+    } else if rest > 0 {
+        words.push(CS_ONES[rest as usize].to_string());
+    }
+    words
+}
+
+/// Splits the number into groups of three digits (lowest first)
+fn groups(mut number: u64) -> Vec<u64> {
+    let mut res = vec![];
+    while number > 0 {
+        res.push(number % 1000);
+        number /= 1000;
+    }
+    res
+}
+
+fn en(number: u64) -> String {
+    if number == 0 {
+        return EN_ONES[0].to_string();
+    }
+    let mut words = vec![];
+    for (scale, group) in groups(number).into_iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        words.extend(en_below_thousand(group));
+        if scale > 0 {
+            words.push(EN_SCALES[scale].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+fn cs(number: u64) -> String {
+    if number == 0 {
+        return CS_ONES[0].to_string();
+    }
+    let mut words = vec![];
+    for (scale, group) in groups(number).into_iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        if scale == 0 {
+            words.extend(cs_below_thousand(group));
+            continue;
+        }
+        let (one, few, many) = CS_SCALES[scale];
+        match group {
+            1 => words.push(one.to_string()),
+            2..=4 => {
+                let count = if group == 2 && scale % 2 == 1 && scale > 1 {
+                    "dvě"
+                } else {
+                    CS_ONES[group as usize]
+                };
+                words.push(format!("{} {}", count, few));
+            }
+            _ => {
+                words.extend(cs_below_thousand(group));
+                words.push(many.to_string());
+            }
+        }
+    }
+    words.join(" ")
+}
+
+/// Writes an integer number in words in the given language
+///
+/// Languages without their own implementation fall back to English.
+pub fn number_to_words(number: u64, lang: &LanguageIdentifier) -> String {
+    match lang.language.as_str() {
+        "cs" => cs(number),
+        _ => en(number),
+    }
+}
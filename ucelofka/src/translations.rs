@@ -1,20 +1,28 @@
 use anyhow::{anyhow, Result};
+use fluent::fluent_args;
 use fluent_bundle::{bundle::FluentBundle, FluentArgs, FluentResource};
 use fluent_langneg::{negotiate_languages, NegotiationStrategy};
 use include_dir::{include_dir, Dir, DirEntry};
 use intl_memoizer::concurrent::IntlLangMemoizer;
 use lazy_static::lazy_static;
 use std::{
-    env,
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
     str::{from_utf8, FromStr},
+    sync::{Arc, Mutex, RwLock},
 };
 use unic_langid::{langid, LanguageIdentifier};
 
-const DEFAULT_LANG_STR: &str = "en-US";
 const DEFAULT_LANG: LanguageIdentifier = langid!("en-US");
 
+/// Directory of the data dir with the overrides of the program's messages (`<lang>.ftl`)
+pub const MESSAGES_DIR: &str = "messages";
+
 static RESOURCES: Dir = include_dir!("resources/");
 
+type Bundle = FluentBundle<FluentResource, IntlLangMemoizer>;
+
 fn get_available_locales() -> Result<Vec<LanguageIdentifier>> {
     RESOURCES
         .find("*")
@@ -39,73 +47,295 @@ fn read_language_data(id: &LanguageIdentifier) -> Result<String> {
     Ok(from_utf8(file.contents)?.to_string())
 }
 
-fn detect_language() -> Result<LanguageIdentifier> {
-    let lang_str = env::var("LANG")
-        .unwrap_or_else(|_| DEFAULT_LANG_STR.to_string())
-        .replace(".utf8", "")
-        .replace(".UTF8", "")
-        .replace(".utf-8", "")
-        .replace(".UTF-8", "");
-    let lang = LanguageIdentifier::from_str(&lang_str)?;
-    Ok(lang)
+/// Parses a POSIX locale (e.g. `cs_CZ.UTF-8@euro`), `C` and `POSIX` have no language
+fn parse_posix_locale(locale: &str) -> Option<LanguageIdentifier> {
+    let name = locale.split(['.', '@']).next()?.trim();
+    if name.is_empty() || name == "C" || name == "POSIX" {
+        return None;
+    }
+    LanguageIdentifier::from_str(&name.replace('_', "-")).ok()
 }
 
-fn get_bundle() -> Result<FluentBundle<FluentResource, IntlLangMemoizer>> {
-    let available = get_available_locales()?;
-    let mut requested = vec![DEFAULT_LANG];
+/// Languages of the environment, the most preferred first
+///
+/// The locale is taken from the first set of `LC_ALL`, `LC_MESSAGES` and `LANG`.
+/// Unless it is `C` it is preceded by the colon separated list in `LANGUAGE` (as in gettext).
+pub fn detect_languages() -> Vec<LanguageIdentifier> {
+    let var = |name| {
+        env::var(name)
+            .ok()
+            .filter(|value: &String| !value.is_empty())
+    };
+    let Some(lang) = var("LC_ALL")
+        .or_else(|| var("LC_MESSAGES"))
+        .or_else(|| var("LANG"))
+        .and_then(|locale| parse_posix_locale(&locale))
+    else {
+        return vec![];
+    };
+    let mut res: Vec<LanguageIdentifier> = var("LANGUAGE")
+        .map(|list| list.split(':').filter_map(parse_posix_locale).collect())
+        .unwrap_or_default();
+    res.push(lang);
+    res
+}
 
-    if let Ok(langid) = detect_language() {
-        requested.push(langid);
-    }
+/// Languages of the program, the most preferred first
+fn languages() -> Vec<LanguageIdentifier> {
+    LANGUAGES
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(detect_languages)
+}
+
+/// Parses a catalog, the valid messages of a partially broken one are still used
+fn parse_resource(data: String) -> FluentResource {
+    FluentResource::try_new(data).unwrap_or_else(|(resource, _)| resource)
+}
+
+fn make_bundle(
+    requested: &[LanguageIdentifier],
+) -> Result<FluentBundle<FluentResource, IntlLangMemoizer>> {
+    let available = get_available_locales()?;
 
     let resolved = negotiate_languages(
-        &requested,
+        requested,
         &available,
         Some(&DEFAULT_LANG),
         NegotiationStrategy::Filtering,
     )
     .drain(..)
-    .map(Clone::clone)
+    .cloned()
     .collect::<Vec<LanguageIdentifier>>();
 
-    let mut bundle = FluentBundle::new_concurrent(resolved.clone());
+    // the most preferred language goes first as it determines e.g. the plural rules
+    let mut bundle = FluentBundle::new_concurrent(resolved.iter().rev().cloned().collect());
     for lang in resolved {
         let data = read_language_data(&lang)?;
-        let resource = FluentResource::try_new(data)
-            .map_err(|_| anyhow!("Failed to parse flt file for language {}", lang))?;
-        bundle.add_resource_overriding(resource);
+        bundle.add_resource_overriding(parse_resource(data));
     }
 
     Ok(bundle)
 }
 
-pub fn get_message(msgid: &str, args: Option<FluentArgs>) -> String {
+/// Bundle with the messages of the program
+///
+/// The messages of the less preferred languages are overridden by the more preferred ones
+/// and finally by the `MESSAGES_DIR` files of the data directory.
+fn get_bundle(data_path: Option<&Path>) -> Bundle {
+    let mut requested = vec![DEFAULT_LANG];
+    requested.extend(languages().into_iter().rev());
+
+    let mut bundle = make_bundle(&requested)
+        .or_else(|_| make_bundle(&[DEFAULT_LANG]))
+        .unwrap_or_else(|_| FluentBundle::new_concurrent(vec![DEFAULT_LANG]));
+    if let Some(messages_path) = data_path.map(|path| path.join(MESSAGES_DIR)) {
+        for lang in &requested {
+            let mut names = vec![lang.language.to_string(), lang.to_string()];
+            names.dedup();
+            for name in names {
+                if let Ok(data) = fs::read_to_string(messages_path.join(format!("{}.ftl", name))) {
+                    bundle.add_resource_overriding(parse_resource(data));
+                }
+            }
+        }
+    }
+    // the messages are printed to the terminal where the isolation marks are not rendered
+    bundle.set_use_isolating(false);
+    bundle
+}
+
+/// Selects the language of the program and loads its messages
+///
+/// `lang` takes precedence over the languages of the environment.
+/// Replaces the messages formatted so far (e.g. while parsing the command line).
+pub fn init(lang: Option<&str>, data_path: Option<&Path>) -> Result<()> {
+    let (languages, res) = match lang.map(LanguageIdentifier::from_str) {
+        Some(Ok(lang)) => (vec![lang], Ok(())),
+        Some(Err(err)) => (detect_languages(), Err(err)),
+        None => (detect_languages(), Ok(())),
+    };
+    *LANGUAGES.write().unwrap() = Some(languages);
+    *BUNDLE.write().unwrap() = Some(Arc::new(get_bundle(data_path)));
+    res.map_err(|err| {
+        anyhow!(get_message(
+            "invalid-locale",
+            Some(fluent_args!["locale" => lang.unwrap_or_default(), "error" => err.to_string()])
+        ))
+    })
+}
+
+/// Returns a bundle for a single locale (falling back to the default one)
+///
+/// Unlike the global bundle, these are meant to format data (e.g. in templates)
+/// so the unicode isolation marks are not used.
+fn get_locale_bundle(lang: &LanguageIdentifier) -> Result<Arc<Bundle>> {
+    let mut bundles = LOCALE_BUNDLES.lock().unwrap();
+    if let Some(bundle) = bundles.get(lang) {
+        return Ok(bundle.clone());
+    }
+
+    let mut bundle = make_bundle(&[DEFAULT_LANG, lang.clone()])?;
+    bundle.set_use_isolating(false);
+    let bundle = Arc::new(bundle);
+    bundles.insert(lang.clone(), bundle.clone());
+    Ok(bundle)
+}
+
+/// Parses the locale string, falls back to the detected language
+pub fn resolve_language(locale: Option<&str>) -> Result<LanguageIdentifier> {
+    match locale {
+        Some(locale) => LanguageIdentifier::from_str(locale).map_err(|err| {
+            anyhow!(get_message(
+                "invalid-locale",
+                Some(fluent_args!["locale" => locale, "error" => err.to_string()])
+            ))
+        }),
+        None => Ok(languages().first().cloned().unwrap_or(DEFAULT_LANG)),
+    }
+}
+
+/// Formats a message for the given locale
+pub fn get_locale_message(
+    lang: &LanguageIdentifier,
+    msgid: &str,
+    args: Option<FluentArgs>,
+) -> Result<String> {
+    let bundle = get_locale_bundle(lang)?;
     let mut errors = vec![];
-    let msg = BUNDLE
+    let msg = bundle
         .get_message(msgid)
-        .unwrap_or_else(|| panic!("Message `{}` was not found.", msgid));
+        .ok_or_else(|| anyhow!("Message `{}` was not found.", msgid))?;
     let pattern = msg
         .value()
-        .unwrap_or_else(|| panic!("Message `{}` has no value.", msgid));
-    BUNDLE
+        .ok_or_else(|| anyhow!("Message `{}` has no value.", msgid))?;
+    Ok(bundle
         .format_pattern(pattern, args.as_ref(), &mut errors)
-        .into()
+        .into())
 }
 
-lazy_static! {
-    static ref BUNDLE: FluentBundle<FluentResource, IntlLangMemoizer> = {
-        match get_bundle() {
-            Err(err) => panic!("failed to load translations: {}", err),
-            Ok(bundle) => bundle,
+/// Translations stored within the data directory (`locales/<lang>.ftl`)
+///
+/// These are meant to be used within the templates.
+pub struct ProjectTranslations {
+    dir: PathBuf,
+    bundles: Mutex<HashMap<LanguageIdentifier, Arc<Bundle>>>,
+}
+
+impl ProjectTranslations {
+    pub fn new(data_path: &Path) -> Self {
+        Self {
+            dir: data_path.join(Path::new("locales")),
+            bundles: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn available_locales(&self) -> Result<Vec<(LanguageIdentifier, PathBuf)>> {
+        if !self.dir.is_dir() {
+            return Ok(vec![]);
+        }
+        let mut res = vec![];
+        for entry in self.dir.read_dir()? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("ftl") {
+                continue;
+            }
+            if let Some(Ok(lang)) = path
+                .file_stem()
+                .and_then(|e| e.to_str())
+                .map(LanguageIdentifier::from_str)
+            {
+                res.push((lang, path));
+            }
+        }
+        Ok(res)
+    }
+
+    fn get_bundle(&self, lang: &LanguageIdentifier) -> Result<Arc<Bundle>> {
+        let mut bundles = self.bundles.lock().unwrap();
+        if let Some(bundle) = bundles.get(lang) {
+            return Ok(bundle.clone());
+        }
+
+        let available = self.available_locales()?;
+        let available_ids: Vec<LanguageIdentifier> =
+            available.iter().map(|(lang, _)| lang.clone()).collect();
+        let resolved = negotiate_languages(
+            &[DEFAULT_LANG, lang.clone()],
+            &available_ids,
+            Some(&DEFAULT_LANG),
+            NegotiationStrategy::Filtering,
+        )
+        .drain(..)
+        .cloned()
+        .collect::<Vec<LanguageIdentifier>>();
+
+        let mut bundle = FluentBundle::new_concurrent(resolved.clone());
+        bundle.set_use_isolating(false);
+        for resolved_lang in resolved {
+            if let Some((_, path)) = available.iter().find(|(lang, _)| lang == &resolved_lang) {
+                let data = fs::read_to_string(path)?;
+                let resource = FluentResource::try_new(data)
+                    .map_err(|_| anyhow!("Failed to parse ftl file {}", path.to_string_lossy()))?;
+                bundle.add_resource_overriding(resource);
+            }
         }
+
+        let bundle = Arc::new(bundle);
+        bundles.insert(lang.clone(), bundle.clone());
+        Ok(bundle)
+    }
+
+    /// Formats a message, returns `None` when the message is not present
+    pub fn get_message(
+        &self,
+        lang: &LanguageIdentifier,
+        msgid: &str,
+        args: Option<FluentArgs>,
+    ) -> Result<Option<String>> {
+        let bundle = self.get_bundle(lang)?;
+        let pattern = match bundle.get_message(msgid).and_then(|msg| msg.value()) {
+            Some(pattern) => pattern,
+            None => return Ok(None),
+        };
+        let mut errors = vec![];
+        Ok(Some(
+            bundle
+                .format_pattern(pattern, args.as_ref(), &mut errors)
+                .into(),
+        ))
+    }
+}
+
+fn current_bundle() -> Arc<Bundle> {
+    if let Some(bundle) = BUNDLE.read().unwrap().as_ref() {
+        return bundle.clone();
+    }
+    BUNDLE
+        .write()
+        .unwrap()
+        .get_or_insert_with(|| Arc::new(get_bundle(None)))
+        .clone()
+}
+
+/// Formats a message of the program, the message id is returned when the message is missing
+pub fn get_message(msgid: &str, args: Option<FluentArgs>) -> String {
+    let bundle = current_bundle();
+    let Some(pattern) = bundle.get_message(msgid).and_then(|msg| msg.value()) else {
+        return msgid.to_string();
     };
+    let mut errors = vec![];
+    bundle
+        .format_pattern(pattern, args.as_ref(), &mut errors)
+        .into()
 }
 
-pub mod texts {
-    use super::get_message;
-    use lazy_static::lazy_static;
+// the language can be changed by `init` once the command line is parsed
+static LANGUAGES: RwLock<Option<Vec<LanguageIdentifier>>> = RwLock::new(None);
+static BUNDLE: RwLock<Option<Arc<Bundle>>> = RwLock::new(None);
 
-    lazy_static! {
-        pub static ref DATA_DIRECTORY_PATH: String = get_message("data-directory-path", None);
-    }
+lazy_static! {
+    static ref LOCALE_BUNDLES: Mutex<HashMap<LanguageIdentifier, Arc<FluentBundle<FluentResource, IntlLangMemoizer>>>> =
+        Mutex::new(HashMap::new());
 }
@@ -3,10 +3,36 @@
 pub mod account;
 pub mod app;
 pub mod customer;
+pub mod dashboard;
 pub mod entry;
+pub mod form;
+pub mod help;
 pub mod identity;
 pub mod invoice;
 pub mod list;
+pub mod status;
 pub mod table;
+pub mod template;
 
 pub use app::{App, AppProps};
+
+use crossterm::{cursor, event::DisableMouseCapture, execute, terminal};
+use std::{io, panic};
+
+/// Restores the terminal before the panic message is printed
+///
+/// Errors of the TUI itself are turned into panics as well, so the terminal
+/// is not left in the raw mode with the message hidden in the alternate screen.
+pub fn restore_terminal_on_panic() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = terminal::disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            terminal::LeaveAlternateScreen,
+            DisableMouseCapture,
+            cursor::Show
+        );
+        hook(info);
+    }));
+}
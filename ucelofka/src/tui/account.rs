@@ -1,81 +1,206 @@
 #![allow(non_snake_case, deprecated)]
 
-use dioxus::prelude::*;
-use std::rc::Rc;
-use ucelofka_data::account::Account;
+use anyhow::Result;
+use dioxus::{
+    events::{KeyCode, KeyboardEvent},
+    prelude::*,
+};
+use fluent::fluent_args;
+use std::{path::Path, rc::Rc};
+use ucelofka_data::account::{Account, Accounts};
 
-use crate::actions::account::list;
+use crate::{
+    actions::{account::list, records},
+    storage::Records,
+    tabular::Row,
+    translations::get_message,
+};
 
-use super::{app::UcelofkaTuiCfg, list::List, table::Table};
+use super::{
+    app::{pressed, UcelofkaTuiCfg},
+    form::{self, Confirm, Field, Form, Mode},
+    list::List,
+    status::{self, Errors},
+    table::Table,
+};
 
 struct CurrentAccountPage(usize);
 
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("id", get_message("tui-id", None)),
+        Field::new("name", get_message("tui-name", None)),
+        Field::new("bank_name", get_message("tui-bank-name", None)),
+        Field::new("account_name", get_message("tui-account-name", None)),
+        Field::new("account_number", get_message("tui-account-number", None)),
+        Field::new("IBAN", get_message("tui-iban", None)),
+        Field::new("BIC", get_message("tui-bic", None)),
+        Field::new("currency", get_message("tui-currency", None)),
+    ]
+}
+
+fn save(data_path: &Path, id: Option<&str>, row: Row, git: bool) -> Result<String> {
+    form::save::<Account, Accounts>(data_path, "accounts", id, row, git)
+}
+
+fn delete(data_path: &Path, id: &str, git: bool) -> Result<()> {
+    records::delete::<Account, Accounts>(data_path, "accounts", id, git)
+}
+
 #[derive(Clone)]
 enum SubPage {
     Create,
     Account(Account),
 }
 
-impl AsRef<str> for SubPage {
-    fn as_ref(&self) -> &str {
+impl SubPage {
+    fn name(&self) -> String {
         match self {
-            Self::Create => "<Create>",
-            Self::Account(account) => account.name.as_str(),
+            Self::Create => get_message("tui-create", None),
+            Self::Account(account) => account.name.clone(),
         }
     }
 }
 
 #[inline_props]
-pub fn Accounts(cx: Scope) -> Element {
+pub fn Accounts(cx: Scope) -> Element<'a> {
     use_shared_state_provider(cx, || CurrentAccountPage(0));
 
     let account_page = use_shared_state::<CurrentAccountPage>(cx).unwrap();
+    let mode = use_state(cx, || Mode::View);
+    let error = use_state(cx, || None::<String>);
 
     let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
-    let accounts = list(cfg.read().path.as_path()).unwrap().accounts;
+    let mut errors = Vec::new();
+    let accounts =
+        status::load::<Account, Accounts>(cfg.read().path.as_path(), "accounts", &mut errors)
+            .accounts;
     let items: Vec<SubPage> = vec![SubPage::Create]
         .into_iter()
         .chain(accounts.iter().map(|e| SubPage::Account(e.clone())))
         .collect();
 
-    let items_str: Vec<String> = items.iter().map(|e| e.as_ref().to_string()).collect();
+    let items_str: Vec<String> = items.iter().map(SubPage::name).collect();
     let selected_idx = account_page.read().0;
-    let selected_account = if selected_idx == 0 {
-        None
-    } else {
-        Some(accounts[selected_idx - 1].clone())
+    // the record might have been removed outside of the TUI
+    let selected_account = selected_idx
+        .checked_sub(1)
+        .and_then(|idx| accounts.get(idx).cloned());
+
+    let selected_id = selected_account.as_ref().map(|e| e.id.clone());
+
+    let onsaved = move |id: String| {
+        // select the saved record
+        let ids = list(cfg.read().path.as_path())
+            .map(|e| e.ids())
+            .unwrap_or_default();
+        if let Some(idx) = ids.iter().position(|e| *e == id) {
+            account_page.write().0 = idx + 1;
+        }
+        mode.set(Mode::View);
+    };
+
+    let keys = cfg.read().keys.clone();
+    let hint = get_message(
+        "tui-record-hint",
+        Some(fluent_args![
+            "edit" => keys.edit.as_str(),
+            "delete" => keys.delete.as_str(),
+            "search" => keys.search.as_str()
+        ]),
+    );
+
+    let onkeydown = move |k: KeyboardEvent| {
+        let Some(id) = selected_id.as_ref() else {
+            return;
+        };
+        match *mode.get() {
+            Mode::View if pressed(&k, &keys.edit) => mode.set(Mode::Edit),
+            Mode::View if pressed(&k, &keys.delete) => mode.set(Mode::Delete),
+            Mode::Delete if pressed(&k, &keys.confirm) => {
+                let cfg = cfg.read();
+                match delete(cfg.path.as_path(), id, cfg.git) {
+                    Ok(()) => {
+                        account_page.write().0 = selected_idx - 1;
+                        mode.set(Mode::View);
+                        error.set(None);
+                    }
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            }
+            Mode::Delete if pressed(&k, &keys.cancel) => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ if k.key_code == KeyCode::Escape => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ => {}
+        }
     };
 
     cx.render(rsx! {
         List {
             tabindex: 0,
-            width: "20%",
+            width: "25%",
             items: Rc::new(items_str),
             dot: "❱",
             idx: account_page.read().0,
             onindexupdate: move |i: usize| {
                 account_page.write().0 = i;
-            }
+                mode.set(Mode::View);
+                error.set(None);
+            },
+            onkeydown: onkeydown,
         }
-        div { width: "60%", border_width: "1px", height: "100%", justify_content: "center",
-            if let Some(account) = selected_account {
-                rsx! {
-                    Table {
-                        width: "100%",
-                        items: vec![
-                            ("Name:", account.name),
-                            ("Bank Name:", account.bank_name),
-                            ("Account Name:", account.account_name),
-                            ("Account number:", account.account_number),
-                            ("IBAN:", account.IBAN),
-                            ("BIC:", account.BIC),
-                            ("Currency:", account.currency),
-
-                        ]
+        div { width: "75%", border_width: "1px", height: "100%", flex_direction: "column",
+            Errors { errors: errors }
+            match (selected_account, *mode.get()) {
+                (Some(account), Mode::View) => rsx! {
+                    div { width: "100%", flex_direction: "column",
+                        Table {
+                            width: "100%",
+                            items: vec![
+                                (get_message("tui-name", None), account.name),
+                                (get_message("tui-bank-name", None), account.bank_name),
+                                (get_message("tui-account-name", None), account.account_name),
+                                (get_message("tui-account-number", None), account.account_number),
+                                (get_message("tui-iban", None), account.IBAN),
+                                (get_message("tui-bic", None), account.BIC),
+                                (get_message("tui-currency", None), account.currency),
+                            ]
+                        }
+                        span { "{hint}" }
                     }
-            }
-            } else {
-                rsx! {span { "TODO CREATE FORM"}}
+                },
+                (Some(account), Mode::Edit) => rsx! {
+                    Form {
+                        key: "{account.id}",
+                        title: get_message("tui-edit-account", None),
+                        fields: fields(),
+                        id: account.id.clone(),
+                        initial: form::values(&account, &fields()),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| mode.set(Mode::View),
+                    }
+                },
+                (Some(account), Mode::Delete) => rsx! {
+                    Confirm {
+                        name: "{account.name}",
+                        error: error.get().as_deref().unwrap_or_default(),
+                    }
+                },
+                (None, _) => rsx! {
+                    Form {
+                        title: get_message("tui-new-account", None),
+                        fields: fields(),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| {},
+                    }
+                },
             }
         }
     })
@@ -2,38 +2,89 @@
 
 use dioxus::{
     events::{KeyCode, KeyboardEvent},
+    html::input_data::keyboard_types::Key,
     prelude::*,
 };
 use dioxus_tui::TuiContext;
 use std::{path::PathBuf, rc::Rc};
+use tokio::sync::mpsc;
+
+use crate::{config::Keys, translations::get_message, watch};
 
 use super::{
-    account::Accounts, customer::Customers, entry::Entries, identity::Identities,
-    invoice::Invoices, list::List,
+    account::Accounts, customer::Customers, dashboard::Dashboard, entry::Entries, help::Help,
+    identity::Identities, invoice::Invoices, list::List, template::Templates,
 };
 
 struct CurrentPage(Page);
 
 pub struct UcelofkaTuiCfg {
     pub path: PathBuf,
+    /// Add the changed records to git
+    pub git: bool,
+    pub keys: Keys,
+}
+
+/// Checks whether the configured key was pressed
+pub fn pressed(k: &KeyboardEvent, key: &str) -> bool {
+    k.key() == Key::Character(key.to_string())
+}
+
+/// State of the keys which work on all the pages
+pub struct Global {
+    pub help: bool,
+    /// List of the pages which can be focused
+    pub pages: Option<Rc<MountedData>>,
+}
+
+/// Handles the keys which work on all the pages
+///
+/// The focused lists pass the keys which they don't use here,
+/// because the events are not passed to the parent components.
+pub fn global_key(
+    cx: &ScopeState,
+    k: &KeyboardEvent,
+    global: &UseSharedState<Global>,
+    keys: &Keys,
+) {
+    if pressed(k, &keys.quit) {
+        if let Some(tui_ctx) = cx.consume_context::<TuiContext>() {
+            tui_ctx.quit();
+        }
+    } else if pressed(k, &keys.help) {
+        let help = global.read().help;
+        global.write().help = !help;
+    } else if pressed(k, &keys.pages) {
+        if let Some(pages) = global.read().pages.clone() {
+            cx.spawn(async move {
+                let _ = pages.set_focus(true).await;
+            });
+        }
+    } else if k.key_code == KeyCode::Escape && global.read().help {
+        global.write().help = false;
+    }
 }
 
-#[derive(Clone, Copy)]
-enum Page {
+#[derive(Clone, Copy, PartialEq)]
+pub enum Page {
+    Dashboard,
     Accounts,
     Customers,
     Entries,
     Identities,
     Invoices,
+    Templates,
 }
-impl AsRef<str> for Page {
-    fn as_ref(&self) -> &str {
+impl Page {
+    pub fn name(&self) -> String {
         match self {
-            Self::Accounts => "Accounts",
-            Self::Customers => "Customers",
-            Self::Entries => "Entries",
-            Self::Identities => "Identities",
-            Self::Invoices => "Invoices",
+            Self::Dashboard => get_message("tui-page-dashboard", None),
+            Self::Accounts => get_message("tui-page-accounts", None),
+            Self::Customers => get_message("tui-page-customers", None),
+            Self::Entries => get_message("tui-page-entries", None),
+            Self::Identities => get_message("tui-page-identities", None),
+            Self::Invoices => get_message("tui-page-invoices", None),
+            Self::Templates => get_message("tui-page-templates", None),
         }
     }
 }
@@ -41,11 +92,13 @@ impl AsRef<str> for Page {
 impl From<usize> for Page {
     fn from(page: usize) -> Self {
         match page {
-            0 => Self::Accounts,
-            1 => Self::Customers,
-            2 => Self::Entries,
-            3 => Self::Identities,
-            4 => Self::Invoices,
+            0 => Self::Dashboard,
+            1 => Self::Accounts,
+            2 => Self::Customers,
+            3 => Self::Entries,
+            4 => Self::Identities,
+            5 => Self::Invoices,
+            6 => Self::Templates,
             _ => unreachable!(),
         }
     }
@@ -54,51 +107,72 @@ impl From<usize> for Page {
 impl From<Page> for usize {
     fn from(val: Page) -> Self {
         match val {
-            Page::Accounts => 0,
-            Page::Customers => 1,
-            Page::Entries => 2,
-            Page::Identities => 3,
-            Page::Invoices => 4,
+            Page::Dashboard => 0,
+            Page::Accounts => 1,
+            Page::Customers => 2,
+            Page::Entries => 3,
+            Page::Identities => 4,
+            Page::Invoices => 5,
+            Page::Templates => 6,
         }
     }
 }
 
 #[inline_props]
-pub fn App(cx: Scope, path: PathBuf) -> Element {
-    let tui_ctx: TuiContext = cx.consume_context().unwrap();
+pub fn App(cx: Scope, path: PathBuf, git: bool, keys: Keys) -> Element<'a> {
+    use_shared_state_provider(cx, || CurrentPage(Page::Dashboard));
+    use_shared_state_provider(cx, || UcelofkaTuiCfg {
+        path: path.clone(),
+        git: *git,
+        keys: keys.clone(),
+    });
 
-    use_shared_state_provider(cx, || CurrentPage(Page::Accounts));
-    use_shared_state_provider(cx, || UcelofkaTuiCfg { path: path.clone() });
+    use_shared_state_provider(cx, || Global {
+        help: false,
+        pages: None,
+    });
 
     let page = use_shared_state::<CurrentPage>(cx).unwrap();
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let global = use_shared_state::<Global>(cx).unwrap();
+    let help = global.read().help;
+
+    // pages read the records on each render so they only need to be redrawn
+    // when the data are changed outside of the TUI
+    use_future(cx, (), |_| {
+        let cfg = cfg.clone();
+        async move {
+            let (tx, mut rx) = mpsc::unbounded_channel();
+            let path = cfg.read().path.clone();
+            let Ok(_watcher) = watch::watch(&path, move |_| {
+                let _ = tx.send(());
+            }) else {
+                return;
+            };
+            while rx.recv().await.is_some() {
+                // several files are usually changed at once
+                while rx.try_recv().is_ok() {}
+                cfg.notify_consumers();
+            }
+        }
+    });
 
     let items = Rc::new(vec![
-        Page::Accounts.as_ref(),
-        Page::Customers.as_ref(),
-        Page::Entries.as_ref(),
-        Page::Identities.as_ref(),
-        Page::Invoices.as_ref(),
+        Page::Dashboard.name(),
+        Page::Accounts.name(),
+        Page::Customers.name(),
+        Page::Entries.name(),
+        Page::Identities.name(),
+        Page::Invoices.name(),
+        Page::Templates.name(),
     ]);
 
-    let header_element = use_state(cx, || None::<Rc<MountedData>>);
-    let onkeydown = move |k: KeyboardEvent| match k.key_code {
-        KeyCode::Q => {
-            tui_ctx.quit();
-        }
-        KeyCode::P => {
-            if let Some(he) = header_element.as_ref() {
-                let inner = he.clone();
-                cx.spawn(async move {
-                    let _ = inner.set_focus(true).await;
-                });
-            }
-        }
-        _ => {}
-    };
+    let onkeydown = move |k: KeyboardEvent| global_key(cx, &k, global, keys);
 
+    // the list of the pages is focused when the TUI starts
     let onmounted = move |ctx: MountedEvent| {
-        header_element.set(Some(ctx.inner().clone()));
         let inner = ctx.inner().clone();
+        global.write_silent().pages = Some(inner.clone());
         cx.spawn(async move {
             let _ = inner.set_focus(true).await;
         });
@@ -111,7 +185,6 @@ pub fn App(cx: Scope, path: PathBuf) -> Element {
             justify_content: "center",
             align_items: "center",
             onkeydown: onkeydown,
-            onmounted: onmounted,
             tabindex: -1,
             List {
                 tabindex: 0,
@@ -119,36 +192,61 @@ pub fn App(cx: Scope, path: PathBuf) -> Element {
                 items: items,
                 dot: "❱",
                 idx: page.read().0.into(),
+                onmounted: onmounted,
                 onindexupdate: move |i: usize| {
                     page.write().0 = i.into();
+                    global.write().help = false;
                 }
             }
-            match page.read().0 {
-                Page::Accounts => {
-                    rsx! {
-                        Accounts {}
+            // the page stays mounted when the help is shown to keep its list focused
+            div {
+                width: "80%",
+                height: "100%",
+                display: if help { "none" } else { "flex" },
+                justify_content: "center",
+                align_items: "center",
+                match page.read().0 {
+                    Page::Dashboard => {
+                        rsx! {
+                            Dashboard {}
+                        }
                     }
-                }
-                Page::Customers => {
-                    rsx! {
-                        Customers {}
+                    Page::Accounts => {
+                        rsx! {
+                            Accounts {}
+                        }
                     }
+                    Page::Customers => {
+                        rsx! {
+                            Customers {}
+                        }
 
-                }
-                Page::Entries => {
-                    rsx! {
-                        Entries {}
                     }
-                }
-                Page::Identities => {
-                    rsx! {
-                        Identities {}
+                    Page::Entries => {
+                        rsx! {
+                            Entries {}
+                        }
                     }
-                }
-                Page::Invoices => {
-                    rsx! {
-                        Invoices {}
+                    Page::Identities => {
+                        rsx! {
+                            Identities {}
+                        }
+                    }
+                    Page::Invoices => {
+                        rsx! {
+                            Invoices {}
+                        }
                     }
+                    Page::Templates => {
+                        rsx! {
+                            Templates {}
+                        }
+                    }
+                }
+            }
+            if help {
+                rsx! {
+                    Help { page: page.read().0 }
                 }
             }
         }
@@ -1,77 +1,205 @@
 #![allow(non_snake_case, deprecated)]
 
-use dioxus::prelude::*;
-use std::rc::Rc;
-use ucelofka_data::customer::Customer;
+use anyhow::Result;
+use dioxus::{
+    events::{KeyCode, KeyboardEvent},
+    prelude::*,
+};
+use fluent::fluent_args;
+use std::{path::Path, rc::Rc};
+use ucelofka_data::customer::{Customer, Customers};
 
-use crate::actions::customer::list;
+use crate::{
+    actions::{customer::list, records},
+    storage::Records,
+    tabular::Row,
+    translations::get_message,
+};
 
-use super::{app::UcelofkaTuiCfg, list::List, table::Table};
+use super::{
+    app::{pressed, UcelofkaTuiCfg},
+    form::{self, Confirm, Field, Form, Mode},
+    list::List,
+    status::{self, Errors},
+    table::Table,
+};
 
 struct CurrentCustomerPage(usize);
 
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("id", get_message("tui-id", None)),
+        Field::new("name", get_message("tui-name", None)),
+        Field::multiple("address", get_message("tui-address", None)),
+        Field::multiple("email", get_message("tui-email", None)),
+        Field::new("locale", get_message("tui-locale", None)),
+        Field::new(
+            "identification.registration",
+            get_message("tui-registration", None),
+        ),
+        Field::new("identification.tax", get_message("tui-tax", None)),
+    ]
+}
+
+fn save(data_path: &Path, id: Option<&str>, row: Row, git: bool) -> Result<String> {
+    form::save::<Customer, Customers>(data_path, "customers", id, row, git)
+}
+
+fn delete(data_path: &Path, id: &str, git: bool) -> Result<()> {
+    records::delete::<Customer, Customers>(data_path, "customers", id, git)
+}
+
 #[derive(Clone)]
 enum SubPage {
     Create,
     Customer(Customer),
 }
 
-impl AsRef<str> for SubPage {
-    fn as_ref(&self) -> &str {
+impl SubPage {
+    fn name(&self) -> String {
         match self {
-            Self::Create => "<Create>",
-            Self::Customer(customer) => customer.name.as_str(),
+            Self::Create => get_message("tui-create", None),
+            Self::Customer(customer) => customer.name.clone(),
         }
     }
 }
 
 #[inline_props]
-pub fn Customers(cx: Scope) -> Element {
+pub fn Customers(cx: Scope) -> Element<'a> {
     use_shared_state_provider(cx, || CurrentCustomerPage(0));
 
     let customer_page = use_shared_state::<CurrentCustomerPage>(cx).unwrap();
+    let mode = use_state(cx, || Mode::View);
+    let error = use_state(cx, || None::<String>);
 
     let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
-    let customers = list(cfg.read().path.as_path()).unwrap().customers;
+    let mut errors = Vec::new();
+    let customers =
+        status::load::<Customer, Customers>(cfg.read().path.as_path(), "customers", &mut errors)
+            .customers;
     let items: Vec<SubPage> = vec![SubPage::Create]
         .into_iter()
         .chain(customers.iter().map(|e| SubPage::Customer(e.clone())))
         .collect();
 
-    let items_str: Vec<String> = items.iter().map(|e| e.as_ref().to_string()).collect();
+    let items_str: Vec<String> = items.iter().map(SubPage::name).collect();
     let selected_idx = customer_page.read().0;
-    let selected_customer = if selected_idx == 0 {
-        None
-    } else {
-        Some(customers[selected_idx - 1].clone())
+    // the record might have been removed outside of the TUI
+    let selected_customer = selected_idx
+        .checked_sub(1)
+        .and_then(|idx| customers.get(idx).cloned());
+
+    let selected_id = selected_customer.as_ref().map(|e| e.id.clone());
+
+    let onsaved = move |id: String| {
+        // select the saved record
+        let ids = list(cfg.read().path.as_path())
+            .map(|e| e.ids())
+            .unwrap_or_default();
+        if let Some(idx) = ids.iter().position(|e| *e == id) {
+            customer_page.write().0 = idx + 1;
+        }
+        mode.set(Mode::View);
+    };
+
+    let keys = cfg.read().keys.clone();
+    let hint = get_message(
+        "tui-record-hint",
+        Some(fluent_args![
+            "edit" => keys.edit.as_str(),
+            "delete" => keys.delete.as_str(),
+            "search" => keys.search.as_str()
+        ]),
+    );
+
+    let onkeydown = move |k: KeyboardEvent| {
+        let Some(id) = selected_id.as_ref() else {
+            return;
+        };
+        match *mode.get() {
+            Mode::View if pressed(&k, &keys.edit) => mode.set(Mode::Edit),
+            Mode::View if pressed(&k, &keys.delete) => mode.set(Mode::Delete),
+            Mode::Delete if pressed(&k, &keys.confirm) => {
+                let cfg = cfg.read();
+                match delete(cfg.path.as_path(), id, cfg.git) {
+                    Ok(()) => {
+                        customer_page.write().0 = selected_idx - 1;
+                        mode.set(Mode::View);
+                        error.set(None);
+                    }
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            }
+            Mode::Delete if pressed(&k, &keys.cancel) => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ if k.key_code == KeyCode::Escape => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ => {}
+        }
     };
 
     cx.render(rsx! {
         List {
             tabindex: 0,
-            width: "20%",
+            width: "25%",
             items: Rc::new(items_str),
             dot: "❱",
             idx: customer_page.read().0,
             onindexupdate: move |i: usize| {
                 customer_page.write().0 = i;
-            }
+                mode.set(Mode::View);
+                error.set(None);
+            },
+            onkeydown: onkeydown,
         }
-        div { width: "60%", border_width: "1px", height: "100%", justify_content: "center",
-            if let Some(customer) = selected_customer {
-                rsx! {
-                    Table {
-                        width: "100%",
-                        items: vec![
-                            ("Name:", customer.name),
-                            ("Address:", customer.address.join(", ")),
-                            ("Email:", customer.email.join(", ")),
-                            ("IDs:", customer.identifications.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")),
+        div { width: "75%", border_width: "1px", height: "100%", flex_direction: "column",
+            Errors { errors: errors }
+            match (selected_customer, *mode.get()) {
+                (Some(customer), Mode::View) => rsx! {
+                    div { width: "100%", flex_direction: "column",
+                        Table {
+                            width: "100%",
+                            items: vec![
+                            (get_message("tui-name", None), customer.name.clone()),
+                            (get_message("tui-address", None), customer.address.join(", ")),
+                            (get_message("tui-email", None), customer.email.join(", ")),
+                            (get_message("tui-identifications", None), customer.identifications.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")),
                         ]
+                        }
+                        span { "{hint}" }
                     }
-            }
-            } else {
-                rsx! {span { "TODO CREATE FORM"}}
+                },
+                (Some(customer), Mode::Edit) => rsx! {
+                    Form {
+                        key: "{customer.id}",
+                        title: get_message("tui-edit-customer", None),
+                        fields: fields(),
+                        id: customer.id.clone(),
+                        initial: form::values(&customer, &fields()),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| mode.set(Mode::View),
+                    }
+                },
+                (Some(customer), Mode::Delete) => rsx! {
+                    Confirm {
+                        name: "{customer.name}",
+                        error: error.get().as_deref().unwrap_or_default(),
+                    }
+                },
+                (None, _) => rsx! {
+                    Form {
+                        title: get_message("tui-new-customer", None),
+                        fields: fields(),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| {},
+                    }
+                },
             }
         }
     })
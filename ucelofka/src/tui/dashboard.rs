@@ -0,0 +1,136 @@
+#![allow(non_snake_case, deprecated)]
+
+use chrono::{Local, NaiveDate};
+use dioxus::prelude::*;
+use fluent::fluent_args;
+use std::collections::BTreeMap;
+use ucelofka_data::{
+    invoice::{Invoice, Invoices},
+    payment::{Payment, Payments},
+};
+
+use crate::{actions::payment, translations::get_message};
+
+use super::{
+    app::UcelofkaTuiCfg,
+    status::{self, Errors},
+    table::Table,
+};
+
+/// Number of the latest invoices shown
+const LATEST: usize = 5;
+
+/// Sums of the amounts per currency (e.g. `1200 CZK, 300 EUR`)
+fn totals<'a>(amounts: impl Iterator<Item = (&'a str, f32)>) -> String {
+    let mut totals: BTreeMap<&str, f64> = BTreeMap::new();
+    for (currency, amount) in amounts {
+        *totals.entry(currency).or_default() += f64::from(amount);
+    }
+    if totals.is_empty() {
+        return "-".to_string();
+    }
+    totals
+        .iter()
+        .map(|(currency, total)| format!("{:.2} {}", total, currency))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[inline_props]
+pub fn Dashboard(cx: Scope) -> Element<'a> {
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let path = cfg.read().path.clone();
+    let mut errors = Vec::new();
+    let mut invoices = status::load::<Invoice, Invoices>(&path, "invoices", &mut errors).invoices;
+    let paid = payment::paid_amounts(&status::load::<Payment, Payments>(
+        &path,
+        "payments",
+        &mut errors,
+    ));
+
+    let today = Local::now().date_naive();
+    let year = today.format("%Y-").to_string();
+    let month = today.format("%Y-%m-").to_string();
+    let invoiced = |prefix: &str| {
+        totals(
+            invoices
+                .iter()
+                .filter(|e| e.issue_date.starts_with(prefix))
+                .map(|e| (e.billing.currency.as_str(), e.billing.total)),
+        )
+    };
+    let summary = vec![
+        (get_message("tui-this-month", None), invoiced(&month)),
+        (get_message("tui-this-year", None), invoiced(&year)),
+        (
+            get_message("tui-unpaid", None),
+            totals(
+                invoices
+                    .iter()
+                    .filter(|e| payment::is_unpaid(e, &paid))
+                    .map(|e| (e.billing.currency.as_str(), payment::outstanding(e, &paid))),
+            ),
+        ),
+    ];
+
+    let overdue: Vec<String> = invoices
+        .iter()
+        .filter(|e| payment::is_unpaid(e, &paid))
+        .filter_map(|e| {
+            let due = NaiveDate::parse_from_str(&e.due_date, "%Y-%m-%d").ok()?;
+            let days = (today - due).num_days();
+            (days > 0).then(|| {
+                get_message(
+                    "tui-overdue-invoice",
+                    Some(fluent_args![
+                        "invoice" => e.id,
+                        "customer" => e.customer.name.as_str(),
+                        "amount" => format!("{:.2}", payment::outstanding(e, &paid)),
+                        "currency" => e.billing.currency.as_str(),
+                        "days" => days,
+                        "due" => e.due_date.as_str()
+                    ]),
+                )
+            })
+        })
+        .collect();
+
+    invoices.sort_by(|a, b| (&b.issue_date, b.id).cmp(&(&a.issue_date, a.id)));
+    let latest: Vec<String> = invoices
+        .iter()
+        .take(LATEST)
+        .map(|e| {
+            format!(
+                "{} {} {} - {} {}",
+                e.id, e.issue_date, e.customer.name, e.billing.total, e.billing.currency
+            )
+        })
+        .collect();
+
+    let invoiced_title = get_message("tui-invoiced", None);
+    let latest_title = get_message("tui-latest-invoices", None);
+    let no_invoices = get_message("tui-no-invoices", None);
+    let overdue_title = get_message("tui-overdue-invoices", None);
+    let no_overdue = get_message("tui-no-overdue-invoices", None);
+
+    cx.render(rsx! {
+        div { width: "100%", border_width: "1px", height: "100%", flex_direction: "column",
+            Errors { errors: errors }
+            strong { "{invoiced_title}" }
+            Table {
+                width: "100%",
+                items: summary,
+            }
+            strong { "{latest_title}" }
+            if latest.is_empty() {
+                rsx! { span { "{no_invoices}" } }
+            }
+            latest.iter().map(|line| rsx! { span { "{line}" } })
+            strong { "{overdue_title}" }
+            if overdue.is_empty() {
+                rsx! { span { "{no_overdue}" } }
+            }
+            overdue.iter().map(|line| rsx! { span { color: "red", "{line}" } })
+        }
+    })
+}
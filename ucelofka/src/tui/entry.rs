@@ -1,89 +1,270 @@
 #![allow(non_snake_case, deprecated)]
 
-use dioxus::prelude::*;
-use std::rc::Rc;
-use ucelofka_data::entry::Entry;
+use anyhow::Result;
+use dioxus::{
+    events::{KeyCode, KeyboardEvent},
+    prelude::*,
+};
+use fluent::fluent_args;
+use std::{fmt, path::Path, rc::Rc};
+use ucelofka_data::entry::{Entries, Entry};
 
-use crate::actions::entry::list;
+use crate::{
+    actions::{entry::list, records},
+    tabular::Row,
+    translations::get_message,
+};
 
-use super::{app::UcelofkaTuiCfg, list::List, table::Table};
+use super::{
+    app::{pressed, UcelofkaTuiCfg},
+    form::{self, Confirm, Field, Form, Mode},
+    list::List,
+    status::{self, Errors},
+    table::Table,
+};
 
 struct CurrentEntryPage(usize);
 
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("id", get_message("tui-id", None)),
+        Field::new("name", get_message("tui-name", None)),
+        Field::new("price", get_message("tui-price", None)),
+        Field::new("currency", get_message("tui-currency", None)),
+        Field::multiple("details", get_message("tui-details", None)),
+        Field::new("vat_rate", get_message("tui-vat-rate", None)),
+    ]
+}
+
+fn save(data_path: &Path, id: Option<&str>, row: Row, git: bool) -> Result<String> {
+    form::save::<Entry, Entries>(data_path, "entries", id, row, git)
+}
+
+fn delete(data_path: &Path, id: &str, git: bool) -> Result<()> {
+    records::delete::<Entry, Entries>(data_path, "entries", id, git)
+}
+
+/// Order of the listed entries
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Sort {
+    #[default]
+    Id,
+    Name,
+    Price,
+}
+
+impl Sort {
+    fn next(self) -> Self {
+        match self {
+            Self::Id => Self::Name,
+            Self::Name => Self::Price,
+            Self::Price => Self::Id,
+        }
+    }
+
+    fn apply(self, entries: &mut [Entry]) {
+        match self {
+            Self::Id => entries.sort_by(|a, b| a.id.cmp(&b.id)),
+            Self::Name => entries.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+            Self::Price => entries.sort_by(|a, b| a.price.total_cmp(&b.price)),
+        }
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Id => get_message("tui-sort-id", None),
+            Self::Name => get_message("tui-sort-name", None),
+            Self::Price => get_message("tui-sort-price", None),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Entries in the order in which they are listed
+fn sorted(data_path: &Path, sort: Sort) -> Vec<Entry> {
+    let mut entries = list(data_path).map(|e| e.entries).unwrap_or_default();
+    sort.apply(&mut entries);
+    entries
+}
+
 #[derive(Clone)]
 enum SubPage {
     Create,
     Entry(Entry),
 }
 
-impl AsRef<str> for SubPage {
-    fn as_ref(&self) -> &str {
+impl SubPage {
+    fn name(&self) -> String {
         match self {
-            Self::Create => "<Create>",
-            Self::Entry(entry) => entry.name.as_str(),
+            Self::Create => get_message("tui-create", None),
+            Self::Entry(entry) => entry.name.clone(),
         }
     }
 }
 
 #[inline_props]
-pub fn Entries(cx: Scope) -> Element {
+pub fn Entries(cx: Scope) -> Element<'a> {
     use_shared_state_provider(cx, || CurrentEntryPage(0));
 
     let entry_page = use_shared_state::<CurrentEntryPage>(cx).unwrap();
+    let mode = use_state(cx, || Mode::View);
+    let error = use_state(cx, || None::<String>);
+    let sort = use_state(cx, Sort::default);
 
     let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
-    let entries = list(cfg.read().path.as_path()).unwrap().entries;
+    let mut errors = Vec::new();
+    let mut entries =
+        status::load::<Entry, Entries>(cfg.read().path.as_path(), "entries", &mut errors).entries;
+    (*sort.get()).apply(&mut entries);
     let items: Vec<SubPage> = vec![SubPage::Create]
         .into_iter()
         .chain(entries.iter().map(|e| SubPage::Entry(e.clone())))
         .collect();
 
-    let items_str: Vec<String> = items.iter().map(|e| e.as_ref().to_string()).collect();
+    let items_str: Vec<String> = items.iter().map(SubPage::name).collect();
     let selected_idx = entry_page.read().0;
-    let selected_entry = if selected_idx == 0 {
-        None
-    } else {
-        Some(entries[selected_idx - 1].clone())
-    };
+    // the record might have been removed outside of the TUI
+    let selected_entry = selected_idx
+        .checked_sub(1)
+        .and_then(|idx| entries.get(idx).cloned());
 
     let get_items = |entry: Entry| {
         vec![
-            ("Name:", entry.name),
-            ("Price:", entry.price.to_string()),
-            ("Currency:", entry.currency),
+            (get_message("tui-name", None), entry.name),
+            (get_message("tui-price", None), entry.price.to_string()),
+            (get_message("tui-currency", None), entry.currency),
         ]
         .into_iter()
         .chain(entry.details.into_iter().enumerate().map(|(idx, e)| {
             if idx == 0 {
-                ("Details:", e)
+                (get_message("tui-details", None), e)
             } else {
-                ("", e)
+                (String::new(), e)
             }
         }))
         .collect::<Vec<_>>()
     };
 
+    let selected_id = selected_entry.as_ref().map(|e| e.id.clone());
+
+    let onsaved = move |id: String| {
+        // select the saved record
+        let entries = sorted(cfg.read().path.as_path(), *sort.get());
+        if let Some(idx) = entries.iter().position(|e| e.id == id) {
+            entry_page.write().0 = idx + 1;
+        }
+        mode.set(Mode::View);
+    };
+
+    let keys = cfg.read().keys.clone();
+    let hint = get_message(
+        "tui-entry-hint",
+        Some(fluent_args![
+            "edit" => keys.edit.as_str(),
+            "delete" => keys.delete.as_str(),
+            "sort" => keys.sort.as_str(),
+            "by" => sort.get().to_string(),
+            "search" => keys.search.as_str()
+        ]),
+    );
+
+    let onkeydown = move |k: KeyboardEvent| {
+        if pressed(&k, &keys.sort) && *mode.get() == Mode::View {
+            let new_sort = sort.get().next();
+            // keep the selected entry
+            if let Some(id) = selected_id.as_ref() {
+                let entries = sorted(cfg.read().path.as_path(), new_sort);
+                if let Some(idx) = entries.iter().position(|e| e.id == *id) {
+                    entry_page.write().0 = idx + 1;
+                }
+            }
+            sort.set(new_sort);
+            return;
+        }
+        let Some(id) = selected_id.as_ref() else {
+            return;
+        };
+        match *mode.get() {
+            Mode::View if pressed(&k, &keys.edit) => mode.set(Mode::Edit),
+            Mode::View if pressed(&k, &keys.delete) => mode.set(Mode::Delete),
+            Mode::Delete if pressed(&k, &keys.confirm) => {
+                let cfg = cfg.read();
+                match delete(cfg.path.as_path(), id, cfg.git) {
+                    Ok(()) => {
+                        entry_page.write().0 = selected_idx - 1;
+                        mode.set(Mode::View);
+                        error.set(None);
+                    }
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            }
+            Mode::Delete if pressed(&k, &keys.cancel) => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ if k.key_code == KeyCode::Escape => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ => {}
+        }
+    };
+
     cx.render(rsx! {
         List {
             tabindex: 0,
-            width: "20%",
+            width: "25%",
             items: Rc::new(items_str),
             dot: "❱",
             idx: entry_page.read().0,
             onindexupdate: move |i: usize| {
                 entry_page.write().0 = i;
-            }
+                mode.set(Mode::View);
+                error.set(None);
+            },
+            onkeydown: onkeydown,
         }
-        div { width: "60%", border_width: "1px", height: "100%", justify_content: "center",
-            if let Some(entry) = selected_entry {
-                rsx! {
-                    Table {
-                        width: "100%",
-                        items: get_items(entry),
+        div { width: "75%", border_width: "1px", height: "100%", flex_direction: "column",
+            Errors { errors: errors }
+            match (selected_entry, *mode.get()) {
+                (Some(entry), Mode::View) => rsx! {
+                    div { width: "100%", flex_direction: "column",
+                        Table {
+                            width: "100%",
+                            items: get_items(entry.clone()),
+                        }
+                        span { "{hint}" }
                     }
-            }
-            } else {
-                rsx! {span { "TODO CREATE FORM"}}
+                },
+                (Some(entry), Mode::Edit) => rsx! {
+                    Form {
+                        key: "{entry.id}",
+                        title: get_message("tui-edit-entry", None),
+                        fields: fields(),
+                        id: entry.id.clone(),
+                        initial: form::values(&entry, &fields()),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| mode.set(Mode::View),
+                    }
+                },
+                (Some(entry), Mode::Delete) => rsx! {
+                    Confirm {
+                        name: "{entry.name}",
+                        error: error.get().as_deref().unwrap_or_default(),
+                    }
+                },
+                (None, _) => rsx! {
+                    Form {
+                        title: get_message("tui-new-entry", None),
+                        fields: fields(),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| {},
+                    }
+                },
             }
         }
     })
@@ -0,0 +1,225 @@
+#![allow(non_snake_case, deprecated)]
+
+use anyhow::Result;
+use dioxus::{
+    events::{KeyCode, KeyboardEvent},
+    prelude::*,
+};
+use fluent::fluent_args;
+use std::{convert::TryFrom, path::Path};
+
+use crate::{
+    actions::records,
+    storage::Records,
+    tabular::{Row, Tabular},
+    translations::get_message,
+};
+
+use super::app::UcelofkaTuiCfg;
+
+/// Separator of the values in the fields with multiple values
+const SEPARATOR: char = ';';
+
+/// Input of the form, `column` is the CSV column of the record
+#[derive(Clone, PartialEq)]
+pub struct Field {
+    pub column: &'static str,
+    pub label: String,
+    /// Field contains multiple values
+    pub multiple: bool,
+}
+
+impl Field {
+    pub fn new(column: &'static str, label: String) -> Self {
+        Self {
+            column,
+            label,
+            multiple: false,
+        }
+    }
+
+    pub fn multiple(column: &'static str, label: String) -> Self {
+        Self {
+            column,
+            label,
+            multiple: true,
+        }
+    }
+}
+
+/// What is shown in the detail of the selected record
+#[derive(Clone, Copy, PartialEq)]
+pub enum Mode {
+    View,
+    Edit,
+    Delete,
+}
+
+/// Stores the record made from the form values and returns its id
+///
+/// The id of the edited record is passed, `None` means a new record.
+pub type Save = fn(&Path, Option<&str>, Row, bool) -> Result<String>;
+
+/// Creates or updates a record from the form values the same way as the CSV import does
+pub fn save<ITEM, RECORDS>(
+    data_path: &Path,
+    dir_name: &str,
+    id: Option<&str>,
+    row: Row,
+    git: bool,
+) -> Result<String>
+where
+    ITEM: Tabular + TryFrom<String> + Clone,
+    <ITEM as TryFrom<String>>::Error: std::fmt::Debug,
+    RECORDS: Records<ITEM>,
+{
+    let record = ITEM::from_rows(&[row])?;
+    if let Some(id) = id {
+        records::update::<ITEM, RECORDS>(data_path, dir_name, id, &record, git)?;
+    } else {
+        records::create::<ITEM, RECORDS>(data_path, dir_name, &record, git)?;
+    }
+    Ok(record.id())
+}
+
+/// Form values of an existing record
+pub fn values<T: Tabular>(record: &T, fields: &[Field]) -> Vec<String> {
+    let rows = record.to_rows();
+    let row = rows.first().cloned().unwrap_or_default();
+    fields
+        .iter()
+        .map(|field| {
+            let value = row.get(field.column);
+            if field.multiple {
+                value.replace('\n', &format!("{} ", SEPARATOR))
+            } else {
+                value.to_string()
+            }
+        })
+        .collect()
+}
+
+#[inline_props]
+pub fn Form<'a>(
+    cx: Scope,
+    title: String,
+    fields: Vec<Field>,
+    /// Id of the edited record
+    id: Option<String>,
+    /// Values of the edited record
+    initial: Option<Vec<String>>,
+    save: Save,
+    onsaved: EventHandler<'a, String>,
+    oncancel: EventHandler<'a, ()>,
+) -> Element<'a> {
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let values = use_ref(cx, || {
+        initial
+            .clone()
+            .unwrap_or_else(|| vec![String::new(); fields.len()])
+    });
+    let error = use_state(cx, || None::<String>);
+
+    let onsave = move |_| {
+        let row = Row::new(
+            fields
+                .iter()
+                .zip(values.read().iter())
+                .map(|(field, value)| {
+                    let value = if field.multiple {
+                        value
+                            .split(SEPARATOR)
+                            .map(str::trim)
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    } else {
+                        value.trim().to_string()
+                    };
+                    (field.column.to_string(), value)
+                })
+                .collect(),
+        );
+        let cfg = cfg.read();
+        match save(cfg.path.as_path(), id.as_deref(), row, cfg.git) {
+            Ok(id) => {
+                error.set(None);
+                onsaved.call(id);
+            }
+            Err(err) => error.set(Some(err.to_string())),
+        }
+    };
+
+    // `value` is set on each render and the inputs report it back
+    let current = values.read().clone();
+    let hint = get_message(
+        "tui-form-hint",
+        Some(fluent_args!["separator" => SEPARATOR.to_string()]),
+    );
+    let save_label = get_message("tui-save", None);
+
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            flex_direction: "column",
+            // keys are typed into the inputs
+            onkeydown: move |k: KeyboardEvent| {
+                if k.key_code == KeyCode::Escape {
+                    oncancel.call(());
+                }
+                k.stop_propagation();
+            },
+            tabindex: -1,
+            strong { "{title}" }
+            span { "{hint}" }
+            fields.iter().zip(current.iter()).enumerate().map(|(idx, (field, value))| rsx! {
+                div {
+                    key: "{field.column}",
+                    width: "100%",
+                    height: "3px",
+                    align_items: "center",
+                    div { width: "30%", "{field.label}" }
+                    input {
+                        width: "70%",
+                        height: "3px",
+                        value: "{value}",
+                        oninput: move |e: FormEvent| values.write_silent()[idx] = e.value.clone(),
+                    }
+                }
+            })
+            input {
+                r#type: "button",
+                value: "{save_label}",
+                width: "20%",
+                height: "3px",
+                oninput: onsave,
+            }
+            if let Some(error) = error.get() {
+                rsx! { span { color: "red", "{error}" } }
+            }
+        }
+    })
+}
+
+/// Confirmation of deleting the selected record
+#[inline_props]
+pub fn Confirm<'a>(cx: Scope, name: &'a str, error: &'a str) -> Element<'a> {
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let keys = &cfg.read().keys;
+    let question = get_message(
+        "tui-confirm-delete",
+        Some(fluent_args![
+            "name" => *name,
+            "confirm" => keys.confirm.as_str(),
+            "cancel" => keys.cancel.as_str()
+        ]),
+    );
+
+    cx.render(rsx! {
+        div { width: "100%", flex_direction: "column",
+            strong { "{question}" }
+            if !error.is_empty() {
+                rsx! { span { color: "red", "{error}" } }
+            }
+        }
+    })
+}
@@ -0,0 +1,104 @@
+#![allow(non_snake_case, deprecated)]
+
+use dioxus::prelude::*;
+use fluent::fluent_args;
+
+use crate::{config::Keys, translations::get_message};
+
+use super::{
+    app::{Page, UcelofkaTuiCfg},
+    table::Table,
+};
+
+/// Keybindings which can be used on the page
+fn bindings(page: Page, keys: &Keys) -> Vec<(String, String)> {
+    let mut res = vec![
+        (
+            "Tab/Shift+Tab".to_string(),
+            get_message("tui-help-next-field", None),
+        ),
+        ("Up/Down".to_string(), get_message("tui-help-move", None)),
+        (
+            "PgUp/PgDn".to_string(),
+            get_message("tui-help-scroll", None),
+        ),
+        (
+            "Home/End".to_string(),
+            get_message("tui-help-first-last", None),
+        ),
+        (keys.search.clone(), get_message("tui-help-search", None)),
+        (keys.help.clone(), get_message("tui-help-help", None)),
+        (keys.pages.clone(), get_message("tui-help-pages", None)),
+        (keys.quit.clone(), get_message("tui-help-quit", None)),
+    ];
+    let records = vec![
+        (keys.edit.clone(), get_message("tui-help-edit", None)),
+        (keys.delete.clone(), get_message("tui-help-delete", None)),
+        (
+            format!("{}/{}", keys.confirm, keys.cancel),
+            get_message("tui-help-confirm", None),
+        ),
+        (
+            "Esc".to_string(),
+            get_message("tui-help-cancel-editing", None),
+        ),
+    ];
+    match page {
+        Page::Dashboard => {}
+        Page::Accounts | Page::Customers | Page::Identities => res.extend(records),
+        Page::Entries => {
+            res.extend(records);
+            res.push((
+                keys.sort.clone(),
+                get_message("tui-help-sort-entries", None),
+            ));
+        }
+        Page::Invoices => res.extend(vec![
+            (
+                keys.customer.clone(),
+                get_message("tui-help-customer", None),
+            ),
+            (keys.year.clone(), get_message("tui-help-year", None)),
+            (keys.unpaid.clone(), get_message("tui-help-unpaid", None)),
+            (
+                keys.sort.clone(),
+                get_message("tui-help-sort-invoices", None),
+            ),
+            (
+                keys.template.clone(),
+                get_message("tui-help-template", None),
+            ),
+            (keys.render.clone(), get_message("tui-help-render", None)),
+            (keys.preview.clone(), get_message("tui-help-preview", None)),
+            (
+                "Esc".to_string(),
+                get_message("tui-help-close-preview", None),
+            ),
+        ]),
+        Page::Templates => res.extend(vec![
+            (keys.invoice.clone(), get_message("tui-help-invoice", None)),
+            (
+                "Esc".to_string(),
+                get_message("tui-help-show-template", None),
+            ),
+        ]),
+    }
+    res
+}
+
+#[inline_props]
+pub fn Help(cx: Scope, page: Page) -> Element<'a> {
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let items = bindings(*page, &cfg.read().keys);
+    let title = get_message("tui-help-title", Some(fluent_args!["page" => page.name()]));
+
+    cx.render(rsx! {
+        div { width: "80%", border_width: "1px", height: "100%", flex_direction: "column",
+            strong { "{title}" }
+            Table {
+                width: "100%",
+                items: items,
+            }
+        }
+    })
+}
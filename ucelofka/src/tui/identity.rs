@@ -1,80 +1,124 @@
 #![allow(non_snake_case, deprecated)]
 
-use dioxus::prelude::*;
-use std::rc::Rc;
-use ucelofka_data::identity::Identity;
+use anyhow::Result;
+use dioxus::{
+    events::{KeyCode, KeyboardEvent},
+    prelude::*,
+};
+use fluent::fluent_args;
+use std::{path::Path, rc::Rc};
+use ucelofka_data::identity::{Identities, Identity};
 
-use crate::actions::identity::list;
+use crate::{
+    actions::{identity::list, records},
+    storage::Records,
+    tabular::Row,
+    translations::get_message,
+};
 
-use super::{app::UcelofkaTuiCfg, list::List, table::Table};
+use super::{
+    app::{pressed, UcelofkaTuiCfg},
+    form::{self, Confirm, Field, Form, Mode},
+    list::List,
+    status::{self, Errors},
+    table::Table,
+};
 
 struct CurrentIdentityPage(usize);
 
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("id", get_message("tui-id", None)),
+        Field::new("name", get_message("tui-name", None)),
+        Field::multiple("address", get_message("tui-address", None)),
+        Field::multiple("phone", get_message("tui-phone", None)),
+        Field::multiple("email", get_message("tui-email", None)),
+        Field::multiple("www", get_message("tui-www", None)),
+        Field::new(
+            "identification.registration",
+            get_message("tui-registration", None),
+        ),
+        Field::new("identification.tax", get_message("tui-tax", None)),
+    ]
+}
+
+fn save(data_path: &Path, id: Option<&str>, row: Row, git: bool) -> Result<String> {
+    form::save::<Identity, Identities>(data_path, "identities", id, row, git)
+}
+
+fn delete(data_path: &Path, id: &str, git: bool) -> Result<()> {
+    records::delete::<Identity, Identities>(data_path, "identities", id, git)
+}
+
 #[derive(Clone)]
 enum SubPage {
     Create,
     Identity(Identity),
 }
 
-impl AsRef<str> for SubPage {
-    fn as_ref(&self) -> &str {
+impl SubPage {
+    fn name(&self) -> String {
         match self {
-            Self::Create => "<Create>",
-            Self::Identity(identity) => identity.name.as_str(),
+            Self::Create => get_message("tui-create", None),
+            Self::Identity(identity) => identity.name.clone(),
         }
     }
 }
 
 #[inline_props]
-pub fn Identities(cx: Scope) -> Element {
+pub fn Identities(cx: Scope) -> Element<'a> {
     use_shared_state_provider(cx, || CurrentIdentityPage(0));
 
     let identity_page = use_shared_state::<CurrentIdentityPage>(cx).unwrap();
+    let mode = use_state(cx, || Mode::View);
+    let error = use_state(cx, || None::<String>);
 
     let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
-    let identities = list(cfg.read().path.as_path()).unwrap().identities;
+    let mut errors = Vec::new();
+    let identities =
+        status::load::<Identity, Identities>(cfg.read().path.as_path(), "identities", &mut errors)
+            .identities;
     let items: Vec<SubPage> = vec![SubPage::Create]
         .into_iter()
         .chain(identities.iter().map(|e| SubPage::Identity(e.clone())))
         .collect();
 
-    let items_str: Vec<String> = items.iter().map(|e| e.as_ref().to_string()).collect();
+    let items_str: Vec<String> = items.iter().map(SubPage::name).collect();
     let selected_idx = identity_page.read().0;
-    let selected_identity = if selected_idx == 0 {
-        None
-    } else {
-        Some(identities[selected_idx - 1].clone())
-    };
+    // the record might have been removed outside of the TUI
+    let selected_identity = selected_idx
+        .checked_sub(1)
+        .and_then(|idx| identities.get(idx).cloned());
 
     let get_items = |identity: Identity| {
-        vec![("Name:", identity.name)]
+        vec![(get_message("tui-name", None), identity.name)]
             .into_iter()
             .chain(identity.address.into_iter().enumerate().map(|(idx, e)| {
                 if idx == 0 {
-                    ("Address:", e)
+                    (get_message("tui-address", None), e)
                 } else {
-                    ("", e)
+                    (String::new(), e)
                 }
             }))
             .chain(identity.phone.into_iter().enumerate().map(|(idx, e)| {
                 if idx == 0 {
-                    ("Phone:", e)
+                    (get_message("tui-phone", None), e)
                 } else {
-                    ("", e)
+                    (String::new(), e)
                 }
             }))
             .chain(identity.email.into_iter().enumerate().map(|(idx, e)| {
                 if idx == 0 {
-                    ("Email:", e)
+                    (get_message("tui-email", None), e)
                 } else {
-                    ("", e)
+                    (String::new(), e)
                 }
             }))
             .chain(identity.www.into_iter().enumerate().map(|(idx, e)| {
                 if idx == 0 {
-                    ("WWW:", e)
+                    (get_message("tui-www", None), e)
                 } else {
-                    ("", e)
+                    (String::new(), e)
                 }
             }))
             .chain(
@@ -84,36 +128,124 @@ pub fn Identities(cx: Scope) -> Element {
                     .enumerate()
                     .map(|(idx, e)| {
                         if idx == 0 {
-                            ("Identification:", format!("{} - {}", e.name, e.value))
+                            (
+                                get_message("tui-identifications", None),
+                                format!("{} - {}", e.name, e.value),
+                            )
                         } else {
-                            ("", format!("{} - {}", e.name, e.value))
+                            (String::new(), format!("{} - {}", e.name, e.value))
                         }
                     }),
             )
             .collect::<Vec<_>>()
     };
 
+    let selected_id = selected_identity.as_ref().map(|e| e.id.clone());
+
+    let onsaved = move |id: String| {
+        // select the saved record
+        let ids = list(cfg.read().path.as_path())
+            .map(|e| e.ids())
+            .unwrap_or_default();
+        if let Some(idx) = ids.iter().position(|e| *e == id) {
+            identity_page.write().0 = idx + 1;
+        }
+        mode.set(Mode::View);
+    };
+
+    let keys = cfg.read().keys.clone();
+    let hint = get_message(
+        "tui-record-hint",
+        Some(fluent_args![
+            "edit" => keys.edit.as_str(),
+            "delete" => keys.delete.as_str(),
+            "search" => keys.search.as_str()
+        ]),
+    );
+
+    let onkeydown = move |k: KeyboardEvent| {
+        let Some(id) = selected_id.as_ref() else {
+            return;
+        };
+        match *mode.get() {
+            Mode::View if pressed(&k, &keys.edit) => mode.set(Mode::Edit),
+            Mode::View if pressed(&k, &keys.delete) => mode.set(Mode::Delete),
+            Mode::Delete if pressed(&k, &keys.confirm) => {
+                let cfg = cfg.read();
+                match delete(cfg.path.as_path(), id, cfg.git) {
+                    Ok(()) => {
+                        identity_page.write().0 = selected_idx - 1;
+                        mode.set(Mode::View);
+                        error.set(None);
+                    }
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            }
+            Mode::Delete if pressed(&k, &keys.cancel) => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ if k.key_code == KeyCode::Escape => {
+                mode.set(Mode::View);
+                error.set(None);
+            }
+            _ => {}
+        }
+    };
+
     cx.render(rsx! {
         List {
             tabindex: 0,
-            width: "20%",
+            width: "25%",
             items: Rc::new(items_str),
             dot: "❱",
             idx: identity_page.read().0,
             onindexupdate: move |i: usize| {
                 identity_page.write().0 = i;
-            }
+                mode.set(Mode::View);
+                error.set(None);
+            },
+            onkeydown: onkeydown,
         }
-        div { width: "60%", border_width: "1px", height: "100%", justify_content: "center",
-            if let Some(identity) = selected_identity {
-                rsx! {
-                    Table {
-                        width: "100%",
-                        items: get_items(identity),
+        div { width: "75%", border_width: "1px", height: "100%", flex_direction: "column",
+            Errors { errors: errors }
+            match (selected_identity, *mode.get()) {
+                (Some(identity), Mode::View) => rsx! {
+                    div { width: "100%", flex_direction: "column",
+                        Table {
+                            width: "100%",
+                            items: get_items(identity.clone()),
+                        }
+                        span { "{hint}" }
                     }
-            }
-            } else {
-                rsx! {span { "TODO CREATE FORM"}}
+                },
+                (Some(identity), Mode::Edit) => rsx! {
+                    Form {
+                        key: "{identity.id}",
+                        title: get_message("tui-edit-identity", None),
+                        fields: fields(),
+                        id: identity.id.clone(),
+                        initial: form::values(&identity, &fields()),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| mode.set(Mode::View),
+                    }
+                },
+                (Some(identity), Mode::Delete) => rsx! {
+                    Confirm {
+                        name: "{identity.name}",
+                        error: error.get().as_deref().unwrap_or_default(),
+                    }
+                },
+                (None, _) => rsx! {
+                    Form {
+                        title: get_message("tui-new-identity", None),
+                        fields: fields(),
+                        save: save,
+                        onsaved: onsaved,
+                        oncancel: move |_| {},
+                    }
+                },
             }
         }
     })
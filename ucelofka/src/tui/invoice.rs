@@ -1,12 +1,33 @@
 #![allow(non_snake_case, deprecated, clippy::large_enum_variant)]
 
-use dioxus::prelude::*;
-use std::rc::Rc;
-use ucelofka_data::invoice::Invoice;
+use anyhow::Result;
+use dioxus::{
+    events::{KeyCode, KeyboardEvent},
+    prelude::*,
+};
+use fluent::fluent_args;
+use std::{collections::HashMap, fmt, path::Path, rc::Rc};
+use ucelofka_data::{
+    account::{Account, Accounts},
+    customer::{Customer, Customers},
+    entry::{Entries, Entry},
+    identity::{Identities, Identity},
+    invoice::{Invoice, Invoices},
+    payment::{Payment, Payments},
+};
 
-use crate::actions::invoice::list;
+use crate::{
+    actions::{self, invoice::list, payment},
+    storage::Records,
+    translations::get_message,
+};
 
-use super::{app::UcelofkaTuiCfg, list::List, table::Table};
+use super::{
+    app::{pressed, UcelofkaTuiCfg},
+    list::List,
+    status::{self, Errors},
+    table::Table,
+};
 
 struct CurrentInvoicePage(usize);
 
@@ -16,65 +37,613 @@ enum SubPage {
     Invoice(Invoice),
 }
 
-impl ToString for SubPage {
-    fn to_string(&self) -> String {
+impl fmt::Display for SubPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Create => "<Create>".to_owned(),
-            Self::Invoice(invoice) => invoice.id.to_string(),
+            Self::Create => write!(f, "{}", get_message("tui-create", None)),
+            Self::Invoice(invoice) => write!(f, "{} {}", invoice.id, invoice.customer.name),
         }
     }
 }
 
+/// Order of the listed invoices
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Sort {
+    #[default]
+    Id,
+    IssueDate,
+    DueDate,
+    Customer,
+    Total,
+}
+
+impl Sort {
+    fn next(self) -> Self {
+        match self {
+            Self::Id => Self::IssueDate,
+            Self::IssueDate => Self::DueDate,
+            Self::DueDate => Self::Customer,
+            Self::Customer => Self::Total,
+            Self::Total => Self::Id,
+        }
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Id => get_message("tui-sort-id", None),
+            Self::IssueDate => get_message("tui-sort-issue-date", None),
+            Self::DueDate => get_message("tui-sort-due-date", None),
+            Self::Customer => get_message("tui-sort-customer", None),
+            Self::Total => get_message("tui-sort-total", None),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Which invoices are listed and in which order
+#[derive(Clone, Default, PartialEq)]
+struct Filter {
+    customer: Option<String>,
+    year: Option<String>,
+    unpaid: bool,
+    sort: Sort,
+}
+
+impl Filter {
+    fn apply(&self, invoices: &[Invoice], paid: &HashMap<u64, f32>) -> Vec<Invoice> {
+        let mut res: Vec<Invoice> = invoices
+            .iter()
+            .filter(|e| {
+                self.customer
+                    .as_ref()
+                    .is_none_or(|customer| e.customer.name == *customer)
+            })
+            .filter(|e| self.year.as_ref().is_none_or(|year| year_of(e) == year))
+            .filter(|e| !self.unpaid || payment::is_unpaid(e, paid))
+            .cloned()
+            .collect();
+        // the stable sort keeps the invoices with the same key ordered by id
+        match self.sort {
+            Sort::Id => {}
+            Sort::IssueDate => res.sort_by(|a, b| a.issue_date.cmp(&b.issue_date)),
+            Sort::DueDate => res.sort_by(|a, b| a.due_date.cmp(&b.due_date)),
+            Sort::Customer => res.sort_by(|a, b| a.customer.name.cmp(&b.customer.name)),
+            Sort::Total => res.sort_by(|a, b| a.billing.total.total_cmp(&b.billing.total)),
+        }
+        res
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(customer) = self.customer.as_ref() {
+            parts.push(customer.clone());
+        }
+        if let Some(year) = self.year.as_ref() {
+            parts.push(year.clone());
+        }
+        if self.unpaid {
+            parts.push(get_message("tui-filter-unpaid", None));
+        }
+        if parts.is_empty() {
+            parts.push(get_message("tui-filter-all", None));
+        }
+        let showing = get_message(
+            "tui-filter",
+            Some(fluent_args![
+                "shown" => parts.join(", "),
+                "sort" => self.sort.to_string()
+            ]),
+        );
+        write!(f, "{}", showing)
+    }
+}
+
+fn year_of(invoice: &Invoice) -> &str {
+    invoice.issue_date.get(..4).unwrap_or_default()
+}
+
+/// Next value of a filter, no filter (`None`) follows the last value
+fn cycle(values: &[String], current: &Option<String>) -> Option<String> {
+    match current
+        .as_ref()
+        .and_then(|current| values.iter().position(|e| e == current))
+    {
+        Some(pos) => values.get(pos + 1).cloned(),
+        None => values.first().cloned(),
+    }
+}
+
+/// What is shown in the detail of the selected invoice
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Detail,
+    Preview,
+}
+
+/// Rows of a list value, only the first one is labeled
+fn labeled(label: String, values: &[String]) -> Vec<(String, String)> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            let label = if idx == 0 {
+                label.clone()
+            } else {
+                String::new()
+            };
+            (label, value.clone())
+        })
+        .collect()
+}
+
+fn detail(invoice: Invoice) -> Vec<(String, String)> {
+    let currency = invoice.billing.currency.clone();
+    let mut items = vec![
+        (get_message("tui-id", None), invoice.id.to_string()),
+        (get_message("tui-issue-date", None), invoice.issue_date),
+        (get_message("tui-due-date", None), invoice.due_date),
+    ];
+    if let Some(paid) = invoice.paid {
+        items.push((get_message("tui-paid", None), paid));
+    }
+
+    items.push((get_message("tui-issuer", None), invoice.issuer.name));
+    items.extend(labeled(String::new(), &invoice.issuer.address));
+    items.extend(
+        invoice
+            .issuer
+            .identifications
+            .iter()
+            .map(|e| (String::new(), e.to_string())),
+    );
+
+    items.push((get_message("tui-customer", None), invoice.customer.name));
+    items.extend(labeled(String::new(), &invoice.customer.address));
+    items.extend(
+        invoice
+            .customer
+            .identifications
+            .iter()
+            .map(|e| (String::new(), e.to_string())),
+    );
+    items.extend(labeled(String::new(), &invoice.customer.email));
+
+    for (idx, entry) in invoice.entries.iter().enumerate() {
+        let price = format!("{}: {} {}", entry.name, entry.price, entry.currency);
+        let price = match entry.vat_rate {
+            Some(rate) => get_message(
+                "tui-entry-vat",
+                Some(fluent_args!["price" => price, "rate" => rate.to_string()]),
+            ),
+            None => price,
+        };
+        let label = if idx == 0 {
+            get_message("tui-entries", None)
+        } else {
+            String::new()
+        };
+        items.push((label, price));
+        items.extend(
+            entry
+                .details
+                .iter()
+                .map(|e| (String::new(), format!("  {}", e))),
+        );
+    }
+
+    items.extend([
+        (
+            get_message("tui-account-name", None),
+            invoice.billing.account_name,
+        ),
+        (
+            get_message("tui-account-number", None),
+            invoice.billing.account_number,
+        ),
+        (get_message("tui-iban", None), invoice.billing.IBAN),
+        (get_message("tui-bic", None), invoice.billing.BIC),
+        (
+            get_message("tui-variable-symbol", None),
+            invoice.billing.variable_symbol,
+        ),
+        (
+            get_message("tui-total", None),
+            format!("{} {}", invoice.billing.total, currency),
+        ),
+    ]);
+    items
+}
+
+/// Rendered invoice as a plain text
+pub fn preview(data_path: &Path, invoice: &str, template: &str) -> Result<String> {
+    actions::invoice::render_text(data_path, invoice, template, None)
+}
+
 #[inline_props]
-pub fn Invoices(cx: Scope) -> Element {
+pub fn Invoices(cx: Scope) -> Element<'a> {
     use_shared_state_provider(cx, || CurrentInvoicePage(0));
 
     let invoice_page = use_shared_state::<CurrentInvoicePage>(cx).unwrap();
+    let mode = use_state(cx, || Mode::Detail);
+    let template_idx = use_state(cx, || 0);
+    let status = use_state(cx, || None::<Result<String, String>>);
+    let filter = use_state(cx, Filter::default);
 
     let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
-    let invoices = list(cfg.read().path.as_path()).unwrap().invoices;
+    let path = cfg.read().path.clone();
+    let mut errors = Vec::new();
+    let all_invoices = status::load::<Invoice, Invoices>(&path, "invoices", &mut errors).invoices;
+    let payments = status::load::<Payment, Payments>(&path, "payments", &mut errors);
+    let paid = payment::paid_amounts(&payments);
+    let invoices = filter.get().apply(&all_invoices, &paid);
+    let templates: Vec<String> = actions::template::list(&path)
+        .map(|e| e.templates.into_iter().map(|e| e.name).collect())
+        .unwrap_or_else(|err| {
+            errors.push(err.to_string());
+            Vec::new()
+        });
     let items: Vec<SubPage> = vec![SubPage::Create]
         .into_iter()
         .chain(invoices.iter().map(|e| SubPage::Invoice(e.clone())))
         .collect();
 
+    let mut customers: Vec<String> = all_invoices
+        .iter()
+        .map(|e| e.customer.name.clone())
+        .collect();
+    customers.sort();
+    customers.dedup();
+    let mut years: Vec<String> = all_invoices
+        .iter()
+        .map(|e| year_of(e).to_string())
+        .collect();
+    years.sort();
+    years.dedup();
+
     let items_str: Vec<String> = items.iter().map(|e| e.to_string()).collect();
     let selected_idx = invoice_page.read().0;
     let selected_invoice = if selected_idx == 0 {
         None
     } else {
-        Some(invoices[selected_idx - 1].clone())
+        invoices.get(selected_idx - 1).cloned()
+    };
+    let selected_id = selected_invoice.as_ref().map(|e| e.id.to_string());
+    let template = templates.get(*template_idx.get()).cloned();
+    let templates_len = templates.len();
+    let keys = cfg.read().keys.clone();
+    let filter_line = get_message(
+        "tui-filter-hint",
+        Some(fluent_args![
+            "filter" => filter.get().to_string(),
+            "customer" => keys.customer.as_str(),
+            "year" => keys.year.as_str(),
+            "unpaid" => keys.unpaid.as_str(),
+            "sort" => keys.sort.as_str(),
+            "search" => keys.search.as_str()
+        ]),
+    );
+    let template_line = match templates.get(*template_idx.get()) {
+        Some(template) => get_message(
+            "tui-template",
+            Some(fluent_args![
+                "template" => template.as_str(),
+                "next" => keys.template.as_str(),
+                "render" => keys.render.as_str(),
+                "preview" => keys.preview.as_str()
+            ]),
+        ),
+        None => get_message("tui-no-templates", None),
     };
 
-    let get_items = |invoice: Invoice| {
-        vec![
-            ("ID:", invoice.id.to_string()),
-            ("Issue date:", invoice.issue_date),
-            ("Due date:", invoice.due_date),
-        ]
+    let onkeydown = move |k: KeyboardEvent| {
+        let mut new_filter = filter.get().clone();
+        if pressed(&k, &keys.customer) {
+            new_filter.customer = cycle(&customers, &new_filter.customer);
+        } else if pressed(&k, &keys.year) {
+            new_filter.year = cycle(&years, &new_filter.year);
+        } else if pressed(&k, &keys.unpaid) {
+            new_filter.unpaid = !new_filter.unpaid;
+        } else if pressed(&k, &keys.sort) {
+            new_filter.sort = new_filter.sort.next();
+        }
+        if new_filter != *filter.get() {
+            // keep the selected invoice when it is still listed
+            let shown = new_filter.apply(&all_invoices, &paid);
+            invoice_page.write().0 = selected_id
+                .as_ref()
+                .and_then(|id| shown.iter().position(|e| e.id.to_string() == *id))
+                .map_or(0, |pos| pos + 1);
+            mode.set(Mode::Detail);
+            filter.set(new_filter);
+            return;
+        }
+
+        let Some(id) = selected_id.as_ref() else {
+            return;
+        };
+        match k.key_code {
+            _ if pressed(&k, &keys.template) && templates_len > 0 => {
+                template_idx.set((*template_idx.get() + 1) % templates_len);
+            }
+            _ if pressed(&k, &keys.render) => {
+                let Some(template) = template.as_ref() else {
+                    return;
+                };
+                let cfg = cfg.read();
+                let res = actions::invoice::render(
+                    cfg.path.as_path(),
+                    id,
+                    template,
+                    None,
+                    None,
+                    actions::invoice::DEFAULT_OUTPUT_PATTERN,
+                    cfg.git,
+                );
+                status.set(Some(
+                    res.map(|path| get_message("tui-rendered", Some(fluent_args!["path" => path])))
+                        .map_err(|err| err.to_string()),
+                ));
+            }
+            _ if pressed(&k, &keys.preview) && template.is_some() => mode.set(Mode::Preview),
+            KeyCode::Escape => mode.set(Mode::Detail),
+            _ => {}
+        }
     };
 
     cx.render(rsx! {
         List {
             tabindex: 0,
-            width: "20%",
+            width: "25%",
             items: Rc::new(items_str),
             dot: "❱",
             idx: invoice_page.read().0,
             onindexupdate: move |i: usize| {
                 invoice_page.write().0 = i;
+                mode.set(Mode::Detail);
+                status.set(None);
+            },
+            onkeydown: onkeydown,
+        }
+        div { width: "75%", border_width: "1px", height: "100%", flex_direction: "column",
+            Errors { errors: errors }
+            span { color: "grey", "{filter_line}" }
+            match (selected_invoice, *mode.get()) {
+                (Some(invoice), Mode::Detail) => rsx! {
+                    div { width: "100%", flex_direction: "column",
+                        Table {
+                            width: "100%",
+                            items: detail(invoice),
+                        }
+                        span { "{template_line}" }
+                        match status.get() {
+                            Some(Ok(message)) => rsx! { span { "{message}" } },
+                            Some(Err(error)) => rsx! { span { color: "red", "{error}" } },
+                            None => rsx! { "" },
+                        }
+                    }
+                },
+                (Some(invoice), Mode::Preview) => {
+                    let template = templates.get(*template_idx.get()).cloned().unwrap_or_default();
+                    let title = get_message(
+                        "tui-preview-title",
+                        Some(fluent_args![
+                            "invoice" => invoice.id.to_string(),
+                            "template" => template.as_str()
+                        ]),
+                    );
+                    match preview(cfg.read().path.as_path(), &invoice.id.to_string(), &template) {
+                        Ok(text) => rsx! {
+                            div { width: "100%", flex_direction: "column",
+                                strong { "{title}" }
+                                text.lines().map(|line| rsx! { span { "{line}" } })
+                            }
+                        },
+                        Err(err) => rsx! { span { color: "red", "{err}" } },
+                    }
+                },
+                (None, _) => rsx! {
+                    InvoiceForm {
+                        onsaved: move |id: String| {
+                            // list all the invoices to select the new one
+                            filter.set(Filter::default());
+                            let ids = list(cfg.read().path.as_path()).map(|e| e.ids()).unwrap_or_default();
+                            if let Some(idx) = ids.iter().position(|e| *e == id) {
+                                invoice_page.write().0 = idx + 1;
+                            }
+                        }
+                    }
+                },
             }
         }
-        div { width: "60%", border_width: "1px", height: "100%", justify_content: "center",
-            if let Some(invoice) = selected_invoice {
+    })
+}
+
+/// Number of the visible items in the lists of the invoice form
+const PICKER_ROWS: usize = 5;
+
+/// Creates an invoice from the selected customer, identity, account and entries
+#[inline_props]
+fn InvoiceForm<'a>(cx: Scope, onsaved: EventHandler<'a, String>) -> Element<'a> {
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let customer_idx = use_state(cx, || 0);
+    let identity_idx = use_state(cx, || 0);
+    let account_idx = use_state(cx, || 0);
+    let selected_entries = use_ref(cx, Vec::<String>::new);
+    let due = use_ref(cx, String::new);
+    let error = use_state(cx, || None::<String>);
+
+    let path = cfg.read().path.clone();
+    let mut errors = Vec::new();
+    let customers = status::load::<Customer, Customers>(&path, "customers", &mut errors).customers;
+    let identities =
+        status::load::<Identity, Identities>(&path, "identities", &mut errors).identities;
+    let accounts = status::load::<Account, Accounts>(&path, "accounts", &mut errors).accounts;
+    let entries = status::load::<Entry, Entries>(&path, "entries", &mut errors).entries;
+
+    if customers.is_empty() || identities.is_empty() || accounts.is_empty() || entries.is_empty() {
+        let message = get_message("tui-create-records-first", None);
+        return cx.render(rsx! {
+            div { width: "100%", flex_direction: "column",
+                Errors { errors: errors }
+                span { "{message}" }
+            }
+        });
+    }
+
+    let customer_names: Vec<String> = customers.iter().map(|e| e.name.clone()).collect();
+    let identity_names: Vec<String> = identities.iter().map(|e| e.name.clone()).collect();
+    let account_names: Vec<String> = accounts.iter().map(|e| e.name.clone()).collect();
+    let entry_ids: Vec<String> = entries.iter().map(|e| e.id.clone()).collect();
+
+    let oncreate = move |_| {
+        let due = due.read();
+        let due = if due.trim().is_empty() {
+            None
+        } else if let Ok(days) = due.trim().parse() {
+            Some(days)
+        } else {
+            error.set(Some(get_message(
+                "tui-invalid-days",
+                Some(fluent_args!["days" => due.trim()]),
+            )));
+            return;
+        };
+        // keep the order of the entries
+        let selected: Vec<String> = entry_ids
+            .iter()
+            .filter(|id| selected_entries.read().contains(id))
+            .cloned()
+            .collect();
+        if selected.is_empty() {
+            error.set(Some(get_message("tui-no-entries-selected", None)));
+            return;
+        }
+        // the records might have been removed outside of the TUI
+        let (Some(customer), Some(identity), Some(account)) = (
+            customers.get(*customer_idx.get()),
+            identities.get(*identity_idx.get()),
+            accounts.get(*account_idx.get()),
+        ) else {
+            error.set(Some(get_message("tui-select-records", None)));
+            return;
+        };
+        let cfg = cfg.read();
+        match actions::invoice::create(
+            cfg.path.as_path(),
+            &customer.id,
+            &identity.id,
+            &account.id,
+            selected,
+            cfg.git,
+            due,
+        ) {
+            Ok(id) => {
+                error.set(None);
+                onsaved.call(id);
+            }
+            Err(err) => error.set(Some(err.to_string())),
+        }
+    };
+
+    let title = get_message("tui-new-invoice", None);
+    let customer_label = get_message("tui-customer", None);
+    let identity_label = get_message("tui-identity", None);
+    let account_label = get_message("tui-account", None);
+    let entries_label = get_message("tui-entries-select", None);
+    let due_label = get_message("tui-due-days", None);
+    let create_label = get_message("tui-create-button", None);
+
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            flex_direction: "column",
+            // keys are typed into the inputs
+            onkeydown: move |k: KeyboardEvent| k.stop_propagation(),
+            tabindex: -1,
+            strong { "{title}" }
+            Errors { errors: errors }
+            div { width: "100%", height: "30%",
+                div { width: "33%", flex_direction: "column",
+                    strong { "{customer_label}" }
+                    List {
+                        tabindex: 0,
+                        height: PICKER_ROWS,
+                        items: Rc::new(customer_names),
+                        dot: "❱",
+                        idx: *customer_idx.get(),
+                        onindexupdate: move |i: usize| customer_idx.set(i),
+                    }
+                }
+                div { width: "33%", flex_direction: "column",
+                    strong { "{identity_label}" }
+                    List {
+                        tabindex: 0,
+                        height: PICKER_ROWS,
+                        items: Rc::new(identity_names),
+                        dot: "❱",
+                        idx: *identity_idx.get(),
+                        onindexupdate: move |i: usize| identity_idx.set(i),
+                    }
+                }
+                div { width: "33%", flex_direction: "column",
+                    strong { "{account_label}" }
+                    List {
+                        tabindex: 0,
+                        height: PICKER_ROWS,
+                        items: Rc::new(account_names),
+                        dot: "❱",
+                        idx: *account_idx.get(),
+                        onindexupdate: move |i: usize| account_idx.set(i),
+                    }
+                }
+            }
+            strong { "{entries_label}" }
+            entries.iter().map(|entry| {
+                let id = entry.id.clone();
                 rsx! {
-                    Table {
+                    div {
+                        key: "{entry.id}",
                         width: "100%",
-                        items: get_items(invoice),
+                        input {
+                            r#type: "checkbox",
+                            value: "{entry.id}",
+                            oninput: move |_| {
+                                let mut selected = selected_entries.write();
+                                if let Some(pos) = selected.iter().position(|e| *e == id) {
+                                    selected.remove(pos);
+                                } else {
+                                    selected.push(id.clone());
+                                }
+                            },
+                        }
+                        span { " {entry.name} ({entry.price} {entry.currency})" }
                     }
+                }
+            })
+            div {
+                width: "100%",
+                height: "3px",
+                align_items: "center",
+                div { width: "30%", "{due_label}" }
+                input {
+                    width: "20%",
+                    height: "3px",
+                    oninput: move |e: FormEvent| *due.write() = e.value.clone(),
+                }
             }
-            } else {
-                rsx! {span { "TODO CREATE FORM"}}
+            input {
+                r#type: "button",
+                value: "{create_label}",
+                width: "20%",
+                height: "3px",
+                oninput: oncreate,
+            }
+            if let Some(error) = error.get() {
+                rsx! { span { color: "red", "{error}" } }
             }
         }
     })
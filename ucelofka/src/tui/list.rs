@@ -2,51 +2,192 @@
 
 use dioxus::{
     events::{KeyCode, KeyboardEvent},
+    html::input_data::keyboard_types::Key,
     prelude::*,
 };
+use fluent::fluent_args;
 
 use std::rc::Rc;
 
+use crate::translations::get_message;
+
+use super::app::{global_key, pressed, Global, UcelofkaTuiCfg};
+
+/// Rows of the terminal which are not used by the list items (the status line)
+const RESERVED_ROWS: usize = 1;
+
+/// Number of the items which fit into the terminal
+pub fn terminal_rows() -> usize {
+    crossterm::terminal::size()
+        .map(|(_, rows)| rows as usize)
+        .unwrap_or(24)
+        .saturating_sub(RESERVED_ROWS)
+        .max(1)
+}
+
+/// Index of the first item containing the query (case insensitive)
+///
+/// Items are searched from `from` and the search wraps around the end (the start) of the list.
+fn find(items: &[String], query: &str, from: usize, backwards: bool) -> Option<usize> {
+    let len = items.len();
+    let query = query.to_lowercase();
+    (0..len)
+        .map(|i| {
+            if backwards {
+                (from + len - i) % len
+            } else {
+                (from + i) % len
+            }
+        })
+        .find(|i| items[*i].to_lowercase().contains(&query))
+}
+
 #[inline_props]
 pub fn List<'a, T>(
     cx: Scope,
     width: Option<&'a str>,
+    /// Number of the visible items, the height of the terminal is used by default
+    height: Option<usize>,
     idx: usize,
     tabindex: i64,
     dot: Option<&'a str>,
     onindexupdate: EventHandler<'a, usize>,
+    /// Keys which are not used to move within the list
+    onkeydown: Option<EventHandler<'a, KeyboardEvent>>,
+    onmounted: Option<EventHandler<'a, MountedEvent>>,
     items: Rc<Vec<T>>,
-) -> Element
+) -> Element<'a>
 where
     T: ToString,
 {
     let width = width.unwrap_or_else(|| "100%");
+    let height = height.unwrap_or_else(terminal_rows).max(1);
     let item_len = items.len();
     let dot = dot.unwrap_or_default();
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let global = use_shared_state::<Global>(cx).unwrap();
+    let keys = cfg.read().keys.clone();
+
+    // `None` when not searching
+    let search = use_state(cx, || None::<String>);
+    // selected index before the search started
+    let search_start = use_ref(cx, || 0);
+    // first visible item
+    let offset = use_ref(cx, || 0);
+
+    // scroll to keep the selected item visible
+    let offset = {
+        let mut offset = offset.write_silent();
+        if *idx < *offset {
+            *offset = *idx;
+        } else if *idx >= *offset + height {
+            *offset = *idx + 1 - height;
+        }
+        *offset = (*offset).min(item_len.saturating_sub(height));
+        *offset
+    };
+
+    let texts: Vec<String> = items.iter().map(|e| e.to_string()).collect();
+    let found = search.get().as_ref().map(|query| {
+        texts
+            .get(*idx)
+            .is_some_and(|e| e.to_lowercase().contains(&query.to_lowercase()))
+    });
+
+    let onlistkeydown = move |k: KeyboardEvent| {
+        k.stop_propagation();
+        if let Some(query) = search.get() {
+            let mut query = query.clone();
+            match k.key() {
+                Key::Enter => search.set(None),
+                Key::Escape => {
+                    search.set(None);
+                    onindexupdate.call(*search_start.read());
+                }
+                Key::ArrowDown => {
+                    if let Some(i) = find(&texts, &query, *idx + 1, false) {
+                        onindexupdate.call(i);
+                    }
+                }
+                Key::ArrowUp => {
+                    if let Some(i) = find(&texts, &query, *idx + item_len - 1, true) {
+                        onindexupdate.call(i);
+                    }
+                }
+                Key::Backspace | Key::Character(_) => {
+                    if let Key::Character(c) = k.key() {
+                        query.push_str(&c);
+                    } else {
+                        query.pop();
+                    }
+                    if let Some(i) = find(&texts, &query, *search_start.read(), false) {
+                        onindexupdate.call(i);
+                    }
+                    search.set(Some(query));
+                }
+                _ => {}
+            }
+            return;
+        }
 
-    let onkeydown = move |k: KeyboardEvent| {
         match k.key_code {
-            KeyCode::UpArrow => {
+            KeyCode::UpArrow if item_len > 0 => {
                 let new_index = if *idx == 0 { item_len - 1 } else { idx - 1 };
                 onindexupdate.call(new_index);
             }
-            KeyCode::DownArrow => {
+            KeyCode::DownArrow if item_len > 0 => {
                 let new_index = if *idx == item_len - 1 { 0 } else { idx + 1 };
                 onindexupdate.call(new_index);
             }
-            _ => {}
+            KeyCode::PageUp if item_len > 0 => onindexupdate.call(idx.saturating_sub(height)),
+            KeyCode::PageDown if item_len > 0 => {
+                onindexupdate.call((idx + height).min(item_len - 1))
+            }
+            KeyCode::Home if item_len > 0 => onindexupdate.call(0),
+            KeyCode::End if item_len > 0 => onindexupdate.call(item_len - 1),
+            _ if pressed(&k, &keys.search) && item_len > 0 => {
+                *search_start.write_silent() = *idx;
+                search.set(Some(String::new()));
+            }
+            _ => {
+                if let Some(handler) = onkeydown {
+                    handler.call(k.clone());
+                }
+                global_key(cx, &k, global, &keys);
+            }
         };
-        k.stop_propagation();
+    };
+
+    let status = match (search.get(), found) {
+        (Some(query), Some(false)) if !query.is_empty() => get_message(
+            "tui-search-not-found",
+            Some(fluent_args!["query" => query.as_str()]),
+        ),
+        (Some(query), _) => format!("/{}", query),
+        (None, _) if item_len > height => format!("{}/{}", idx + 1, item_len),
+        (None, _) => String::new(),
     };
 
     cx.render(rsx! {
-        ListInner {
-            tabindex: *tabindex,
-            width: width,
-            idx: *idx,
-            dot: dot,
-            items: items.clone(),
-            onkeydown: onkeydown
+        div { width: width, flex_direction: "column",
+            ListInner {
+                tabindex: *tabindex,
+                width: "100%",
+                idx: *idx,
+                offset: offset,
+                height: height,
+                dot: dot,
+                items: items.clone(),
+                onkeydown: onlistkeydown,
+                onmounted: move |m: MountedEvent| {
+                    if let Some(handler) = onmounted {
+                        handler.call(m);
+                    }
+                }
+            }
+            if !status.is_empty() {
+                rsx! { span { color: "grey", "{status}" } }
+            }
         }
     })
 }
@@ -55,22 +196,32 @@ where
 pub fn ListInner<'a, T>(
     cx: Scope,
     idx: usize,
+    /// Index of the first visible item
+    offset: usize,
+    height: usize,
     dot: &'a str,
     width: &'a str,
     tabindex: i64,
     items: Rc<Vec<T>>,
     onkeydown: EventHandler<'a, KeyboardEvent>,
-) -> Element
+    onmounted: EventHandler<'a, MountedEvent>,
+) -> Element<'a>
 where
     T: ToString,
 {
-    let items: Vec<_> = items.iter().enumerate().collect();
+    let items: Vec<_> = items
+        .iter()
+        .enumerate()
+        .skip(*offset)
+        .take(*height)
+        .collect();
 
     cx.render(rsx! {
         ul {
             width: *width,
             tabindex: *tabindex,
             onkeydown: move |k: KeyboardEvent| onkeydown.call(k),
+            onmounted: move |m: MountedEvent| onmounted.call(m),
             flex_direction: "column",
             items.iter().map(move |(i, text)| {
                 let background_color = if *idx == *i {
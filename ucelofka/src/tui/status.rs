@@ -0,0 +1,43 @@
+#![allow(non_snake_case, deprecated)]
+
+use dioxus::prelude::*;
+use fluent::fluent_args;
+use std::{convert::TryFrom, path::Path};
+
+use crate::{
+    storage::{Record, Records, Versioned},
+    translations::get_message,
+};
+
+/// Loads the records which can be parsed
+///
+/// The errors of the other files are added to `errors` so the pages can show them
+/// instead of crashing the whole TUI.
+pub fn load<ITEM, RECORDS>(data_path: &Path, dir_name: &str, errors: &mut Vec<String>) -> RECORDS
+where
+    ITEM: TryFrom<String> + Clone + Record + Versioned,
+    <ITEM as TryFrom<String>>::Error: std::fmt::Debug,
+    RECORDS: Records<ITEM>,
+{
+    match RECORDS::load_valid(&data_path.join(dir_name)) {
+        Ok((records, load_errors)) => {
+            errors.extend(load_errors.iter().map(|err| err.to_string()));
+            records
+        }
+        Err(err) => {
+            errors.push(get_message(
+                "tui-list-failed",
+                Some(fluent_args!["dir" => dir_name, "error" => err.to_string()]),
+            ));
+            RECORDS::new(vec![])
+        }
+    }
+}
+
+/// Errors which occurred while loading the data
+#[inline_props]
+pub fn Errors(cx: Scope, errors: Vec<String>) -> Element<'a> {
+    cx.render(rsx! {
+        errors.iter().map(|err| rsx! { span { color: "red", "{err}" } })
+    })
+}
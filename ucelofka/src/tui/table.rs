@@ -3,7 +3,7 @@
 use dioxus::prelude::*;
 
 #[inline_props]
-pub fn Table<'a, T1, T2>(cx: Scope, width: &'a str, items: Vec<(T1, T2)>) -> Element
+pub fn Table<'a, T1, T2>(cx: Scope, width: &'a str, items: Vec<(T1, T2)>) -> Element<'a>
 where
     T1: ToString,
     T2: ToString,
@@ -0,0 +1,112 @@
+#![allow(non_snake_case, deprecated)]
+
+use dioxus::{
+    events::{KeyCode, KeyboardEvent},
+    prelude::*,
+};
+use fluent::fluent_args;
+use std::rc::Rc;
+use ucelofka_data::invoice::{Invoice, Invoices};
+
+use crate::{actions::template, translations::get_message};
+
+use super::{
+    app::{pressed, UcelofkaTuiCfg},
+    invoice::preview,
+    list::List,
+    status::{self, Errors},
+};
+
+struct CurrentTemplatePage(usize);
+
+#[inline_props]
+pub fn Templates(cx: Scope) -> Element<'a> {
+    use_shared_state_provider(cx, || CurrentTemplatePage(0));
+
+    let template_page = use_shared_state::<CurrentTemplatePage>(cx).unwrap();
+    // invoice which fills the template
+    let invoice_idx = use_state(cx, || None::<usize>);
+
+    let cfg = use_shared_state::<UcelofkaTuiCfg>(cx).unwrap();
+    let path = cfg.read().path.clone();
+    let mut errors = Vec::new();
+    let templates = template::list(&path)
+        .map(|e| e.templates)
+        .unwrap_or_else(|err| {
+            errors.push(err.to_string());
+            Vec::new()
+        });
+    let invoices: Vec<String> = status::load::<Invoice, Invoices>(&path, "invoices", &mut errors)
+        .invoices
+        .iter()
+        .map(|e| e.id.to_string())
+        .collect();
+
+    let items_str: Vec<String> = templates.iter().map(|e| e.name.clone()).collect();
+    let selected_idx = template_page.read().0;
+    let selected_template = templates.get(selected_idx).map(|e| e.name.clone());
+    let invoice_id = invoice_idx.and_then(|idx| invoices.get(idx).cloned());
+    let invoices_len = invoices.len();
+
+    let keys = cfg.read().keys.clone();
+    let title = match (selected_template.as_ref(), invoice_id.as_ref()) {
+        (Some(name), Some(id)) => get_message(
+            "tui-template-filled",
+            Some(fluent_args![
+                "template" => name.as_str(),
+                "invoice" => id.as_str(),
+                "key" => keys.invoice.as_str()
+            ]),
+        ),
+        (Some(name), None) => get_message(
+            "tui-template-fill",
+            Some(fluent_args!["template" => name.as_str(), "key" => keys.invoice.as_str()]),
+        ),
+        _ => get_message("tui-no-templates", None),
+    };
+
+    let onkeydown = move |k: KeyboardEvent| {
+        if pressed(&k, &keys.invoice) {
+            // next invoice, the last one is followed by the template itself
+            invoice_idx.set(match *invoice_idx.get() {
+                None if invoices_len > 0 => Some(0),
+                Some(idx) if idx + 1 < invoices_len => Some(idx + 1),
+                _ => None,
+            });
+        } else if k.key_code == KeyCode::Escape {
+            invoice_idx.set(None);
+        }
+    };
+
+    let text = selected_template
+        .as_ref()
+        .map(|name| match invoice_id.as_ref() {
+            Some(id) => preview(&path, id, name),
+            None => template::get(&path, name).map(|e| e.text.unwrap_or_default()),
+        });
+
+    cx.render(rsx! {
+        List {
+            tabindex: 0,
+            width: "25%",
+            items: Rc::new(items_str),
+            dot: "❱",
+            idx: selected_idx,
+            onindexupdate: move |i: usize| {
+                template_page.write().0 = i;
+            },
+            onkeydown: onkeydown,
+        }
+        div { width: "75%", border_width: "1px", height: "100%", flex_direction: "column",
+            Errors { errors: errors }
+            strong { "{title}" }
+            match text {
+                Some(Ok(text)) => rsx! {
+                    text.lines().map(|line| rsx! { span { "{line}" } })
+                },
+                Some(Err(err)) => rsx! { span { color: "red", "{err}" } },
+                None => rsx! { "" },
+            }
+        }
+    })
+}
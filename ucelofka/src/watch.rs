@@ -0,0 +1,57 @@
+//! Watching the data directory for changes made outside of the running program
+//!
+//! e.g. records created in another terminal or pulled from git
+
+use anyhow::{anyhow, Result};
+use fluent::fluent_args;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::translations::get_message;
+use std::path::{Component, Path, PathBuf};
+
+/// Calls `on_change` with the path (relative to the data directory) of each changed file
+///
+/// The changes are watched only until the returned watcher is dropped.
+/// Changes within `.git` directory are ignored.
+pub fn watch<F>(data_path: &Path, on_change: F) -> Result<RecommendedWatcher>
+where
+    F: Fn(PathBuf) + Send + 'static,
+{
+    let root = data_path.canonicalize()?;
+    let prefix = root.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        let Ok(event) = res else {
+            return;
+        };
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        for path in event.paths {
+            let path = path
+                .strip_prefix(&prefix)
+                .map(Path::to_path_buf)
+                .unwrap_or(path);
+            if !path
+                .components()
+                .any(|e| e == Component::Normal(".git".as_ref()))
+            {
+                on_change(path);
+            }
+        }
+    })
+    .map_err(|err| {
+        anyhow!(get_message(
+            "watch-failed",
+            Some(fluent_args!["path" => root.to_string_lossy(), "error" => err.to_string()])
+        ))
+    })?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .map_err(|err| {
+            anyhow!(get_message(
+                "watch-failed",
+                Some(fluent_args!["path" => root.to_string_lossy(), "error" => err.to_string()])
+            ))
+        })?;
+    Ok(watcher)
+}
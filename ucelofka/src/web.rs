@@ -1,6 +1,7 @@
 mod account;
 mod customer;
 mod entry;
+mod events;
 mod identity;
 mod invoice;
 mod template;
@@ -9,11 +10,19 @@ use std::{fmt, path::PathBuf, sync::Mutex};
 
 use actix_web::{error::ResponseError, web, App, HttpServer};
 use anyhow::Result;
-use tokio::runtime::Runtime;
+use fluent::fluent_args;
+use tokio::sync::broadcast;
+
+use crate::{translations::get_message, watch};
+
+/// Number of the changes kept for the slow clients
+const CHANGES_CAPACITY: usize = 64;
 
 #[derive(Clone)]
 struct UcelofkaData {
     data_dir_path: PathBuf,
+    /// Paths of the changed files
+    changes: broadcast::Sender<String>,
 }
 
 #[derive(Debug)]
@@ -37,16 +46,29 @@ impl From<anyhow::Error> for WrappedError {
 
 #[actix_web::main]
 async fn actix_main(port: u16, data_dir: PathBuf) -> std::io::Result<()> {
+    let (changes, _) = broadcast::channel(CHANGES_CAPACITY);
+    let sender = changes.clone();
+    // the server works without the notifications when the directory can't be watched
+    let _watcher = watch::watch(&data_dir, move |path| {
+        let _ = sender.send(path.display().to_string());
+    })
+    .map_err(|err| eprintln!("{}", err))
+    .ok();
     let data = web::Data::new(Mutex::new(UcelofkaData {
         data_dir_path: data_dir,
+        changes,
     }));
-    println!("Starting ucelfka web on http://localhost:{}", port);
+    println!(
+        "{}",
+        get_message("web-started", Some(fluent_args!["port" => port]))
+    );
     HttpServer::new(move || {
         App::new().app_data(data.clone()).service(
             web::scope("api")
                 .service(account::account_endpoint())
                 .service(customer::customer_endpoint())
                 .service(entry::entry_endpoint())
+                .service(events::events_endpoint())
                 .service(identity::identity_endpoint())
                 .service(invoice::invoice_endpoint())
                 .service(template::template_endpoint()),
@@ -59,7 +81,6 @@ async fn actix_main(port: u16, data_dir: PathBuf) -> std::io::Result<()> {
 }
 
 pub fn run(port: u16, data_dir: PathBuf) -> Result<()> {
-    let rt = Runtime::new().unwrap();
-    rt.block_on(async { actix_main(port, data_dir) })
-        .map_err(|e| e.into())
+    // actix starts its own runtime
+    actix_main(port, data_dir).map_err(|e| e.into())
 }
@@ -0,0 +1,35 @@
+use std::sync::Mutex;
+
+use actix_web::{web, web::Bytes, Error, HttpRequest, HttpResponse, Scope};
+use futures_util::{stream, StreamExt};
+use tokio::sync::broadcast::error::RecvError;
+
+use super::UcelofkaData;
+
+/// Streams server-sent events with the paths of the changed files
+///
+/// An event with an empty path is sent when some changes were missed.
+async fn get_events(data: web::Data<Mutex<UcelofkaData>>, req: HttpRequest) -> HttpResponse {
+    println!("{:?}", req);
+    let receiver = data.lock().unwrap().changes.subscribe();
+
+    let connected = stream::once(async { Ok::<_, Error>(Bytes::from(": connected\n\n")) });
+    let changes = stream::unfold(receiver, |mut receiver| async move {
+        let path = match receiver.recv().await {
+            Ok(path) => path,
+            Err(RecvError::Lagged(_)) => String::new(),
+            Err(RecvError::Closed) => return None,
+        };
+        let event = format!("event: change\ndata: {}\n\n", path);
+        Some((Ok(Bytes::from(event)), receiver))
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .streaming(connected.chain(changes))
+}
+
+pub(super) fn events_endpoint() -> Scope {
+    web::scope("events").service(web::resource("/").route(web::get().to(get_events)))
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::unused_unit)]
+
 use assert_cmd::Command;
 use tempfile::TempDir;
 
@@ -96,6 +98,8 @@ mod account {
 
 mod customer {
     use super::{prepare_project, test_cmd};
+    use assert_cmd::Command;
+    use test_case::test_case;
 
     #[test]
     fn list() {
@@ -135,6 +139,94 @@ mod customer {
             &["id: first_customer"],
         );
     }
+
+    #[test_case("csv" ; "csv")]
+    #[test_case("json" ; "json")]
+    fn export_import(format: &str) {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let export_path = project_dir.path().join(format!("customers.{}", format));
+
+        test_cmd(
+            "customer",
+            "export",
+            path,
+            &["--format", format],
+            &["first_customer", "CZ123456"],
+        );
+        test_cmd(
+            "customer",
+            "export",
+            path,
+            &[
+                "--format",
+                format,
+                "--output",
+                export_path.to_str().unwrap(),
+            ],
+            &[],
+        );
+
+        // existing records are kept by default
+        Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["customer", "--path", path, "import", "--input"])
+            .arg(&export_path)
+            .assert()
+            .failure();
+        test_cmd(
+            "customer",
+            "import",
+            path,
+            &[
+                "--input",
+                export_path.to_str().unwrap(),
+                "--conflict",
+                "skip",
+            ],
+            &["skipped"],
+        );
+
+        let customer_path = project_dir
+            .path()
+            .join("customers")
+            .join("first_customer.yml");
+        std::fs::remove_file(&customer_path).unwrap();
+        test_cmd(
+            "customer",
+            "import",
+            path,
+            &["--input", export_path.to_str().unwrap()],
+            &["imported"],
+        );
+        test_cmd(
+            "customer",
+            "get",
+            path,
+            &["--id", "first_customer"],
+            &["_version: 2", "CoStreet 1234/5", "value: CZ123456"],
+        );
+    }
+
+    #[test]
+    fn import_invalid() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let import_path = project_dir.path().join("customers.csv");
+        std::fs::write(&import_path, "id,name\nnew_customer,\n").unwrap();
+
+        Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["customer", "--path", path, "import", "--input"])
+            .arg(&import_path)
+            .assert()
+            .failure();
+        assert!(!project_dir
+            .path()
+            .join("customers")
+            .join("new_customer.yml")
+            .exists());
+    }
 }
 
 mod entry {
@@ -281,10 +373,480 @@ mod identity {
     }
 }
 
+mod payment {
+    use super::{prepare_project, test_cmd};
+    use assert_cmd::Command;
+    use std::path::{Path, PathBuf};
+    use test_case::test_case;
+
+    /// Writes MT940 statement with the given `:61:` and `:86:` lines
+    fn mt940(dir: &Path, lines: &str) -> PathBuf {
+        let statement = dir.join("statement.sta");
+        std::fs::write(
+            &statement,
+            format!(
+                ":20:STMT2024001\n:25:CZ1234567890/123456789\n:60F:C240101USD0,00\n{}:62F:C240131USD0,00\n-\n",
+                lines
+            ),
+        )
+        .unwrap();
+        statement
+    }
+
+    #[test_case("camt053.xml", &["partially paid", "499.99", "no matching invoice", "777"] ; "camt")]
+    #[test_case("statement.gpc", &["202400001", "is paid"] ; "gpc")]
+    #[test_case("statement.sta", &["202400001", "is paid"] ; "mt940")]
+    fn import(statement: &str, expected: &[&str]) {
+        let project_dir = prepare_project(true);
+        let path = project_dir.path().to_str().unwrap();
+        let statement = format!(
+            "{}/tests/statements/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            statement
+        );
+
+        test_cmd(
+            "payment",
+            "import",
+            path,
+            &["--input", &statement, "--dry-run"],
+            expected,
+        );
+        let (stdout, _) = test_cmd("payment", "ids", path, &[], &[]);
+        assert!(stdout.trim().is_empty());
+
+        test_cmd(
+            "payment",
+            "import",
+            path,
+            &["--input", &statement, "--git"],
+            expected,
+        );
+        test_cmd(
+            "payment",
+            "list",
+            path,
+            &[],
+            &["invoice: 202400001", "currency: USD"],
+        );
+
+        // payments are recorded only once
+        let (stdout, _) = test_cmd(
+            "payment",
+            "import",
+            path,
+            &["--input", &statement],
+            &["already recorded"],
+        );
+        assert!(!stdout.contains("is paid"));
+    }
+
+    #[test]
+    fn import_same_payments() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let payment = ":61:2401220122C400,00NTRFNONREF\n:86:VS 202400001\n";
+        let statement = mt940(project_dir.path(), &payment.repeat(2));
+        let statement = statement.to_str().unwrap();
+
+        test_cmd(
+            "payment",
+            "import",
+            path,
+            &["--input", statement],
+            &["2 matched", "199.99 remaining"],
+        );
+        let (stdout, _) = test_cmd("payment", "ids", path, &[], &[]);
+        assert_eq!(stdout.lines().count(), 2);
+
+        test_cmd(
+            "payment",
+            "import",
+            path,
+            &["--input", statement],
+            &["0 matched", "2 already recorded"],
+        );
+    }
+
+    #[test]
+    fn import_wrong_amount() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let statement = mt940(
+            project_dir.path(),
+            ":61:2401220122C1999,99NTRFNONREF\n:86:VS 202400001\n",
+        );
+
+        test_cmd(
+            "payment",
+            "import",
+            path,
+            &["--input", statement.to_str().unwrap()],
+            &[
+                "exceeds 999.99 remaining on invoice 202400001",
+                "0 matched",
+                "1 to review",
+            ],
+        );
+        let (stdout, _) = test_cmd("payment", "ids", path, &[], &[]);
+        assert!(stdout.trim().is_empty());
+    }
+
+    #[test]
+    fn import_invalid_mt940() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let statement = mt940(project_dir.path(), ":61:2401221ČČC999,99NTRF\n");
+
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["payment", "--path", path, "import", "--input"])
+            .arg(&statement)
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(!stderr.contains("panicked"));
+        assert!(stderr.contains("2401221ČČ"));
+    }
+
+    #[test_case(&[] ; "detected")]
+    #[test_case(&["--encoding", "windows-1250"] ; "explicit")]
+    fn import_cp1250(args: &[&str]) {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let statement = format!(
+            "{}/tests/statements/statement-cp1250.gpc",
+            env!("CARGO_MANIFEST_DIR")
+        );
+
+        let mut import_args = vec!["--input", &statement];
+        import_args.extend(args);
+        test_cmd("payment", "import", path, &import_args, &["is paid"]);
+        test_cmd(
+            "payment",
+            "list",
+            path,
+            &[],
+            &["counterparty: Účetní Šťastný"],
+        );
+    }
+}
+
+mod remind {
+    use super::{prepare_project, test_cmd};
+    use std::fs;
+
+    #[test]
+    fn escalate() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let remind = |date: &str, args: &[&str]| {
+            let mut all_args = vec!["--date", date];
+            all_args.extend_from_slice(args);
+            let assert = assert_cmd::Command::cargo_bin("ucelofka")
+                .unwrap()
+                .args(["remind", "--path", path])
+                .args(all_args)
+                .assert()
+                .success();
+            String::from_utf8(assert.get_output().stdout.clone()).unwrap()
+        };
+
+        // not overdue yet
+        assert_eq!(remind("2024-02-03", &[]), "");
+
+        assert!(remind("2024-02-20", &["--interest", "8.5"]).contains("202400001-reminder-1.txt"));
+        let reminder =
+            fs::read_to_string(project_dir.path().join("output/202400001-reminder-1.txt")).unwrap();
+        assert!(reminder.contains("Payment reminder 1 - Invoice 202400001"));
+        assert!(reminder.contains("Days overdue: 17"));
+        assert!(reminder.contains("Late interest (8.5 %): $3.96"));
+        assert!(reminder.contains("Total due: $1,003.95"));
+
+        // too early for the next reminder
+        assert_eq!(remind("2024-02-25", &[]), "");
+        assert!(remind("2024-03-10", &[]).contains("202400001-reminder-2.txt"));
+        test_cmd(
+            "invoice",
+            "get",
+            path,
+            &["--id", "202400001"],
+            &["level: 2", "date: 2024-03-10"],
+        );
+
+        test_cmd(
+            "invoice",
+            "paid",
+            path,
+            &["--invoice", "202400001", "--date", "2024-03-11"],
+            &[],
+        );
+        assert_eq!(remind("2024-05-10", &[]), "");
+        test_cmd(
+            "invoice",
+            "get",
+            path,
+            &["--id", "202400001"],
+            &["paid: 2024-03-11"],
+        );
+    }
+}
+
+mod report {
+    use super::{prepare_project, test_cmd};
+    use test_case::test_case;
+
+    #[test]
+    fn revenue() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        test_cmd(
+            "report",
+            "revenue",
+            path,
+            &[],
+            &["period", "2024-01", "USD", "999.99"],
+        );
+        let (stdout, _) = test_cmd(
+            "report",
+            "revenue",
+            path,
+            &["--period", "quarter", "--by-customer", "--format", "csv"],
+            &[],
+        );
+        assert_eq!(
+            stdout,
+            "period,customer,currency,invoices,total\n2024-Q1,First Customer,USD,1,999.99\n"
+        );
+        let (stdout, _) = test_cmd(
+            "report",
+            "revenue",
+            path,
+            &[
+                "--period",
+                "year",
+                "--from",
+                "2025-01-01",
+                "--format",
+                "json",
+            ],
+            &[],
+        );
+        assert_eq!(stdout, "[]\n");
+    }
+
+    #[test_case("2024-02-01", "999.99,0.00,0.00,0.00" ; "current")]
+    #[test_case("2024-02-20", "0.00,999.99,0.00,0.00" ; "up to 30 days")]
+    #[test_case("2024-03-20", "0.00,0.00,999.99,0.00" ; "up to 60 days")]
+    #[test_case("2024-06-01", "0.00,0.00,0.00,999.99" ; "over 60 days")]
+    fn aging(date: &str, buckets: &str) {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        let (stdout, _) = test_cmd(
+            "report",
+            "aging",
+            path,
+            &["--date", date, "--format", "csv"],
+            &[],
+        );
+        assert_eq!(
+            stdout,
+            format!(
+                "customer,currency,current,1-30,31-60,60+,total\nFirst Customer,USD,{},999.99\n",
+                buckets
+            )
+        );
+    }
+
+    #[test]
+    fn aging_paid() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let statement = format!(
+            "{}/tests/statements/statement.gpc",
+            env!("CARGO_MANIFEST_DIR")
+        );
+
+        test_cmd("payment", "import", path, &["--input", &statement], &[]);
+        let (stdout, _) = test_cmd(
+            "report",
+            "aging",
+            path,
+            &["--date", "2024-06-01", "--format", "json"],
+            &[],
+        );
+        assert_eq!(stdout, "[]\n");
+    }
+
+    #[test]
+    fn aging_marked_paid() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        test_cmd("invoice", "paid", path, &["--invoice", "202400001"], &[]);
+        let (stdout, _) = test_cmd(
+            "report",
+            "aging",
+            path,
+            &["--date", "2024-06-01", "--format", "json"],
+            &[],
+        );
+        assert_eq!(stdout, "[]\n");
+    }
+}
+
+mod tax {
+    use super::{prepare_project, test_cmd};
+    use assert_cmd::Command;
+    use std::fs;
+    use tempfile::TempDir;
+    use test_case::test_case;
+
+    fn prepare_vat_project() -> TempDir {
+        let project_dir = prepare_project(false);
+        for id in ["202600001", "202600002"] {
+            fs::copy(
+                format!("{}/tests/invoices/{}.yml", env!("CARGO_MANIFEST_DIR"), id),
+                project_dir.path().join(format!("invoices/{}.yml", id)),
+            )
+            .unwrap();
+        }
+        project_dir
+    }
+
+    #[test]
+    fn report() {
+        let project_dir = prepare_vat_project();
+        let path = project_dir.path().to_str().unwrap();
+
+        let (stdout, _) = test_cmd(
+            "tax",
+            "report",
+            path,
+            &["--period", "2026Q3", "--format", "csv"],
+            &[],
+        );
+        assert_eq!(
+            stdout,
+            "period,currency,rate,invoices,base,tax,total\n\
+             2026Q3,CZK,12,1,1000.00,120.00,1120.00\n\
+             2026Q3,CZK,21,2,25000.00,5250.00,30250.00\n"
+        );
+        test_cmd(
+            "tax",
+            "report",
+            path,
+            &["--period", "2024-01"],
+            &["USD", "-", "999.99"],
+        );
+    }
+
+    #[test]
+    fn control_statement() {
+        let project_dir = prepare_vat_project();
+        let path = project_dir.path().to_str().unwrap();
+
+        test_cmd(
+            "tax",
+            "control-statement",
+            path,
+            &["--period", "2026-Q3", "--date", "2026-10-19"],
+            &[
+                r#"<VetaD dokument="KH1" k_uladis="DPH" khdph_forma="B" rok="2026" ctvrt="3" d_poddp="19.10.2026"/>"#,
+                r#"dic="1234567890" typ_ds="F" jmeno="Your" prijmeni="Name""#,
+                r#"<VetaA4 c_radku="1" dic_odb="123456" c_evid_dd="202600001" dppd="10.07.2026" zakl_dane1="20000.00" dan1="4200.00" zakl_dane2="1000.00" dan2="120.00" kod_rezim_pl="0" zdph_44="N"/>"#,
+                r#"<VetaA5 zakl_dane1="5000.00" dan1="1050.00"/>"#,
+                r#"<VetaC obrat23="25000.00" obrat5="1000.00"/>"#,
+            ],
+        );
+
+        // no invoices with VAT were issued
+        Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["tax", "--path", path, "control-statement"])
+            .args(["--period", "2026-09"])
+            .assert()
+            .failure();
+    }
+
+    #[test_case(&["--invoice", "202600001", "--format", "isdoc", "--stdout"], include_str!("golden/202600001.isdoc"); "isdoc")]
+    #[test_case(&["--invoice", "202600001", "--format", "ubl", "--stdout"], include_str!("golden/202600001.ubl.xml"); "ubl")]
+    #[test_case(&["--invoice", "202600001", "--format", "cii", "--stdout"], include_str!("golden/202600001.cii.xml"); "cii")]
+    #[test_case(&["--format", "pohoda", "--from", "2026-01-01"], include_str!("golden/invoices-vat.pohoda.xml"); "pohoda")]
+    #[test_case(&["--format", "money-s3", "--from", "2026-01-01"], include_str!("golden/invoices-vat.money-s3.xml"); "money s3")]
+    fn export_with_vat(args: &[&str], expected: &str) {
+        let project_dir = prepare_vat_project();
+        let path = project_dir.path().to_str().unwrap();
+
+        let (stdout, _) = test_cmd("invoice", "export", path, args, &[]);
+        assert_eq!(stdout, expected);
+    }
+
+    #[test]
+    fn render_with_vat() {
+        let project_dir = prepare_vat_project();
+        let path = project_dir.path().to_str().unwrap();
+
+        let (stdout, _) = test_cmd(
+            "invoice",
+            "render",
+            path,
+            &[
+                "--invoice",
+                "202600001",
+                "--template",
+                "default.txt",
+                "--stdout",
+            ],
+            &[],
+        );
+        assert!(stdout.contains("Books: 1,000.00 CZK (VAT rate 12%)"));
+        assert!(stdout.contains("21%: 20,000.00 CZK / 4,200.00 CZK / 24,200.00 CZK"));
+        assert!(stdout.contains("12%: 1,000.00 CZK / 120.00 CZK / 1,120.00 CZK"));
+
+        let (stdout, _) = test_cmd(
+            "invoice",
+            "render",
+            path,
+            &[
+                "--invoice",
+                "202600001",
+                "--template",
+                "default-cz.html",
+                "--stdout",
+            ],
+            &[],
+        );
+        assert!(stdout.contains("<td>12\u{a0}%</td>"));
+        assert!(stdout.contains("Základ daně"));
+    }
+}
+
+mod template {
+    use super::{prepare_project, test_cmd};
+
+    #[test]
+    fn list() {
+        let project_dir = prepare_project(false);
+
+        let (stdout, _) = test_cmd(
+            "template",
+            "list",
+            project_dir.path().to_str().unwrap(),
+            &[],
+            &["default.html", "default-cz.html"],
+        );
+        assert!(!stdout.contains("_styles.css"));
+    }
+}
+
 #[cfg(test)]
 mod invoice {
     use super::{prepare_project, test_cmd};
     use assert_cmd::Command;
+    use std::path::Path;
     use test_case::test_case;
 
     fn invoice(path: &str, git: bool, due: bool) -> String {
@@ -479,4 +1041,1023 @@ mod invoice {
             &[&invoice_id],
         );
     }
+
+    #[test_case("default.html", &["$999.99", "January 19, 2024", "February 3, 2024"] ; "english template")]
+    #[test_case("default-cz.html", &["999,99 US$", "19. ledna 2024", "3. února 2024"] ; "czech template")]
+    fn render_formatting(template: &str, expected: &[&str]) {
+        let project_dir = prepare_project(false);
+
+        test_cmd(
+            "invoice",
+            "render",
+            project_dir.path().to_str().unwrap(),
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                template,
+                "--locale",
+                "en-US",
+            ],
+            &["202400001"],
+        );
+
+        let output =
+            std::fs::read_to_string(project_dir.path().join("output").join("202400001.html"))
+                .unwrap();
+        for item in expected {
+            assert!(output.contains(item), "`{}` not found in output", item);
+        }
+    }
+
+    #[test]
+    fn render_partials() {
+        let project_dir = prepare_project(false);
+        let templates_dir = project_dir.path().join("templates");
+        std::fs::create_dir(templates_dir.join("assets")).unwrap();
+        std::fs::write(templates_dir.join("assets").join("logo.png"), b"logo").unwrap();
+        std::fs::write(
+            templates_dir.join("_base.html"),
+            "<html>{% block content %}{% endblock %}</html>",
+        )
+        .unwrap();
+        std::fs::write(
+            templates_dir.join("child.html"),
+            concat!(
+                r#"{% extends "_base.html" %}{% block content %}"#,
+                r#"<img src="{{ data_uri("assets/logo.png") }}">{{ id }}{% endblock %}"#,
+            ),
+        )
+        .unwrap();
+
+        test_cmd(
+            "invoice",
+            "render",
+            project_dir.path().to_str().unwrap(),
+            &["--invoice", "202400001", "--template", "child.html"],
+            &["202400001"],
+        );
+
+        let output =
+            std::fs::read_to_string(project_dir.path().join("output").join("202400001.html"))
+                .unwrap();
+        assert_eq!(
+            output,
+            r#"<html><img src="data:image/png;base64,bG9nbw==">202400001</html>"#
+        );
+    }
+
+    #[test]
+    fn render_translated() {
+        let project_dir = prepare_project(false);
+        let output_path = project_dir.path().join("output").join("202400001.html");
+
+        test_cmd(
+            "invoice",
+            "render",
+            project_dir.path().to_str().unwrap(),
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.html",
+                "--locale",
+                "cs",
+            ],
+            &["202400001"],
+        );
+        let output = std::fs::read_to_string(&output_path).unwrap();
+        assert!(output.contains("Faktura #: 202400001"));
+        assert!(output.contains("19. ledna 2024"));
+
+        // customer's preferred locale is used when the locale is not set
+        let invoice_path = project_dir.path().join("invoices").join("202400001.yml");
+        let invoice = std::fs::read_to_string(&invoice_path)
+            .unwrap()
+            .replace("customer:\n", "customer:\n  locale: cs\n");
+        std::fs::write(&invoice_path, invoice).unwrap();
+
+        test_cmd(
+            "invoice",
+            "render",
+            project_dir.path().to_str().unwrap(),
+            &["--invoice", "202400001", "--template", "default.html"],
+            &["202400001"],
+        );
+        let output = std::fs::read_to_string(&output_path).unwrap();
+        assert!(output.contains("Vydáno: 19. ledna 2024"));
+        assert!(output.contains("<td>DIČ</td>"));
+    }
+
+    #[test]
+    fn build() {
+        let project_dir = prepare_project(true);
+        let path = project_dir.path().to_str().unwrap();
+        let invoice_id = invoice(path, false, false);
+        let output_path = project_dir
+            .path()
+            .join("output")
+            .join(format!("{}.html", invoice_id));
+        let modified = || std::fs::metadata(&output_path).unwrap().modified().unwrap();
+
+        test_cmd("invoice", "build", path, &["--git"], &["rendered"]);
+        assert!(project_dir
+            .path()
+            .join("output")
+            .join("202400001.html")
+            .exists());
+        let first = modified();
+
+        // up to date outputs are skipped
+        test_cmd("invoice", "build", path, &[], &["up to date"]);
+        assert_eq!(first, modified());
+
+        test_cmd(
+            "invoice",
+            "build",
+            path,
+            &["--invoice", &invoice_id, "--force", "--jobs", "1"],
+            &[],
+        );
+        assert!(first < modified());
+
+        // included templates are tracked too
+        let cz_path = project_dir.path().join("output").join("202400001-cz.html");
+        let pattern = [
+            "--template",
+            "default-cz.html",
+            "--pattern",
+            "{id}-cz.{ext}",
+        ];
+        test_cmd("invoice", "build", path, &pattern, &["rendered"]);
+        let cz_modified = std::fs::metadata(&cz_path).unwrap().modified().unwrap();
+        let template_path = project_dir.path().join("templates").join("default.html");
+        let template = std::fs::read_to_string(&template_path).unwrap();
+        std::fs::write(&template_path, template.replace("</body>", "<hr></body>")).unwrap();
+        test_cmd("invoice", "build", path, &pattern, &["rendered"]);
+        assert!(cz_modified < std::fs::metadata(&cz_path).unwrap().modified().unwrap());
+    }
+
+    #[test]
+    fn build_conflicting_outputs() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let templates = [
+            "--template",
+            "default.html",
+            "--template",
+            "default-cz.html",
+        ];
+
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["invoice", "--path", path, "build"])
+            .args(templates)
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(stderr.contains("202400001.html"));
+        assert!(!project_dir
+            .path()
+            .join("output")
+            .join("202400001.html")
+            .exists());
+
+        test_cmd(
+            "invoice",
+            "build",
+            path,
+            &[&templates[..], &["--pattern", "{id}-{template}.{ext}"]].concat(),
+            &["2 rendered"],
+        );
+        for name in ["202400001-default.html", "202400001-default-cz.html"] {
+            assert!(project_dir.path().join("output").join(name).exists());
+        }
+    }
+
+    #[test]
+    fn build_subdirectory() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        test_cmd(
+            "invoice",
+            "build",
+            path,
+            &["--force", "--pattern", "{customer}/{id}.{ext}"],
+            &["1 rendered"],
+        );
+        assert!(project_dir
+            .path()
+            .join("output")
+            .join("First_Customer")
+            .join("202400001.html")
+            .exists());
+    }
+
+    #[test]
+    fn render_output() {
+        let project_dir = prepare_project(true);
+        let path = project_dir.path().to_str().unwrap();
+
+        let (stdout, _) = test_cmd(
+            "invoice",
+            "render",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.html",
+                "--stdout",
+            ],
+            &["<html>", "202400001"],
+        );
+        assert!(!stdout.contains("was rendered"));
+        assert!(!project_dir
+            .path()
+            .join("output")
+            .join("202400001.html")
+            .exists());
+
+        let output_path = project_dir.path().join("custom").join("invoice.html");
+        test_cmd(
+            "invoice",
+            "render",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.html",
+                "--output",
+                output_path.to_str().unwrap(),
+                "--git",
+            ],
+            &["invoice.html"],
+        );
+        assert!(output_path.exists());
+
+        test_cmd(
+            "invoice",
+            "render",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default-cz.html",
+                "--pattern",
+                "{id}-{customer}-{template}.{ext}",
+                "--git",
+            ],
+            &["202400001-First_Customer-default-cz.html"],
+        );
+        assert!(project_dir
+            .path()
+            .join("output")
+            .join("202400001-First_Customer-default-cz.html")
+            .exists());
+
+        for pattern in ["../{id}.{ext}", "/tmp/{id}.{ext}"] {
+            Command::cargo_bin("ucelofka")
+                .unwrap()
+                .args([
+                    "invoice",
+                    "--path",
+                    path,
+                    "render",
+                    "--invoice",
+                    "202400001",
+                ])
+                .args(["--template", "default.html", "--pattern", pattern])
+                .assert()
+                .failure();
+        }
+    }
+
+    /// Validates the XML by xmllint against the schema placed in `tests/schemas`
+    ///
+    /// ISDOC schema is a part of the sources, the other ones are optional
+    /// (see `tests/schemas/README.md`).
+    fn validate_schema(xml: &Path, schema: Option<&str>) {
+        let Some(schema) = schema else {
+            return;
+        };
+        let required = schema.starts_with("isdoc/");
+        let schema = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/schemas")
+            .join(schema);
+        if !schema.exists() {
+            assert!(!required, "{} is missing", schema.display());
+            eprintln!("{} is missing, the validation is skipped", schema.display());
+            return;
+        }
+        let output = std::process::Command::new("xmllint")
+            .args(["--noout", "--schema"])
+            .arg(&schema)
+            .arg(xml)
+            .output()
+            .expect("xmllint is required to validate the exports");
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test_case("202400001", "cii", "202400001.cii.xml", include_str!("golden/202400001.cii.xml"), None; "cii")]
+    #[test_case("202600001", "isdoc", "202600001.isdoc", include_str!("golden/202600001.isdoc"), Some("isdoc/isdoc-invoice-6.0.2.xsd"); "isdoc")]
+    #[test_case("202400001", "ubl", "202400001.ubl.xml", include_str!("golden/202400001.ubl.xml"), Some("ubl/maindoc/UBL-Invoice-2.1.xsd"); "ubl")]
+    #[test_case("202400002", "cii", "202400002.cii.xml", include_str!("golden/202400002.cii.xml"), None; "cii buyer reference")]
+    #[test_case("202400002", "ubl", "202400002.ubl.xml", include_str!("golden/202400002.ubl.xml"), Some("ubl/maindoc/UBL-Invoice-2.1.xsd"); "ubl buyer reference")]
+    #[test_case("202400003", "cii", "202400003.cii.xml", include_str!("golden/202400003.cii.xml"), None; "cii not subject to vat")]
+    #[test_case("202400003", "ubl", "202400003.ubl.xml", include_str!("golden/202400003.ubl.xml"), Some("ubl/maindoc/UBL-Invoice-2.1.xsd"); "ubl not subject to vat")]
+    fn export(invoice: &str, format: &str, filename: &str, expected: &str, schema: Option<&str>) {
+        let project_dir = prepare_project(true);
+        let path = project_dir.path().to_str().unwrap();
+        let fixture = format!(
+            "{}/tests/invoices/{}.yml",
+            env!("CARGO_MANIFEST_DIR"),
+            invoice
+        );
+        if Path::new(&fixture).exists() {
+            std::fs::copy(
+                &fixture,
+                project_dir.path().join(format!("invoices/{}.yml", invoice)),
+            )
+            .unwrap();
+        }
+
+        let (stdout, _) = test_cmd(
+            "invoice",
+            "export",
+            path,
+            &["--invoice", invoice, "--format", format, "--stdout"],
+            &[],
+        );
+        assert_eq!(stdout, expected);
+
+        test_cmd(
+            "invoice",
+            "export",
+            path,
+            &["--invoice", invoice, "--format", format, "--git"],
+            &[filename],
+        );
+        let output_path = project_dir.path().join("output").join(filename);
+        validate_schema(&output_path, schema);
+        let exported = std::fs::read_to_string(output_path).unwrap();
+        assert_eq!(exported, expected);
+    }
+
+    #[test]
+    fn export_isdoc_foreign_currency() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["invoice", "--path", path, "export"])
+            .args(["--invoice", "202400001", "--format", "isdoc", "--stdout"])
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(stderr.contains("USD"));
+    }
+
+    #[test_case("pohoda", include_str!("golden/invoices.pohoda.xml"); "pohoda")]
+    #[test_case("money-s3", include_str!("golden/invoices.money-s3.xml"); "money s3")]
+    fn export_accounting(format: &str, expected: &str) {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        let (stdout, _) = test_cmd(
+            "invoice",
+            "export",
+            path,
+            &[
+                "--format",
+                format,
+                "--from",
+                "2024-01-01",
+                "--to",
+                "2024-01-31",
+            ],
+            &[],
+        );
+        assert_eq!(stdout, expected);
+
+        let (stdout, _) = test_cmd(
+            "invoice",
+            "export",
+            path,
+            &["--format", format, "--from", "2024-02-01"],
+            &[],
+        );
+        assert!(!stdout.contains("202400001"));
+
+        // the dates select the invoices so they can't be combined with the ids
+        Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["invoice", "--path", path, "export", "--format", format])
+            .args(["--invoice", "202400001", "--from", "2024-01-01"])
+            .assert()
+            .failure();
+    }
+
+    #[test]
+    fn export_import_csv() {
+        let project_dir = prepare_project(true);
+        let path = project_dir.path().to_str().unwrap();
+        let export_path = project_dir.path().join("invoices.csv");
+
+        let (stdout, _) = test_cmd(
+            "invoice",
+            "export",
+            path,
+            &["--format", "csv", "--output", export_path.to_str().unwrap()],
+            &[],
+        );
+        assert!(stdout.is_empty());
+        let exported = std::fs::read_to_string(&export_path).unwrap();
+        assert!(exported.starts_with("id,issue_date,due_date,"));
+        assert!(exported.contains("entry.name"));
+        assert!(exported.contains("IT system management"));
+
+        let invoice_path = project_dir.path().join("invoices").join("202400001.yml");
+        std::fs::remove_file(&invoice_path).unwrap();
+        test_cmd(
+            "invoice",
+            "import",
+            path,
+            &["--input", export_path.to_str().unwrap(), "--git"],
+            &["imported"],
+        );
+        test_cmd(
+            "invoice",
+            "get",
+            path,
+            &["--id", "202400001"],
+            &[
+                "issue_date: 2024-01-19",
+                "value: CZ1234567890",
+                "- DB Optimizations",
+                "variable_symbol: '202400001'",
+            ],
+        );
+    }
+
+    #[test]
+    fn render_facturx() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        test_cmd(
+            "invoice",
+            "render",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.txt",
+                "--facturx",
+            ],
+            &["202400001.pdf"],
+        );
+        let pdf = std::fs::read(project_dir.path().join("output").join("202400001.pdf")).unwrap();
+        let pdf = String::from_utf8_lossy(&pdf);
+        assert!(pdf.starts_with("%PDF-1.7"));
+        assert!(!pdf.contains("pdfaid"));
+        assert!(pdf.contains("<fx:DocumentFileName>factur-x.xml</fx:DocumentFileName>"));
+        assert!(pdf.contains("/AFRelationship /Alternative"));
+        // the CII XML is embedded uncompressed
+        assert!(pdf.contains(include_str!("golden/202400001.cii.xml")));
+
+        // HTML templates are converted to text
+        test_cmd(
+            "invoice",
+            "render",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.html",
+                "--facturx",
+            ],
+            &["202400001.pdf"],
+        );
+        let pdf = std::fs::read(project_dir.path().join("output").join("202400001.pdf")).unwrap();
+        assert!(!String::from_utf8_lossy(&pdf).contains("&lt;!doctype"));
+
+        // empty text is refused instead of writing a PDF without pages
+        std::fs::write(project_dir.path().join("templates/empty.txt"), "{# #}\n").unwrap();
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args([
+                "invoice",
+                "--path",
+                path,
+                "render",
+                "--invoice",
+                "202400001",
+            ])
+            .args(["--template", "empty.txt", "--facturx"])
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(stderr.contains("produced no text"));
+    }
+
+    /// Minimal SMTP server accepting a single message
+    fn smtp_stand_in() -> (u16, std::thread::JoinHandle<String>) {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut writer = stream;
+            let mut transcript = String::new();
+            let mut in_data = false;
+            writer.write_all(b"220 localhost\r\n").unwrap();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 {
+                    break;
+                }
+                transcript.push_str(&line);
+                let reply: &[u8] = if in_data {
+                    if line != ".\r\n" {
+                        continue;
+                    }
+                    in_data = false;
+                    b"250 queued\r\n"
+                } else if line.starts_with("EHLO") {
+                    b"250-localhost\r\n250 8BITMIME\r\n"
+                } else if line.starts_with("DATA") {
+                    in_data = true;
+                    b"354 go ahead\r\n"
+                } else if line.starts_with("QUIT") {
+                    writer.write_all(b"221 bye\r\n").unwrap();
+                    break;
+                } else {
+                    b"250 ok\r\n"
+                };
+                writer.write_all(reply).unwrap();
+            }
+            transcript
+        });
+        (port, handle)
+    }
+
+    #[test]
+    fn send_smtp() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let (port, handle) = smtp_stand_in();
+
+        test_cmd(
+            "invoice",
+            "send",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.txt",
+                "--smtp",
+                &format!("127.0.0.1:{}", port),
+                "--smtp-security",
+                "none",
+            ],
+            &["me@customer.com"],
+        );
+        let transcript = handle.join().unwrap();
+        assert!(transcript.contains("MAIL FROM:<your@email.com>\r\n"));
+        assert!(transcript.contains("RCPT TO:<me@customer.com>\r\n"));
+        assert!(transcript.contains("Subject: Invoice 202400001 - Your Name\r\n"));
+        assert!(transcript.contains("filename=\"202400001.txt\""));
+        assert!(project_dir.path().join("output/202400001.txt").exists());
+
+        test_cmd(
+            "invoice",
+            "get",
+            path,
+            &["--id", "202400001"],
+            &["sent:", "- me@customer.com"],
+        );
+    }
+
+    #[test]
+    fn send_smtp_unencrypted() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let send = |args: &[&str]| {
+            let assert = Command::cargo_bin("ucelofka")
+                .unwrap()
+                .args(["invoice", "--path", path, "send", "--invoice", "202400001"])
+                .args(["--template", "default.txt"])
+                .args(args)
+                .assert()
+                .failure();
+            String::from_utf8(assert.get_output().stderr.clone()).unwrap()
+        };
+
+        // credentials are refused before connecting
+        let stderr = send(&[
+            "--smtp",
+            "127.0.0.1:1",
+            "--smtp-security",
+            "none",
+            "--smtp-user",
+            "user",
+            "--smtp-password",
+            "secret",
+        ]);
+        assert!(stderr.contains("unencrypted connection"));
+
+        // STARTTLS is required by default
+        let (port, handle) = smtp_stand_in();
+        let server = format!("127.0.0.1:{}", port);
+        send(&[
+            "--smtp",
+            &server,
+            "--smtp-user",
+            "user",
+            "--smtp-password",
+            "secret",
+        ]);
+        let transcript = handle.join().unwrap();
+        assert!(!transcript.contains("AUTH"));
+        assert!(!transcript.contains("MAIL FROM"));
+    }
+
+    #[test]
+    fn send_smtp_password_without_user() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .env("UCELOFKA_SMTP_PASSWORD", "secret")
+            .args(["invoice", "--path", path, "send"])
+            .args(["--invoice", "202400001", "--template", "default.txt"])
+            .args(["--smtp", "127.0.0.1:1", "--smtp-security", "none"])
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(stderr.contains("--smtp-user"));
+    }
+
+    #[test]
+    fn send_dry_run() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        test_cmd(
+            "invoice",
+            "send",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.html",
+                "--to",
+                "Účetní <accounting@customer.com>",
+                "--dry-run",
+            ],
+            &["202400001.eml"],
+        );
+        let eml = std::fs::read_to_string(project_dir.path().join("output/202400001.eml")).unwrap();
+        assert!(eml.contains("To: =?UTF-8?B?w5rEjWV0bsOt?= <accounting@customer.com>\r\n"));
+        assert!(eml.contains("Content-Type: text/html; charset=utf-8\r\n"));
+        assert!(eml.contains("filename=\"202400001.html\""));
+
+        // nothing was sent
+        let (stdout, _) = test_cmd("invoice", "get", path, &["--id", "202400001"], &[]);
+        assert!(!stdout.contains("sent:"));
+
+        // outdated output is rendered again
+        let invoice_path = project_dir.path().join("invoices/202400001.yml");
+        let invoice = std::fs::read_to_string(&invoice_path).unwrap();
+        std::fs::write(
+            &invoice_path,
+            invoice.replace("IT system management", "IT system administration"),
+        )
+        .unwrap();
+        test_cmd(
+            "invoice",
+            "send",
+            path,
+            &[
+                "--invoice",
+                "202400001",
+                "--template",
+                "default.html",
+                "--dry-run",
+            ],
+            &["202400001.eml"],
+        );
+        let output =
+            std::fs::read_to_string(project_dir.path().join("output/202400001.html")).unwrap();
+        assert!(output.contains("IT system administration"));
+    }
+}
+
+mod tui {
+    use super::prepare_project;
+    use assert_cmd::Command;
+    use test_case::test_case;
+
+    #[test_case("help: F1", "Invalid key 'F1' in config.yml" ; "length")]
+    #[test_case("edit: q", "Key 'q' in config.yml is used for more actions" ; "quit shadowed")]
+    #[test_case("year: u", "Key 'u' in config.yml is used for more actions" ; "same page")]
+    fn invalid_config(keys: &str, error: &str) {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        // fails before the terminal is set up
+        std::fs::write(
+            project_dir.path().join("config.yml"),
+            format!("tui:\n  keys:\n    {}\n", keys),
+        )
+        .unwrap();
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .args(["tui", "--path", path])
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(stderr.contains(error));
+    }
+}
+
+mod web {
+    use super::prepare_project;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::{TcpListener, TcpStream},
+        process::{Command, Stdio},
+        thread::sleep,
+        time::Duration,
+    };
+
+    /// Reads the lines of the response until a line starting with `prefix`
+    fn read_until(reader: &mut BufReader<TcpStream>, prefix: &str) -> String {
+        loop {
+            let mut line = String::new();
+            assert_ne!(reader.read_line(&mut line).unwrap(), 0);
+            if line.starts_with(prefix) {
+                return line;
+            }
+        }
+    }
+
+    #[test]
+    fn events() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let mut server = Command::new(assert_cmd::cargo::cargo_bin("ucelofka"))
+            .args(["web", "--path", path, "--port", &port.to_string()])
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        let stream = (0..50)
+            .find_map(|_| {
+                sleep(Duration::from_millis(100));
+                TcpStream::connect(("localhost", port)).ok()
+            })
+            .expect("server not started");
+        stream
+            .set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        writer
+            .write_all(b"GET /api/events/ HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        assert!(read_until(&mut reader, "content-type").contains("text/event-stream"));
+        read_until(&mut reader, ": connected");
+
+        // changes in git are not reported
+        std::fs::create_dir(project_dir.path().join(".git")).unwrap();
+        std::fs::write(project_dir.path().join(".git/HEAD"), "").unwrap();
+        let customer = project_dir.path().join("customers/first_customer.yml");
+        let content = std::fs::read_to_string(&customer).unwrap();
+        std::fs::write(&customer, content.replace("First Customer", "Changed")).unwrap();
+
+        assert_eq!(
+            read_until(&mut reader, "data:"),
+            "data: customers/first_customer.yml\n"
+        );
+        server.kill().unwrap();
+        server.wait().unwrap();
+    }
+}
+
+mod translations {
+    use std::{collections::BTreeSet, fs, path::Path};
+
+    use super::prepare_project;
+    use assert_cmd::Command;
+    use test_case::test_case;
+
+    fn resources() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("resources")
+    }
+
+    /// Ids of the messages defined in the catalog
+    fn message_ids(lang: &str) -> BTreeSet<String> {
+        fs::read_to_string(resources().join(lang).join("ucelofka.ftl"))
+            .unwrap()
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once(" =").map(|(id, _)| id.to_string()))
+            .collect()
+    }
+
+    /// Ids passed to `get_message` in the sources
+    fn used_ids(dir: &Path, ids: &mut BTreeSet<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                used_ids(&path, ids);
+            } else if path.extension().and_then(|e| e.to_str()) == Some("rs") {
+                let content = fs::read_to_string(&path).unwrap();
+                for part in content.split("get_message(").skip(1) {
+                    if let Some(rest) = part.trim_start().strip_prefix('"') {
+                        ids.insert(rest.split('"').next().unwrap().to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_ids() {
+        let expected = message_ids("en-US");
+        let mut langs = vec![];
+        for entry in fs::read_dir(resources()).unwrap() {
+            let lang = entry.unwrap().file_name().to_string_lossy().to_string();
+            let ids = message_ids(&lang);
+            assert_eq!(
+                expected.difference(&ids).collect::<Vec<_>>(),
+                Vec::<&String>::new(),
+                "missing in {}",
+                lang
+            );
+            assert_eq!(
+                ids.difference(&expected).collect::<Vec<_>>(),
+                Vec::<&String>::new(),
+                "extra in {}",
+                lang
+            );
+            langs.push(lang);
+        }
+        langs.sort();
+        assert_eq!(langs, ["cs", "de", "en-US", "sk"]);
+    }
+
+    #[test]
+    fn used_ids_defined() {
+        let mut ids = BTreeSet::new();
+        used_ids(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut ids);
+        let defined = message_ids("en-US");
+        assert_eq!(
+            ids.difference(&defined).collect::<Vec<_>>(),
+            Vec::<&String>::new()
+        );
+    }
+
+    #[test]
+    fn localized_output() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .env("LANG", "de_DE.UTF-8")
+            .arg("--help")
+            .assert()
+            .success();
+        let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+        assert!(stdout.contains("Einfaches Programm zum Ausstellen von Rechnungen"));
+
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .env("LANG", "cs_CZ.UTF-8")
+            .args(["invoice", "--path", path, "get", "--id", "missing"])
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(stderr.contains("Faktura missing nebyla nalezena."));
+
+        // errors of the template functions are translated too
+        std::fs::write(
+            project_dir.path().join("templates/broken.txt"),
+            "{{ issue_date|date(\"%Q\") }}",
+        )
+        .unwrap();
+        let assert = Command::cargo_bin("ucelofka")
+            .unwrap()
+            .env("LANG", "cs_CZ.UTF-8")
+            .args([
+                "invoice",
+                "--path",
+                path,
+                "render",
+                "--invoice",
+                "202400001",
+            ])
+            .args(["--template", "broken.txt", "--stdout"])
+            .assert()
+            .failure();
+        let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+        assert!(stderr.contains("Neplatný formát data %Q"));
+    }
+
+    /// First line of the help printed within the given environment
+    fn help_title(env: &[(&str, &str)], args: &[&str]) -> String {
+        let mut cmd = Command::cargo_bin("ucelofka").unwrap();
+        for name in ["LC_ALL", "LC_MESSAGES", "LANG", "LANGUAGE"] {
+            cmd.env_remove(name);
+        }
+        let assert = cmd.envs(env.iter().copied()).args(args).assert().success();
+        String::from_utf8(assert.get_output().stdout.clone())
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test_case(&[], "Simple program to issue invoices" ; "default")]
+    #[test_case(&[("LANG", "de_DE.UTF-8")], "Einfaches Programm zum Ausstellen von Rechnungen" ; "lang")]
+    #[test_case(&[("LANG", "de_DE.UTF-8"), ("LC_MESSAGES", "sk_SK.UTF-8")], "Jednoduchý program na vystavovanie faktúr" ; "lc_messages")]
+    #[test_case(&[("LC_MESSAGES", "de_DE.UTF-8"), ("LC_ALL", "cs_CZ.UTF-8")], "Jednoduchý program na vystavování faktur" ; "lc_all")]
+    #[test_case(&[("LANG", "de_DE.UTF-8"), ("LANGUAGE", "sk:de")], "Jednoduchý program na vystavovanie faktúr" ; "language")]
+    #[test_case(&[("LANG", "C"), ("LANGUAGE", "sk:de")], "Simple program to issue invoices" ; "c_locale")]
+    fn environment(env: &[(&str, &str)], title: &str) {
+        assert_eq!(help_title(env, &["--help"]), title);
+    }
+
+    #[test]
+    fn lang_option() {
+        let env = [("LANG", "cs_CZ.UTF-8")];
+        assert_eq!(
+            help_title(&env, &["--lang", "de", "--help"]),
+            "Einfaches Programm zum Ausstellen von Rechnungen"
+        );
+        assert_eq!(
+            help_title(&env, &["account", "--lang=sk", "list", "--help"]),
+            "Vypíše účty"
+        );
+    }
+
+    #[test]
+    fn config_and_overrides() {
+        let project_dir = prepare_project(false);
+        let path = project_dir.path().to_str().unwrap();
+        fs::write(project_dir.path().join("config.yml"), "lang: sk\n").unwrap();
+
+        let get_account_with = |path_args: &[&str]| {
+            let assert = Command::cargo_bin("ucelofka")
+                .unwrap()
+                .env("LANG", "de_DE.UTF-8")
+                .arg("account")
+                .args(path_args)
+                .args(["get", "--id", "missing"])
+                .assert()
+                .failure();
+            String::from_utf8(assert.get_output().stderr.clone()).unwrap()
+        };
+        let get_account = || get_account_with(&["--path", path]);
+        assert!(get_account().contains("Účet missing nebol nájdený."));
+        let short = format!("-P{}", path);
+        assert!(get_account_with(&[&short]).contains("Účet missing nebol nájdený."));
+
+        // the valid messages of a broken file are used too
+        fs::create_dir(project_dir.path().join("messages")).unwrap();
+        fs::write(
+            project_dir.path().join("messages/sk.ftl"),
+            "account-not-found = Chýba { $id }\nbroken = {\n",
+        )
+        .unwrap();
+        assert!(get_account().contains("Chýba missing"));
+    }
 }
@@ -0,0 +1,8 @@
+#!/bin/sh
+# Downloads the ISDOC 6.0.2 schemas used by the export tests
+set -e
+
+cd "$(dirname "$0")"
+mkdir -p isdoc
+curl -fsSL -o isdoc/isdoc-invoice-6.0.2.xsd https://isdoc.cz/6.0.2/xsd/isdoc-invoice-6.0.2.xsd
+curl -fsSL -o isdoc/xmldsig-core-schema.xsd https://www.w3.org/TR/xmldsig-core/xmldsig-core-schema.xsd